@@ -7,6 +7,9 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+part 'bridge_definitions.freezed.dart';
 
 abstract class Native {
   Future<String> trainKnn(
@@ -28,6 +31,119 @@ abstract class Native {
 
   FlutterRustBridgeTaskConstMeta get kTrainKnnAkazeFeaturesConstMeta;
 
+  /// Trains on 64-bit perceptual hashes of the examples instead of their
+  /// pixels: each comparison is a single Hamming distance, whatever the photo
+  /// size. `classify_knn_hash` hashes with the same `kind`.
+  Future<String> trainKnnHash(
+      {required int k,
+      required List<LabeledImage> examples,
+      required HashKind kind,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTrainKnnHashConstMeta;
+
+  Future<String> classifyKnnHash({required DartImage img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClassifyKnnHashConstMeta;
+
+  /// `classify_knn_hash` on a camera frame, hashed from its Y plane.
+  Future<String> classifyKnnHashFrame({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClassifyKnnHashFrameConstMeta;
+
+  Future<int> perceptualHash(
+      {required DartImage img, required HashKind kind, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPerceptualHashConstMeta;
+
+  Future<int> framePerceptualHash(
+      {required ImageData img, required HashKind kind, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFramePerceptualHashConstMeta;
+
+  /// Number of bits in which two perceptual hashes differ, from 0 to 64.
+  Future<int> hashDistance({required int a, required int b, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHashDistanceConstMeta;
+
+  /// Pads out a small training set with jittered copies of its photos; see
+  /// `AugmentSettings`. Pass the result to any of the `train_knn*` functions.
+  Future<List<LabeledImage>> augmentExamples(
+      {required List<LabeledImage> examples,
+      required AugmentSettings settings,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAugmentExamplesConstMeta;
+
+  /// Trains `model` on the photos of `project`, read straight from the
+  /// `projects/<project>/<label>/*.png` layout under the app directory `dir`.
+  Future<String> loadProject(
+      {required String dir,
+      required String project,
+      required int k,
+      required KnnModel model,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLoadProjectConstMeta;
+
+  /// Packs `project` into a zip archive at `archive`, with a manifest of every
+  /// photo's label, file name, size and modification time.
+  Future<String> exportProject(
+      {required String dir,
+      required String project,
+      required String archive,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportProjectConstMeta;
+
+  /// Adds the photos of an archive from `export_project` to `project`.
+  Future<String> importProject(
+      {required String dir,
+      required String project,
+      required String archive,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kImportProjectConstMeta;
+
+  /// Finds photos no more than `max_distance` apart (see `LabelConflict::distance`):
+  /// groups of near-duplicates under one label, which skew kNN votes, and pairs
+  /// under different labels, which contradict each other.
+  Future<DuplicateReport> findDuplicates(
+      {required List<LabeledImage> examples,
+      required double maxDistance,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFindDuplicatesConstMeta;
+
+  /// `find_duplicates` comparing perceptual hashes, which also catches photos
+  /// of different sizes: pairs whose hashes differ in no more than `max_bits`.
+  Future<DuplicateReport> findHashDuplicates(
+      {required List<LabeledImage> examples,
+      required HashKind kind,
+      required int maxBits,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFindHashDuplicatesConstMeta;
+
+  /// `find_duplicates` over the photos of `project` under the app directory `dir`.
+  Future<DuplicateReport> projectDuplicates(
+      {required String dir,
+      required String project,
+      required double maxDistance,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kProjectDuplicatesConstMeta;
+
+  /// `find_hash_duplicates` over the photos of `project` under the app directory `dir`.
+  Future<DuplicateReport> projectHashDuplicates(
+      {required String dir,
+      required String project,
+      required HashKind kind,
+      required int maxBits,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kProjectHashDuplicatesConstMeta;
+
   Future<String> classifyKnnAkazePos({required DartImage img, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kClassifyKnnAkazePosConstMeta;
@@ -37,6 +153,13 @@ abstract class Native {
 
   FlutterRustBridgeTaskConstMeta get kClassifyKnnAkazeFeatureConstMeta;
 
+  /// Selects the pyramid level that `akaze_view`, `akaze_flow` and `color_clusterer`
+  /// process at; each level halves the resolution. Results are still drawn at full
+  /// resolution.
+  Future<void> setPyramidLevel({required int level, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetPyramidLevelConstMeta;
+
   Future<bool> kmeansReady({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kKmeansReadyConstMeta;
@@ -46,10 +169,25 @@ abstract class Native {
   FlutterRustBridgeTaskConstMeta get kTrainingTimeConstMeta;
 
   Future<ImageResponse> intensityRgba(
-      {required Uint8List intensities, dynamic hint});
+      {required Uint8List intensities,
+      required int width,
+      required int height,
+      dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kIntensityRgbaConstMeta;
 
+  Future<void> setColorConversion(
+      {required ColorStandard standard,
+      required ColorRange range,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetColorConversionConstMeta;
+
+  Future<void> setFrameTransform(
+      {required FrameTransform transform, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetFrameTransformConstMeta;
+
   Future<ImageResponse> yuvRgba({required ImageData img, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kYuvRgbaConstMeta;
@@ -62,6 +200,89 @@ abstract class Native {
 
   FlutterRustBridgeTaskConstMeta get kColorClustererConstMeta;
 
+  Future<FreeSpace> floorFreeSpace({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFloorFreeSpaceConstMeta;
+
+  Future<ImageResponse> floorView({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFloorViewConstMeta;
+
+  /// Blobs smaller than `min_area` pixels are left out of `detect_blobs` and `blob_view`.
+  Future<void> setMinBlobArea({required int minArea, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetMinBlobAreaConstMeta;
+
+  /// Restricts `detect_blobs` and `blob_view` to the color cluster whose mean is
+  /// nearest to the given color.
+  Future<void> trackBlobColor(
+      {required int red, required int green, required int blue, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTrackBlobColorConstMeta;
+
+  Future<void> stopBlobTracking({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStopBlobTrackingConstMeta;
+
+  /// Blobs of the clustered frame, largest first.
+  Future<List<ColorBlob>> detectBlobs({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDetectBlobsConstMeta;
+
+  Future<ImageResponse> blobView({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBlobViewConstMeta;
+
+  Future<void> setColorTarget({required ColorTarget target, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetColorTargetConstMeta;
+
+  /// Sets the straight-ahead speed and the largest steering correction that
+  /// `track_color_target` uses.
+  Future<void> setTrackingSpeeds(
+      {required int cruise, required int turn, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetTrackingSpeedsConstMeta;
+
+  Future<TrackingCommand> trackColorTarget(
+      {required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTrackColorTargetConstMeta;
+
+  Future<ImageResponse> colorTargetView({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kColorTargetViewConstMeta;
+
+  Future<ImageResponse> sobelView({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSobelViewConstMeta;
+
+  /// Sets the gradient magnitudes below which `canny_view` discards edge pixels
+  /// (`low`) and above which it always keeps them (`high`).
+  Future<void> setCannyThresholds(
+      {required double low, required double high, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetCannyThresholdsConstMeta;
+
+  Future<ImageResponse> cannyView({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCannyViewConstMeta;
+
+  /// Sets how many Canny edge pixels a line needs (`min_votes`) and how many of
+  /// the strongest lines `detect_lines` and `lines_view` report (`max_lines`).
+  Future<void> setLineDetection(
+      {required int minVotes, required int maxLines, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetLineDetectionConstMeta;
+
+  Future<List<LineSegment>> detectLines({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDetectLinesConstMeta;
+
+  Future<ImageResponse> linesView({required ImageData img, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLinesViewConstMeta;
+
   Future<ImageResponse> akazeView({required ImageData img, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kAkazeViewConstMeta;
@@ -70,6 +291,11 @@ abstract class Native {
 
   FlutterRustBridgeTaskConstMeta get kAkazeFlowConstMeta;
 
+  /// Forgets the previous frame, so the next `akaze_flow` shows no motion.
+  Future<void> resetAkazeFlow({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kResetAkazeFlowConstMeta;
+
   Future<void> resetPositionEstimate({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kResetPositionEstimateConstMeta;
@@ -79,12 +305,202 @@ abstract class Native {
 
   FlutterRustBridgeTaskConstMeta get kProcessSensorDataConstMeta;
 
+  /// Appends every frame and sensor packet the native code receives from now on
+  /// to a log at `path`, replacing any recording in progress.
+  Future<void> startRecording({required String path, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStartRecordingConstMeta;
+
+  Future<void> stopRecording({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStopRecordingConstMeta;
+
+  Future<bool> isRecording({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIsRecordingConstMeta;
+
+  /// Plays a log from `start_recording` back through `process_sensor_data`,
+  /// `akaze_flow` and the kNN classifiers, from a fresh position estimate and
+  /// flow history, and returns one message per record. Frames are converted with
+  /// the settings they were recorded with; the current settings come back
+  /// afterwards. Stops any recording.
+  Future<List<String>> replayRecording({required String path, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReplayRecordingConstMeta;
+
+  /// Takes effect at the next `set_controller`.
+  Future<void> setSonarSettings(
+      {required SonarSettings settings, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetSonarSettingsConstMeta;
+
+  Future<void> setController({required ControllerKind kind, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetControllerConstMeta;
+
+  /// Queues a command to go out ahead of anything the active controller chooses.
+  Future<void> queueRobotCommand({required RobotCommand command, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kQueueRobotCommandConstMeta;
+
+  Future<RobotCommand> nextRobotCommand(
+      {required SensorData sensors, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextRobotCommandConstMeta;
+
+  /// Makes the classifier label `label` mean `command` for `observe_label`.
+  Future<void> setLabelAction(
+      {required String label, required RobotCommand command, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetLabelActionConstMeta;
+
+  Future<void> clearLabelActions({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClearLabelActionsConstMeta;
+
+  /// Labels vote over the last `window` frames; a new winner must win `hold`
+  /// frames in a row before the robot switches to its action.
+  Future<void> setLabelVoting(
+      {required int window, required int hold, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetLabelVotingConstMeta;
+
+  /// Feeds the latest `classify_knn*` result to the label behavior and returns
+  /// the command it settles on.
+  Future<RobotCommand> observeLabel({required String label, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kObserveLabelConstMeta;
+
+  Future<String> robotCommandWire(
+      {required RobotCommand command, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRobotCommandWireConstMeta;
+
   Future<SensorData> parseSensorData(
       {required String incomingData, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kParseSensorDataConstMeta;
 }
 
+/// How `augment_examples` varies each photo. Every copy gets its own random
+/// rotation, shift, brightness, contrast and noise, each uniform up to these
+/// limits; the same `seed` always gives the same copies.
+class AugmentSettings {
+  /// Extra copies made of each photo.
+  final int copies;
+  /// Mirrors every other copy left to right. Off by default, since it turns
+  /// a photo of a left turn into one of a right turn.
+  final bool flip;
+  final double maxRotationDegrees;
+  /// As a fraction of the photo's width and height.
+  final double maxShift;
+  /// Added to every channel, in 8-bit levels.
+  final double maxBrightness;
+  /// Fractional change of the spread of every channel around mid-gray.
+  final double maxContrast;
+  /// Per-channel noise, in 8-bit levels.
+  final double maxNoise;
+  final int seed;
+
+  const AugmentSettings({
+    required this.copies,
+    required this.flip,
+    required this.maxRotationDegrees,
+    required this.maxShift,
+    required this.maxBrightness,
+    required this.maxContrast,
+    required this.maxNoise,
+    required this.seed,
+  });
+}
+
+/// A connected region of one color cluster in a clustered frame. The bounding
+/// box is inclusive.
+class ColorBlob {
+  final int red;
+  final int green;
+  final int blue;
+  final int area;
+  final double centroidX;
+  final double centroidY;
+  final int left;
+  final int top;
+  final int right;
+  final int bottom;
+
+  const ColorBlob({
+    required this.red,
+    required this.green,
+    required this.blue,
+    required this.area,
+    required this.centroidX,
+    required this.centroidY,
+    required this.left,
+    required this.top,
+    required this.right,
+    required this.bottom,
+  });
+}
+
+/// Whether luma spans 16..=235 (chroma 16..=240) or the full 0..=255.
+enum ColorRange {
+  Limited,
+  Full,
+}
+
+/// How `ColorTarget::tolerance` is measured.
+enum ColorSpace {
+  /// Per-channel difference, 0..=255.
+  Rgb,
+
+  /// Hue difference in degrees; dim or washed-out pixels never match.
+  Hsv,
+}
+
+/// Matrix coefficients used to turn camera YUV into RGB.
+enum ColorStandard {
+  /// SD video and JPEG; what most phone cameras produce.
+  Bt601,
+
+  /// HD video.
+  Bt709,
+}
+
+/// A color for the robot to drive toward.
+class ColorTarget {
+  final int red;
+  final int green;
+  final int blue;
+  final int tolerance;
+  final ColorSpace space;
+  /// Matching regions smaller than this many pixels are ignored.
+  final int minArea;
+
+  const ColorTarget({
+    required this.red,
+    required this.green,
+    required this.blue,
+    required this.tolerance,
+    required this.space,
+    required this.minArea,
+  });
+}
+
+/// Which controller picks commands when none are queued.
+enum ControllerKind {
+  /// Only queued commands are sent.
+  Manual,
+
+  AvoidObstacles,
+
+  FollowLeftWall,
+
+  FollowRightWall,
+
+  /// Carries out the action of the label that `observe_label` settled on.
+  Vision,
+}
+
 class DartImage {
   final Uint8List bytes;
   final int width;
@@ -97,14 +513,99 @@ class DartImage {
   });
 }
 
+/// Photos of one label that nearly repeat one another, by index into the
+/// report's `names`.
+class DuplicateGroup {
+  final String label;
+  final Int64List members;
+
+  const DuplicateGroup({
+    required this.label,
+    required this.members,
+  });
+}
+
+class DuplicateReport {
+  /// Every photo scanned: `label/filename` for a project, `label #index`
+  /// for a list of examples.
+  final List<String> names;
+  final List<DuplicateGroup> groups;
+  final List<LabelConflict> conflicts;
+
+  const DuplicateReport({
+    required this.names,
+    required this.groups,
+    required this.conflicts,
+  });
+}
+
+/// Orientation and region of interest applied to every frame before processing.
+///
+/// The frame is rotated, then mirrored left-to-right, then cropped. The crop
+/// rectangle is in rotated and mirrored coordinates; a zero `crop_width` or
+/// `crop_height` keeps the whole frame.
+class FrameTransform {
+  final Rotation rotation;
+  final bool mirror;
+  final int cropX;
+  final int cropY;
+  final int cropWidth;
+  final int cropHeight;
+
+  const FrameTransform({
+    required this.rotation,
+    required this.mirror,
+    required this.cropX,
+    required this.cropY,
+    required this.cropWidth,
+    required this.cropHeight,
+  });
+}
+
+/// Free space in front of the robot, found by treating the color clusters of the
+/// frame's bottom-center region as floor. Until a color model is trained, the
+/// clusters come from the frame itself.
+class FreeSpace {
+  /// One byte per pixel, row-major: 255 for floor, 0 for anything else.
+  final Uint8List mask;
+  final int width;
+  final int height;
+  /// For each column, the number of floor pixels above the bottom row before
+  /// the first obstacle pixel.
+  final Int64List columnHeights;
+
+  const FreeSpace({
+    required this.mask,
+    required this.width,
+    required this.height,
+    required this.columnHeights,
+  });
+}
+
+/// Ways to reduce a photo to a 64-bit perceptual hash.
+enum HashKind {
+  /// Average hash: each cell of an 8x8 thumbnail against the mean.
+  Average,
+
+  /// Difference hash: each cell of a 9x8 thumbnail against its right
+  /// neighbor. Cheap, and robust to brightness changes.
+  Difference,
+
+  /// pHash: the signs of the lowest DCT frequencies of a 32x32 thumbnail.
+  /// The slowest, and the most robust to blur and noise.
+  Dct,
+}
+
 class ImageData {
   final Uint8List ys;
   final Uint8List us;
   final Uint8List vs;
   final int width;
   final int height;
+  final int yRowStride;
   final int uvRowStride;
   final int uvPixelStride;
+  final PixelFormat format;
 
   const ImageData({
     required this.ys,
@@ -112,18 +613,49 @@ class ImageData {
     required this.vs,
     required this.width,
     required this.height,
+    required this.yRowStride,
     required this.uvRowStride,
     required this.uvPixelStride,
+    required this.format,
   });
 }
 
 class ImageResponse {
   final Uint8List img;
   final String msg;
+  final int width;
+  final int height;
 
   const ImageResponse({
     required this.img,
     required this.msg,
+    required this.width,
+    required this.height,
+  });
+}
+
+/// The kNN classifiers that `load_project` can train.
+enum KnnModel {
+  Pixels,
+  AkazePositions,
+  AkazeFeatures,
+  AverageHash,
+  DifferenceHash,
+  DctHash,
+}
+
+/// Two nearly identical photos with different labels.
+class LabelConflict {
+  final int first;
+  final int second;
+  /// Root-mean-square difference per byte, in 8-bit levels, or differing
+  /// hash bits for the `*_hash_duplicates` functions.
+  final double distance;
+
+  const LabelConflict({
+    required this.first,
+    required this.second,
+    required this.distance,
   });
 }
 
@@ -137,6 +669,76 @@ class LabeledImage {
   });
 }
 
+/// A straight line in a frame, `x cos(theta) + y sin(theta) = rho` in pixels and
+/// radians, with the endpoints of the edge pixels that support it.
+class LineSegment {
+  final double rho;
+  final double theta;
+  final double x1;
+  final double y1;
+  final double x2;
+  final double y2;
+  final int votes;
+
+  const LineSegment({
+    required this.rho,
+    required this.theta,
+    required this.x1,
+    required this.y1,
+    required this.x2,
+    required this.y2,
+    required this.votes,
+  });
+}
+
+/// Memory layout of the planes in an `ImageData`.
+enum PixelFormat {
+  /// Android `YUV_420_888`: separate `ys`, `us` and `vs` planes, addressed
+  /// with `uv_row_stride` and `uv_pixel_stride`.
+  Yuv420,
+
+  /// Semi-planar with interleaved V/U samples (V first). The chroma plane is
+  /// passed in `us`; if `us` is empty it is taken from `ys` after the luma rows.
+  Nv21,
+
+  /// Semi-planar with interleaved U/V samples (U first). Chroma as for `Nv21`.
+  Nv12,
+
+  /// Fully planar `us` and `vs`, one byte per sample. If `us` and `vs` are
+  /// empty, both are taken from `ys` after the luma rows.
+  I420,
+
+  /// iOS `BGRA8888`: `ys` holds four bytes per pixel and `y_row_stride` is
+  /// the number of bytes per row. `us` and `vs` are ignored.
+  Bgra8888,
+}
+
+@freezed
+class RobotCommand with _$RobotCommand {
+  /// Keep doing whatever the robot is already doing.
+  const factory RobotCommand.noOp() = RobotCommand_NoOp;
+  const factory RobotCommand.start() = RobotCommand_Start;
+  const factory RobotCommand.stop() = RobotCommand_Stop;
+  const factory RobotCommand.drive({
+    required int speed,
+  }) = RobotCommand_Drive;
+  const factory RobotCommand.turn({
+    required int speed,
+  }) = RobotCommand_Turn;
+  const factory RobotCommand.setSpeeds({
+    required int left,
+    required int right,
+  }) = RobotCommand_SetSpeeds;
+}
+
+/// Clockwise rotation from sensor orientation to display orientation.
+enum Rotation {
+  Rotate0,
+  Rotate90,
+  Rotate180,
+  Rotate270,
+}
+
 class SensorData {
   final int sonarFront;
   final int sonarLeft;
@@ -156,3 +758,42 @@ class SensorData {
     required this.rightSpeed,
   });
 }
+
+/// Thresholds for the sonar controllers. Distances are in the units of the
+/// `SensorData` sonar readings; speeds in the units of its wheel speeds.
+class SonarSettings {
+  /// Anything closer than this ahead makes the robot turn.
+  final int obstacleDistance;
+  /// How far a wall follower stays from its wall.
+  final int wallDistance;
+  final int cruiseSpeed;
+  final int turnSpeed;
+
+  const SonarSettings({
+    required this.obstacleDistance,
+    required this.wallDistance,
+    required this.cruiseSpeed,
+    required this.turnSpeed,
+  });
+}
+
+/// Where the tracked color is and how to drive toward it. Speeds are in the same
+/// units as `SensorData::left_speed` and `right_speed`; both are 0 when the target
+/// is not in view or has been reached.
+class TrackingCommand {
+  final bool found;
+  final int leftSpeed;
+  final int rightSpeed;
+  final double centroidX;
+  final double centroidY;
+  final int area;
+
+  const TrackingCommand({
+    required this.found,
+    required this.leftSpeed,
+    required this.rightSpeed,
+    required this.centroidX,
+    required this.centroidY,
+    required this.area,
+  });
+}
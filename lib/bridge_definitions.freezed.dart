@@ -0,0 +1,963 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'bridge_definitions.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#custom-getters-and-methods');
+
+/// @nodoc
+mixin _$RobotCommand {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $RobotCommandCopyWith<$Res> {
+  factory $RobotCommandCopyWith(
+          RobotCommand value, $Res Function(RobotCommand) then) =
+      _$RobotCommandCopyWithImpl<$Res, RobotCommand>;
+}
+
+/// @nodoc
+class _$RobotCommandCopyWithImpl<$Res, $Val extends RobotCommand>
+    implements $RobotCommandCopyWith<$Res> {
+  _$RobotCommandCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$RobotCommand_NoOpCopyWith<$Res> {
+  factory _$$RobotCommand_NoOpCopyWith(
+          _$RobotCommand_NoOp value, $Res Function(_$RobotCommand_NoOp) then) =
+      __$$RobotCommand_NoOpCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$RobotCommand_NoOpCopyWithImpl<$Res>
+    extends _$RobotCommandCopyWithImpl<$Res, _$RobotCommand_NoOp>
+    implements _$$RobotCommand_NoOpCopyWith<$Res> {
+  __$$RobotCommand_NoOpCopyWithImpl(
+      _$RobotCommand_NoOp _value, $Res Function(_$RobotCommand_NoOp) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$RobotCommand_NoOp implements RobotCommand_NoOp {
+  const _$RobotCommand_NoOp();
+
+  @override
+  String toString() {
+    return 'RobotCommand.noOp()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$RobotCommand_NoOp);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) {
+    return noOp();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) {
+    return noOp?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (noOp != null) {
+      return noOp();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) {
+    return noOp(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) {
+    return noOp?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (noOp != null) {
+      return noOp(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RobotCommand_NoOp implements RobotCommand {
+  const factory RobotCommand_NoOp() = _$RobotCommand_NoOp;
+}
+
+/// @nodoc
+abstract class _$$RobotCommand_StartCopyWith<$Res> {
+  factory _$$RobotCommand_StartCopyWith(_$RobotCommand_Start value,
+          $Res Function(_$RobotCommand_Start) then) =
+      __$$RobotCommand_StartCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$RobotCommand_StartCopyWithImpl<$Res>
+    extends _$RobotCommandCopyWithImpl<$Res, _$RobotCommand_Start>
+    implements _$$RobotCommand_StartCopyWith<$Res> {
+  __$$RobotCommand_StartCopyWithImpl(
+      _$RobotCommand_Start _value, $Res Function(_$RobotCommand_Start) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$RobotCommand_Start implements RobotCommand_Start {
+  const _$RobotCommand_Start();
+
+  @override
+  String toString() {
+    return 'RobotCommand.start()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$RobotCommand_Start);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) {
+    return start();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) {
+    return start?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (start != null) {
+      return start();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) {
+    return start(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) {
+    return start?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (start != null) {
+      return start(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RobotCommand_Start implements RobotCommand {
+  const factory RobotCommand_Start() = _$RobotCommand_Start;
+}
+
+/// @nodoc
+abstract class _$$RobotCommand_StopCopyWith<$Res> {
+  factory _$$RobotCommand_StopCopyWith(
+          _$RobotCommand_Stop value, $Res Function(_$RobotCommand_Stop) then) =
+      __$$RobotCommand_StopCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$RobotCommand_StopCopyWithImpl<$Res>
+    extends _$RobotCommandCopyWithImpl<$Res, _$RobotCommand_Stop>
+    implements _$$RobotCommand_StopCopyWith<$Res> {
+  __$$RobotCommand_StopCopyWithImpl(
+      _$RobotCommand_Stop _value, $Res Function(_$RobotCommand_Stop) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$RobotCommand_Stop implements RobotCommand_Stop {
+  const _$RobotCommand_Stop();
+
+  @override
+  String toString() {
+    return 'RobotCommand.stop()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$RobotCommand_Stop);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) {
+    return stop();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) {
+    return stop?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (stop != null) {
+      return stop();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) {
+    return stop(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) {
+    return stop?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (stop != null) {
+      return stop(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RobotCommand_Stop implements RobotCommand {
+  const factory RobotCommand_Stop() = _$RobotCommand_Stop;
+}
+
+/// @nodoc
+abstract class _$$RobotCommand_DriveCopyWith<$Res> {
+  factory _$$RobotCommand_DriveCopyWith(_$RobotCommand_Drive value,
+          $Res Function(_$RobotCommand_Drive) then) =
+      __$$RobotCommand_DriveCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int speed});
+}
+
+/// @nodoc
+class __$$RobotCommand_DriveCopyWithImpl<$Res>
+    extends _$RobotCommandCopyWithImpl<$Res, _$RobotCommand_Drive>
+    implements _$$RobotCommand_DriveCopyWith<$Res> {
+  __$$RobotCommand_DriveCopyWithImpl(
+      _$RobotCommand_Drive _value, $Res Function(_$RobotCommand_Drive) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? speed = null,
+  }) {
+    return _then(_$RobotCommand_Drive(
+      speed: null == speed
+          ? _value.speed
+          : speed // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RobotCommand_Drive implements RobotCommand_Drive {
+  const _$RobotCommand_Drive({required this.speed});
+
+  @override
+  final int speed;
+
+  @override
+  String toString() {
+    return 'RobotCommand.drive(speed: $speed)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RobotCommand_Drive &&
+            (identical(other.speed, speed) || other.speed == speed)));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, speed);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RobotCommand_DriveCopyWith<_$RobotCommand_Drive> get copyWith =>
+      __$$RobotCommand_DriveCopyWithImpl<_$RobotCommand_Drive>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) {
+    return drive(speed);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) {
+    return drive?.call(speed);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (drive != null) {
+      return drive(speed);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) {
+    return drive(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) {
+    return drive?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (drive != null) {
+      return drive(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RobotCommand_Drive implements RobotCommand {
+  const factory RobotCommand_Drive(
+      {required final int speed}) = _$RobotCommand_Drive;
+
+  int get speed;
+  @JsonKey(ignore: true)
+  _$$RobotCommand_DriveCopyWith<_$RobotCommand_Drive> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$RobotCommand_TurnCopyWith<$Res> {
+  factory _$$RobotCommand_TurnCopyWith(
+          _$RobotCommand_Turn value, $Res Function(_$RobotCommand_Turn) then) =
+      __$$RobotCommand_TurnCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int speed});
+}
+
+/// @nodoc
+class __$$RobotCommand_TurnCopyWithImpl<$Res>
+    extends _$RobotCommandCopyWithImpl<$Res, _$RobotCommand_Turn>
+    implements _$$RobotCommand_TurnCopyWith<$Res> {
+  __$$RobotCommand_TurnCopyWithImpl(
+      _$RobotCommand_Turn _value, $Res Function(_$RobotCommand_Turn) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? speed = null,
+  }) {
+    return _then(_$RobotCommand_Turn(
+      speed: null == speed
+          ? _value.speed
+          : speed // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RobotCommand_Turn implements RobotCommand_Turn {
+  const _$RobotCommand_Turn({required this.speed});
+
+  @override
+  final int speed;
+
+  @override
+  String toString() {
+    return 'RobotCommand.turn(speed: $speed)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RobotCommand_Turn &&
+            (identical(other.speed, speed) || other.speed == speed)));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, speed);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RobotCommand_TurnCopyWith<_$RobotCommand_Turn> get copyWith =>
+      __$$RobotCommand_TurnCopyWithImpl<_$RobotCommand_Turn>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) {
+    return turn(speed);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) {
+    return turn?.call(speed);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (turn != null) {
+      return turn(speed);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) {
+    return turn(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) {
+    return turn?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (turn != null) {
+      return turn(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RobotCommand_Turn implements RobotCommand {
+  const factory RobotCommand_Turn(
+      {required final int speed}) = _$RobotCommand_Turn;
+
+  int get speed;
+  @JsonKey(ignore: true)
+  _$$RobotCommand_TurnCopyWith<_$RobotCommand_Turn> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$RobotCommand_SetSpeedsCopyWith<$Res> {
+  factory _$$RobotCommand_SetSpeedsCopyWith(_$RobotCommand_SetSpeeds value,
+          $Res Function(_$RobotCommand_SetSpeeds) then) =
+      __$$RobotCommand_SetSpeedsCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int left, int right});
+}
+
+/// @nodoc
+class __$$RobotCommand_SetSpeedsCopyWithImpl<$Res>
+    extends _$RobotCommandCopyWithImpl<$Res, _$RobotCommand_SetSpeeds>
+    implements _$$RobotCommand_SetSpeedsCopyWith<$Res> {
+  __$$RobotCommand_SetSpeedsCopyWithImpl(_$RobotCommand_SetSpeeds _value,
+      $Res Function(_$RobotCommand_SetSpeeds) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? left = null,
+    Object? right = null,
+  }) {
+    return _then(_$RobotCommand_SetSpeeds(
+      left: null == left
+          ? _value.left
+          : left // ignore: cast_nullable_to_non_nullable
+              as int,
+      right: null == right
+          ? _value.right
+          : right // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$RobotCommand_SetSpeeds implements RobotCommand_SetSpeeds {
+  const _$RobotCommand_SetSpeeds({required this.left, required this.right});
+
+  @override
+  final int left;
+  @override
+  final int right;
+
+  @override
+  String toString() {
+    return 'RobotCommand.setSpeeds(left: $left, right: $right)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RobotCommand_SetSpeeds &&
+            (identical(other.left, left) || other.left == left) &&
+            (identical(other.right, right) || other.right == right)));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, left, right);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RobotCommand_SetSpeedsCopyWith<_$RobotCommand_SetSpeeds> get copyWith =>
+      __$$RobotCommand_SetSpeedsCopyWithImpl<_$RobotCommand_SetSpeeds>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() noOp,
+    required TResult Function() start,
+    required TResult Function() stop,
+    required TResult Function(int speed) drive,
+    required TResult Function(int speed) turn,
+    required TResult Function(int left, int right) setSpeeds,
+  }) {
+    return setSpeeds(left, right);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? noOp,
+    TResult? Function()? start,
+    TResult? Function()? stop,
+    TResult? Function(int speed)? drive,
+    TResult? Function(int speed)? turn,
+    TResult? Function(int left, int right)? setSpeeds,
+  }) {
+    return setSpeeds?.call(left, right);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? noOp,
+    TResult Function()? start,
+    TResult Function()? stop,
+    TResult Function(int speed)? drive,
+    TResult Function(int speed)? turn,
+    TResult Function(int left, int right)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (setSpeeds != null) {
+      return setSpeeds(left, right);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(RobotCommand_NoOp value) noOp,
+    required TResult Function(RobotCommand_Start value) start,
+    required TResult Function(RobotCommand_Stop value) stop,
+    required TResult Function(RobotCommand_Drive value) drive,
+    required TResult Function(RobotCommand_Turn value) turn,
+    required TResult Function(RobotCommand_SetSpeeds value) setSpeeds,
+  }) {
+    return setSpeeds(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(RobotCommand_NoOp value)? noOp,
+    TResult? Function(RobotCommand_Start value)? start,
+    TResult? Function(RobotCommand_Stop value)? stop,
+    TResult? Function(RobotCommand_Drive value)? drive,
+    TResult? Function(RobotCommand_Turn value)? turn,
+    TResult? Function(RobotCommand_SetSpeeds value)? setSpeeds,
+  }) {
+    return setSpeeds?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(RobotCommand_NoOp value)? noOp,
+    TResult Function(RobotCommand_Start value)? start,
+    TResult Function(RobotCommand_Stop value)? stop,
+    TResult Function(RobotCommand_Drive value)? drive,
+    TResult Function(RobotCommand_Turn value)? turn,
+    TResult Function(RobotCommand_SetSpeeds value)? setSpeeds,
+    required TResult orElse(),
+  }) {
+    if (setSpeeds != null) {
+      return setSpeeds(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class RobotCommand_SetSpeeds implements RobotCommand {
+  const factory RobotCommand_SetSpeeds(
+      {required final int left,
+      required final int right}) = _$RobotCommand_SetSpeeds;
+
+  int get left;
+  int get right;
+  @JsonKey(ignore: true)
+  _$$RobotCommand_SetSpeedsCopyWith<_$RobotCommand_SetSpeeds> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -102,6 +102,315 @@ class NativeImpl implements Native {
         argNames: ["k", "examples"],
       );
 
+  Future<String> trainKnnHash(
+      {required int k,
+      required List<LabeledImage> examples,
+      required HashKind kind,
+      dynamic hint}) {
+    var arg0 = api2wire_usize(k);
+    var arg1 = _platform.api2wire_list_labeled_image(examples);
+    var arg2 = api2wire_hash_kind(kind);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_train_knn_hash(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kTrainKnnHashConstMeta,
+      argValues: [k, examples, kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kTrainKnnHashConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "train_knn_hash",
+        argNames: ["k", "examples", "kind"],
+      );
+
+  Future<String> classifyKnnHash({required DartImage img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_dart_image(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_classify_knn_hash(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kClassifyKnnHashConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kClassifyKnnHashConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "classify_knn_hash",
+        argNames: ["img"],
+      );
+
+  Future<String> classifyKnnHashFrame({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_classify_knn_hash_frame(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kClassifyKnnHashFrameConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kClassifyKnnHashFrameConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "classify_knn_hash_frame",
+        argNames: ["img"],
+      );
+
+  Future<int> perceptualHash(
+      {required DartImage img, required HashKind kind, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_dart_image(img);
+    var arg1 = api2wire_hash_kind(kind);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_perceptual_hash(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u64,
+      constMeta: kPerceptualHashConstMeta,
+      argValues: [img, kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPerceptualHashConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "perceptual_hash",
+        argNames: ["img", "kind"],
+      );
+
+  Future<int> framePerceptualHash(
+      {required ImageData img, required HashKind kind, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    var arg1 = api2wire_hash_kind(kind);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_frame_perceptual_hash(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u64,
+      constMeta: kFramePerceptualHashConstMeta,
+      argValues: [img, kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFramePerceptualHashConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "frame_perceptual_hash",
+        argNames: ["img", "kind"],
+      );
+
+  Future<int> hashDistance({required int a, required int b, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(a);
+    var arg1 = _platform.api2wire_u64(b);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_hash_distance(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kHashDistanceConstMeta,
+      argValues: [a, b],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHashDistanceConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "hash_distance",
+        argNames: ["a", "b"],
+      );
+
+  Future<List<LabeledImage>> augmentExamples(
+      {required List<LabeledImage> examples,
+      required AugmentSettings settings,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_list_labeled_image(examples);
+    var arg1 = _platform.api2wire_box_autoadd_augment_settings(settings);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_augment_examples(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_labeled_image,
+      constMeta: kAugmentExamplesConstMeta,
+      argValues: [examples, settings],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAugmentExamplesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "augment_examples",
+        argNames: ["examples", "settings"],
+      );
+
+  Future<String> loadProject(
+      {required String dir,
+      required String project,
+      required int k,
+      required KnnModel model,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(dir);
+    var arg1 = _platform.api2wire_String(project);
+    var arg2 = api2wire_usize(k);
+    var arg3 = api2wire_knn_model(model);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_load_project(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kLoadProjectConstMeta,
+      argValues: [dir, project, k, model],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kLoadProjectConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "load_project",
+        argNames: ["dir", "project", "k", "model"],
+      );
+
+  Future<String> exportProject(
+      {required String dir,
+      required String project,
+      required String archive,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(dir);
+    var arg1 = _platform.api2wire_String(project);
+    var arg2 = _platform.api2wire_String(archive);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_export_project(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kExportProjectConstMeta,
+      argValues: [dir, project, archive],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportProjectConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_project",
+        argNames: ["dir", "project", "archive"],
+      );
+
+  Future<String> importProject(
+      {required String dir,
+      required String project,
+      required String archive,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(dir);
+    var arg1 = _platform.api2wire_String(project);
+    var arg2 = _platform.api2wire_String(archive);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_import_project(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kImportProjectConstMeta,
+      argValues: [dir, project, archive],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kImportProjectConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "import_project",
+        argNames: ["dir", "project", "archive"],
+      );
+
+  Future<DuplicateReport> findDuplicates(
+      {required List<LabeledImage> examples,
+      required double maxDistance,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_list_labeled_image(examples);
+    var arg1 = api2wire_f64(maxDistance);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_find_duplicates(port_, arg0, arg1),
+      parseSuccessData: _wire2api_duplicate_report,
+      constMeta: kFindDuplicatesConstMeta,
+      argValues: [examples, maxDistance],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFindDuplicatesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "find_duplicates",
+        argNames: ["examples", "maxDistance"],
+      );
+
+  Future<DuplicateReport> findHashDuplicates(
+      {required List<LabeledImage> examples,
+      required HashKind kind,
+      required int maxBits,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_list_labeled_image(examples);
+    var arg1 = api2wire_hash_kind(kind);
+    var arg2 = api2wire_u32(maxBits);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_find_hash_duplicates(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_duplicate_report,
+      constMeta: kFindHashDuplicatesConstMeta,
+      argValues: [examples, kind, maxBits],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFindHashDuplicatesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "find_hash_duplicates",
+        argNames: ["examples", "kind", "maxBits"],
+      );
+
+  Future<DuplicateReport> projectDuplicates(
+      {required String dir,
+      required String project,
+      required double maxDistance,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(dir);
+    var arg1 = _platform.api2wire_String(project);
+    var arg2 = api2wire_f64(maxDistance);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_project_duplicates(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_duplicate_report,
+      constMeta: kProjectDuplicatesConstMeta,
+      argValues: [dir, project, maxDistance],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kProjectDuplicatesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "project_duplicates",
+        argNames: ["dir", "project", "maxDistance"],
+      );
+
+  Future<DuplicateReport> projectHashDuplicates(
+      {required String dir,
+      required String project,
+      required HashKind kind,
+      required int maxBits,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(dir);
+    var arg1 = _platform.api2wire_String(project);
+    var arg2 = api2wire_hash_kind(kind);
+    var arg3 = api2wire_u32(maxBits);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_project_hash_duplicates(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_duplicate_report,
+      constMeta: kProjectHashDuplicatesConstMeta,
+      argValues: [dir, project, kind, maxBits],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kProjectHashDuplicatesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "project_hash_duplicates",
+        argNames: ["dir", "project", "kind", "maxBits"],
+      );
+
   Future<String> classifyKnnAkazePos({required DartImage img, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_dart_image(img);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -139,6 +448,23 @@ class NativeImpl implements Native {
         argNames: ["img"],
       );
 
+  Future<void> setPyramidLevel({required int level, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(level);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_pyramid_level(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetPyramidLevelConstMeta,
+      argValues: [level],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetPyramidLevelConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_pyramid_level",
+        argNames: ["level"],
+      );
+
   Future<bool> kmeansReady({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_kmeans_ready(port_),
@@ -172,13 +498,19 @@ class NativeImpl implements Native {
       );
 
   Future<ImageResponse> intensityRgba(
-      {required Uint8List intensities, dynamic hint}) {
+      {required Uint8List intensities,
+      required int width,
+      required int height,
+      dynamic hint}) {
     var arg0 = _platform.api2wire_uint_8_list(intensities);
+    var arg1 = _platform.api2wire_i64(width);
+    var arg2 = _platform.api2wire_i64(height);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_intensity_rgba(port_, arg0),
+      callFfi: (port_) =>
+          _platform.inner.wire_intensity_rgba(port_, arg0, arg1, arg2),
       parseSuccessData: _wire2api_image_response,
       constMeta: kIntensityRgbaConstMeta,
-      argValues: [intensities],
+      argValues: [intensities, width, height],
       hint: hint,
     ));
   }
@@ -186,7 +518,47 @@ class NativeImpl implements Native {
   FlutterRustBridgeTaskConstMeta get kIntensityRgbaConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
         debugName: "intensity_rgba",
-        argNames: ["intensities"],
+        argNames: ["intensities", "width", "height"],
+      );
+
+  Future<void> setColorConversion(
+      {required ColorStandard standard,
+      required ColorRange range,
+      dynamic hint}) {
+    var arg0 = api2wire_color_standard(standard);
+    var arg1 = api2wire_color_range(range);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_color_conversion(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetColorConversionConstMeta,
+      argValues: [standard, range],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetColorConversionConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_color_conversion",
+        argNames: ["standard", "range"],
+      );
+
+  Future<void> setFrameTransform(
+      {required FrameTransform transform, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_frame_transform(transform);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_frame_transform(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetFrameTransformConstMeta,
+      argValues: [transform],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetFrameTransformConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_frame_transform",
+        argNames: ["transform"],
       );
 
   Future<ImageResponse> yuvRgba({required ImageData img, dynamic hint}) {
@@ -240,122 +612,828 @@ class NativeImpl implements Native {
         argNames: ["img"],
       );
 
-  Future<ImageResponse> akazeView({required ImageData img, dynamic hint}) {
+  Future<FreeSpace> floorFreeSpace({required ImageData img, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_image_data(img);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_akaze_view(port_, arg0),
-      parseSuccessData: _wire2api_image_response,
-      constMeta: kAkazeViewConstMeta,
+      callFfi: (port_) => _platform.inner.wire_floor_free_space(port_, arg0),
+      parseSuccessData: _wire2api_free_space,
+      constMeta: kFloorFreeSpaceConstMeta,
       argValues: [img],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAkazeViewConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kFloorFreeSpaceConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "akaze_view",
+        debugName: "floor_free_space",
         argNames: ["img"],
       );
 
-  Future<ImageResponse> akazeFlow({required ImageData img, dynamic hint}) {
+  Future<ImageResponse> floorView({required ImageData img, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_image_data(img);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_akaze_flow(port_, arg0),
+      callFfi: (port_) => _platform.inner.wire_floor_view(port_, arg0),
       parseSuccessData: _wire2api_image_response,
-      constMeta: kAkazeFlowConstMeta,
+      constMeta: kFloorViewConstMeta,
       argValues: [img],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAkazeFlowConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kFloorViewConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "akaze_flow",
+        debugName: "floor_view",
         argNames: ["img"],
       );
 
-  Future<void> resetPositionEstimate({dynamic hint}) {
+  Future<void> setMinBlobArea({required int minArea, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(minArea);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_reset_position_estimate(port_),
+      callFfi: (port_) => _platform.inner.wire_set_min_blob_area(port_, arg0),
       parseSuccessData: _wire2api_unit,
-      constMeta: kResetPositionEstimateConstMeta,
-      argValues: [],
+      constMeta: kSetMinBlobAreaConstMeta,
+      argValues: [minArea],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kResetPositionEstimateConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kSetMinBlobAreaConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "reset_position_estimate",
-        argNames: [],
+        debugName: "set_min_blob_area",
+        argNames: ["minArea"],
       );
 
-  Future<String> processSensorData(
-      {required String incomingData, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(incomingData);
+  Future<void> trackBlobColor(
+      {required int red, required int green, required int blue, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(red);
+    var arg1 = _platform.api2wire_i64(green);
+    var arg2 = _platform.api2wire_i64(blue);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_process_sensor_data(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kProcessSensorDataConstMeta,
-      argValues: [incomingData],
+      callFfi: (port_) =>
+          _platform.inner.wire_track_blob_color(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kTrackBlobColorConstMeta,
+      argValues: [red, green, blue],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kProcessSensorDataConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kTrackBlobColorConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "process_sensor_data",
-        argNames: ["incomingData"],
+        debugName: "track_blob_color",
+        argNames: ["red", "green", "blue"],
       );
 
-  Future<SensorData> parseSensorData(
-      {required String incomingData, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(incomingData);
+  Future<void> stopBlobTracking({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_parse_sensor_data(port_, arg0),
-      parseSuccessData: _wire2api_sensor_data,
-      constMeta: kParseSensorDataConstMeta,
-      argValues: [incomingData],
+      callFfi: (port_) => _platform.inner.wire_stop_blob_tracking(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kStopBlobTrackingConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kParseSensorDataConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kStopBlobTrackingConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "parse_sensor_data",
-        argNames: ["incomingData"],
+        debugName: "stop_blob_tracking",
+        argNames: [],
       );
 
-  void dispose() {
-    _platform.dispose();
+  Future<List<ColorBlob>> detectBlobs({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_detect_blobs(port_, arg0),
+      parseSuccessData: _wire2api_list_color_blob,
+      constMeta: kDetectBlobsConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
   }
-// Section: wire2api
 
-  String _wire2api_String(dynamic raw) {
-    return raw as String;
-  }
+  FlutterRustBridgeTaskConstMeta get kDetectBlobsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "detect_blobs",
+        argNames: ["img"],
+      );
 
-  Uint8List _wire2api_ZeroCopyBuffer_Uint8List(dynamic raw) {
-    return raw as Uint8List;
+  Future<ImageResponse> blobView({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_blob_view(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kBlobViewConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
   }
 
-  bool _wire2api_bool(dynamic raw) {
-    return raw as bool;
-  }
+  FlutterRustBridgeTaskConstMeta get kBlobViewConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "blob_view",
+        argNames: ["img"],
+      );
 
-  int _wire2api_i64(dynamic raw) {
-    return castInt(raw);
+  Future<void> setColorTarget({required ColorTarget target, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_color_target(target);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_color_target(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetColorTargetConstMeta,
+      argValues: [target],
+      hint: hint,
+    ));
   }
 
-  ImageResponse _wire2api_image_response(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ImageResponse(
-      img: _wire2api_ZeroCopyBuffer_Uint8List(arr[0]),
-      msg: _wire2api_String(arr[1]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kSetColorTargetConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_color_target",
+        argNames: ["target"],
+      );
+
+  Future<void> setTrackingSpeeds(
+      {required int cruise, required int turn, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(cruise);
+    var arg1 = _platform.api2wire_i64(turn);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_tracking_speeds(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetTrackingSpeedsConstMeta,
+      argValues: [cruise, turn],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetTrackingSpeedsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_tracking_speeds",
+        argNames: ["cruise", "turn"],
+      );
+
+  Future<TrackingCommand> trackColorTarget(
+      {required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_track_color_target(port_, arg0),
+      parseSuccessData: _wire2api_tracking_command,
+      constMeta: kTrackColorTargetConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kTrackColorTargetConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "track_color_target",
+        argNames: ["img"],
+      );
+
+  Future<ImageResponse> colorTargetView(
+      {required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_color_target_view(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kColorTargetViewConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kColorTargetViewConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "color_target_view",
+        argNames: ["img"],
+      );
+
+  Future<ImageResponse> sobelView({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_sobel_view(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kSobelViewConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSobelViewConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "sobel_view",
+        argNames: ["img"],
+      );
+
+  Future<void> setCannyThresholds(
+      {required double low, required double high, dynamic hint}) {
+    var arg0 = api2wire_f64(low);
+    var arg1 = api2wire_f64(high);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_canny_thresholds(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetCannyThresholdsConstMeta,
+      argValues: [low, high],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetCannyThresholdsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_canny_thresholds",
+        argNames: ["low", "high"],
+      );
+
+  Future<ImageResponse> cannyView({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_canny_view(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kCannyViewConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCannyViewConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "canny_view",
+        argNames: ["img"],
+      );
+
+  Future<void> setLineDetection(
+      {required int minVotes, required int maxLines, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(minVotes);
+    var arg1 = _platform.api2wire_i64(maxLines);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_line_detection(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetLineDetectionConstMeta,
+      argValues: [minVotes, maxLines],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetLineDetectionConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_line_detection",
+        argNames: ["minVotes", "maxLines"],
+      );
+
+  Future<List<LineSegment>> detectLines(
+      {required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_detect_lines(port_, arg0),
+      parseSuccessData: _wire2api_list_line_segment,
+      constMeta: kDetectLinesConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDetectLinesConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "detect_lines",
+        argNames: ["img"],
+      );
+
+  Future<ImageResponse> linesView({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_lines_view(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kLinesViewConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kLinesViewConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "lines_view",
+        argNames: ["img"],
+      );
+
+  Future<ImageResponse> akazeView({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_akaze_view(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kAkazeViewConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAkazeViewConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "akaze_view",
+        argNames: ["img"],
+      );
+
+  Future<ImageResponse> akazeFlow({required ImageData img, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_image_data(img);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_akaze_flow(port_, arg0),
+      parseSuccessData: _wire2api_image_response,
+      constMeta: kAkazeFlowConstMeta,
+      argValues: [img],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAkazeFlowConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "akaze_flow",
+        argNames: ["img"],
+      );
+
+  Future<void> resetAkazeFlow({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_reset_akaze_flow(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kResetAkazeFlowConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kResetAkazeFlowConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "reset_akaze_flow",
+        argNames: [],
+      );
+
+  Future<void> resetPositionEstimate({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_reset_position_estimate(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kResetPositionEstimateConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kResetPositionEstimateConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "reset_position_estimate",
+        argNames: [],
+      );
+
+  Future<String> processSensorData(
+      {required String incomingData, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(incomingData);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_process_sensor_data(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kProcessSensorDataConstMeta,
+      argValues: [incomingData],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kProcessSensorDataConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "process_sensor_data",
+        argNames: ["incomingData"],
+      );
+
+  Future<void> startRecording({required String path, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_start_recording(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kStartRecordingConstMeta,
+      argValues: [path],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kStartRecordingConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "start_recording",
+        argNames: ["path"],
+      );
+
+  Future<void> stopRecording({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_stop_recording(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kStopRecordingConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kStopRecordingConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "stop_recording",
+        argNames: [],
+      );
+
+  Future<bool> isRecording({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_is_recording(port_),
+      parseSuccessData: _wire2api_bool,
+      constMeta: kIsRecordingConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIsRecordingConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "is_recording",
+        argNames: [],
+      );
+
+  Future<List<String>> replayRecording({required String path, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_replay_recording(port_, arg0),
+      parseSuccessData: _wire2api_StringList,
+      constMeta: kReplayRecordingConstMeta,
+      argValues: [path],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReplayRecordingConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "replay_recording",
+        argNames: ["path"],
+      );
+
+  Future<void> setSonarSettings(
+      {required SonarSettings settings, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_sonar_settings(settings);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_sonar_settings(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetSonarSettingsConstMeta,
+      argValues: [settings],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetSonarSettingsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_sonar_settings",
+        argNames: ["settings"],
+      );
+
+  Future<void> setController({required ControllerKind kind, dynamic hint}) {
+    var arg0 = api2wire_controller_kind(kind);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_controller(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetControllerConstMeta,
+      argValues: [kind],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetControllerConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_controller",
+        argNames: ["kind"],
+      );
+
+  Future<void> queueRobotCommand(
+      {required RobotCommand command, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_robot_command(command);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_queue_robot_command(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kQueueRobotCommandConstMeta,
+      argValues: [command],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kQueueRobotCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "queue_robot_command",
+        argNames: ["command"],
+      );
+
+  Future<RobotCommand> nextRobotCommand(
+      {required SensorData sensors, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_sensor_data(sensors);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_robot_command(port_, arg0),
+      parseSuccessData: _wire2api_robot_command,
+      constMeta: kNextRobotCommandConstMeta,
+      argValues: [sensors],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextRobotCommandConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_robot_command",
+        argNames: ["sensors"],
+      );
+
+  Future<void> setLabelAction(
+      {required String label, required RobotCommand command, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(label);
+    var arg1 = _platform.api2wire_box_autoadd_robot_command(command);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_label_action(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetLabelActionConstMeta,
+      argValues: [label, command],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetLabelActionConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_label_action",
+        argNames: ["label", "command"],
+      );
+
+  Future<void> clearLabelActions({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_clear_label_actions(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kClearLabelActionsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kClearLabelActionsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "clear_label_actions",
+        argNames: [],
+      );
+
+  Future<void> setLabelVoting(
+      {required int window, required int hold, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(window);
+    var arg1 = _platform.api2wire_i64(hold);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_set_label_voting(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetLabelVotingConstMeta,
+      argValues: [window, hold],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetLabelVotingConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_label_voting",
+        argNames: ["window", "hold"],
+      );
+
+  Future<RobotCommand> observeLabel({required String label, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(label);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_observe_label(port_, arg0),
+      parseSuccessData: _wire2api_robot_command,
+      constMeta: kObserveLabelConstMeta,
+      argValues: [label],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kObserveLabelConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "observe_label",
+        argNames: ["label"],
+      );
+
+  Future<String> robotCommandWire(
+      {required RobotCommand command, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_robot_command(command);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_robot_command_wire(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kRobotCommandWireConstMeta,
+      argValues: [command],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRobotCommandWireConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "robot_command_wire",
+        argNames: ["command"],
+      );
+
+  Future<SensorData> parseSensorData(
+      {required String incomingData, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(incomingData);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_parse_sensor_data(port_, arg0),
+      parseSuccessData: _wire2api_sensor_data,
+      constMeta: kParseSensorDataConstMeta,
+      argValues: [incomingData],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseSensorDataConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_sensor_data",
+        argNames: ["incomingData"],
+      );
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
+
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
+
+  Uint8List _wire2api_ZeroCopyBuffer_Uint8List(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
+
+  ColorBlob _wire2api_color_blob(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ColorBlob(
+      red: _wire2api_i64(arr[0]),
+      green: _wire2api_i64(arr[1]),
+      blue: _wire2api_i64(arr[2]),
+      area: _wire2api_i64(arr[3]),
+      centroidX: _wire2api_f64(arr[4]),
+      centroidY: _wire2api_f64(arr[5]),
+      left: _wire2api_i64(arr[6]),
+      top: _wire2api_i64(arr[7]),
+      right: _wire2api_i64(arr[8]),
+      bottom: _wire2api_i64(arr[9]),
+    );
+  }
+
+  DartImage _wire2api_dart_image(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DartImage(
+      bytes: _wire2api_uint_8_list(arr[0]),
+      width: _wire2api_i64(arr[1]),
+      height: _wire2api_i64(arr[2]),
+    );
+  }
+
+  DuplicateGroup _wire2api_duplicate_group(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DuplicateGroup(
+      label: _wire2api_String(arr[0]),
+      members: _wire2api_int_64_list(arr[1]),
+    );
+  }
+
+  DuplicateReport _wire2api_duplicate_report(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DuplicateReport(
+      names: _wire2api_StringList(arr[0]),
+      groups: _wire2api_list_duplicate_group(arr[1]),
+      conflicts: _wire2api_list_label_conflict(arr[2]),
+    );
+  }
+
+  double _wire2api_f64(dynamic raw) {
+    return raw as double;
+  }
+
+  FreeSpace _wire2api_free_space(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FreeSpace(
+      mask: _wire2api_ZeroCopyBuffer_Uint8List(arr[0]),
+      width: _wire2api_i64(arr[1]),
+      height: _wire2api_i64(arr[2]),
+      columnHeights: _wire2api_int_64_list(arr[3]),
+    );
+  }
+
+  int _wire2api_i64(dynamic raw) {
+    return castInt(raw);
+  }
+
+  ImageResponse _wire2api_image_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImageResponse(
+      img: _wire2api_ZeroCopyBuffer_Uint8List(arr[0]),
+      msg: _wire2api_String(arr[1]),
+      width: _wire2api_i64(arr[2]),
+      height: _wire2api_i64(arr[3]),
+    );
+  }
+
+  Int64List _wire2api_int_64_list(dynamic raw) {
+    return Int64List.from(raw);
+  }
+
+  LabelConflict _wire2api_label_conflict(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LabelConflict(
+      first: _wire2api_i64(arr[0]),
+      second: _wire2api_i64(arr[1]),
+      distance: _wire2api_f64(arr[2]),
+    );
+  }
+
+  LabeledImage _wire2api_labeled_image(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LabeledImage(
+      label: _wire2api_String(arr[0]),
+      image: _wire2api_dart_image(arr[1]),
+    );
+  }
+
+  LineSegment _wire2api_line_segment(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return LineSegment(
+      rho: _wire2api_f64(arr[0]),
+      theta: _wire2api_f64(arr[1]),
+      x1: _wire2api_f64(arr[2]),
+      y1: _wire2api_f64(arr[3]),
+      x2: _wire2api_f64(arr[4]),
+      y2: _wire2api_f64(arr[5]),
+      votes: _wire2api_i64(arr[6]),
+    );
+  }
+
+  List<ColorBlob> _wire2api_list_color_blob(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_color_blob).toList();
+  }
+
+  List<DuplicateGroup> _wire2api_list_duplicate_group(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_duplicate_group).toList();
+  }
+
+  List<LabelConflict> _wire2api_list_label_conflict(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_label_conflict).toList();
+  }
+
+  List<LabeledImage> _wire2api_list_labeled_image(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_labeled_image).toList();
+  }
+
+  List<LineSegment> _wire2api_list_line_segment(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_line_segment).toList();
+  }
+
+  RobotCommand _wire2api_robot_command(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return RobotCommand_NoOp();
+      case 1:
+        return RobotCommand_Start();
+      case 2:
+        return RobotCommand_Stop();
+      case 3:
+        return RobotCommand_Drive(
+          speed: _wire2api_i64(raw[1]),
+        );
+      case 4:
+        return RobotCommand_Turn(
+          speed: _wire2api_i64(raw[1]),
+        );
+      case 5:
+        return RobotCommand_SetSpeeds(
+          left: _wire2api_i64(raw[1]),
+          right: _wire2api_i64(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
 
   SensorData _wire2api_sensor_data(dynamic raw) {
     final arr = raw as List<dynamic>;
@@ -372,483 +1450,1615 @@ class NativeImpl implements Native {
     );
   }
 
-  int _wire2api_u8(dynamic raw) {
-    return raw as int;
+  TrackingCommand _wire2api_tracking_command(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TrackingCommand(
+      found: _wire2api_bool(arr[0]),
+      leftSpeed: _wire2api_i64(arr[1]),
+      rightSpeed: _wire2api_i64(arr[2]),
+      centroidX: _wire2api_f64(arr[3]),
+      centroidY: _wire2api_f64(arr[4]),
+      area: _wire2api_i64(arr[5]),
+    );
+  }
+
+  int _wire2api_u32(dynamic raw) {
+    return raw as int;
+  }
+
+  int _wire2api_u64(dynamic raw) {
+    return castInt(raw);
+  }
+
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
+
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
+}
+
+// Section: api2wire
+
+@protected
+bool api2wire_bool(bool raw) {
+  return raw;
+}
+
+@protected
+int api2wire_color_range(ColorRange raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_color_space(ColorSpace raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_color_standard(ColorStandard raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_controller_kind(ControllerKind raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+double api2wire_f64(double raw) {
+  return raw;
+}
+
+@protected
+int api2wire_hash_kind(HashKind raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_i32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_knn_model(KnnModel raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_pixel_format(PixelFormat raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_rotation(Rotation raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_u32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_u8(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_usize(int raw) {
+  return raw;
+}
+// Section: finalizer
+
+class NativePlatform extends FlutterRustBridgeBase<NativeWire> {
+  NativePlatform(ffi.DynamicLibrary dylib) : super(NativeWire(dylib));
+
+// Section: api2wire
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
+    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_AugmentSettings> api2wire_box_autoadd_augment_settings(
+      AugmentSettings raw) {
+    final ptr = inner.new_box_autoadd_augment_settings_0();
+    _api_fill_to_wire_augment_settings(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ColorTarget> api2wire_box_autoadd_color_target(
+      ColorTarget raw) {
+    final ptr = inner.new_box_autoadd_color_target_0();
+    _api_fill_to_wire_color_target(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_DartImage> api2wire_box_autoadd_dart_image(DartImage raw) {
+    final ptr = inner.new_box_autoadd_dart_image_0();
+    _api_fill_to_wire_dart_image(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_FrameTransform> api2wire_box_autoadd_frame_transform(
+      FrameTransform raw) {
+    final ptr = inner.new_box_autoadd_frame_transform_0();
+    _api_fill_to_wire_frame_transform(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ImageData> api2wire_box_autoadd_image_data(ImageData raw) {
+    final ptr = inner.new_box_autoadd_image_data_0();
+    _api_fill_to_wire_image_data(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_RobotCommand> api2wire_box_autoadd_robot_command(
+      RobotCommand raw) {
+    final ptr = inner.new_box_autoadd_robot_command_0();
+    _api_fill_to_wire_robot_command(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_SensorData> api2wire_box_autoadd_sensor_data(
+      SensorData raw) {
+    final ptr = inner.new_box_autoadd_sensor_data_0();
+    _api_fill_to_wire_sensor_data(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_SonarSettings> api2wire_box_autoadd_sonar_settings(
+      SonarSettings raw) {
+    final ptr = inner.new_box_autoadd_sonar_settings_0();
+    _api_fill_to_wire_sonar_settings(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  int api2wire_i64(int raw) {
+    return raw;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_labeled_image> api2wire_list_labeled_image(
+      List<LabeledImage> raw) {
+    final ans = inner.new_list_labeled_image_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_labeled_image(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  int api2wire_u64(int raw) {
+    return raw;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
+    final ans = inner.new_uint_8_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+// Section: finalizer
+
+// Section: api_fill_to_wire
+
+  void _api_fill_to_wire_augment_settings(
+      AugmentSettings apiObj, wire_AugmentSettings wireObj) {
+    wireObj.copies = api2wire_i64(apiObj.copies);
+    wireObj.flip = api2wire_bool(apiObj.flip);
+    wireObj.max_rotation_degrees = api2wire_f64(apiObj.maxRotationDegrees);
+    wireObj.max_shift = api2wire_f64(apiObj.maxShift);
+    wireObj.max_brightness = api2wire_f64(apiObj.maxBrightness);
+    wireObj.max_contrast = api2wire_f64(apiObj.maxContrast);
+    wireObj.max_noise = api2wire_f64(apiObj.maxNoise);
+    wireObj.seed = api2wire_i64(apiObj.seed);
+  }
+
+  void _api_fill_to_wire_box_autoadd_augment_settings(
+      AugmentSettings apiObj, ffi.Pointer<wire_AugmentSettings> wireObj) {
+    _api_fill_to_wire_augment_settings(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_color_target(
+      ColorTarget apiObj, ffi.Pointer<wire_ColorTarget> wireObj) {
+    _api_fill_to_wire_color_target(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_dart_image(
+      DartImage apiObj, ffi.Pointer<wire_DartImage> wireObj) {
+    _api_fill_to_wire_dart_image(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_frame_transform(
+      FrameTransform apiObj, ffi.Pointer<wire_FrameTransform> wireObj) {
+    _api_fill_to_wire_frame_transform(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_image_data(
+      ImageData apiObj, ffi.Pointer<wire_ImageData> wireObj) {
+    _api_fill_to_wire_image_data(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_robot_command(
+      RobotCommand apiObj, ffi.Pointer<wire_RobotCommand> wireObj) {
+    _api_fill_to_wire_robot_command(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_sensor_data(
+      SensorData apiObj, ffi.Pointer<wire_SensorData> wireObj) {
+    _api_fill_to_wire_sensor_data(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_sonar_settings(
+      SonarSettings apiObj, ffi.Pointer<wire_SonarSettings> wireObj) {
+    _api_fill_to_wire_sonar_settings(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_color_target(
+      ColorTarget apiObj, wire_ColorTarget wireObj) {
+    wireObj.red = api2wire_i64(apiObj.red);
+    wireObj.green = api2wire_i64(apiObj.green);
+    wireObj.blue = api2wire_i64(apiObj.blue);
+    wireObj.tolerance = api2wire_i64(apiObj.tolerance);
+    wireObj.space = api2wire_color_space(apiObj.space);
+    wireObj.min_area = api2wire_i64(apiObj.minArea);
+  }
+
+  void _api_fill_to_wire_dart_image(DartImage apiObj, wire_DartImage wireObj) {
+    wireObj.bytes = api2wire_uint_8_list(apiObj.bytes);
+    wireObj.width = api2wire_i64(apiObj.width);
+    wireObj.height = api2wire_i64(apiObj.height);
+  }
+
+  void _api_fill_to_wire_frame_transform(
+      FrameTransform apiObj, wire_FrameTransform wireObj) {
+    wireObj.rotation = api2wire_rotation(apiObj.rotation);
+    wireObj.mirror = api2wire_bool(apiObj.mirror);
+    wireObj.crop_x = api2wire_i64(apiObj.cropX);
+    wireObj.crop_y = api2wire_i64(apiObj.cropY);
+    wireObj.crop_width = api2wire_i64(apiObj.cropWidth);
+    wireObj.crop_height = api2wire_i64(apiObj.cropHeight);
+  }
+
+  void _api_fill_to_wire_image_data(ImageData apiObj, wire_ImageData wireObj) {
+    wireObj.ys = api2wire_uint_8_list(apiObj.ys);
+    wireObj.us = api2wire_uint_8_list(apiObj.us);
+    wireObj.vs = api2wire_uint_8_list(apiObj.vs);
+    wireObj.width = api2wire_i64(apiObj.width);
+    wireObj.height = api2wire_i64(apiObj.height);
+    wireObj.y_row_stride = api2wire_i64(apiObj.yRowStride);
+    wireObj.uv_row_stride = api2wire_i64(apiObj.uvRowStride);
+    wireObj.uv_pixel_stride = api2wire_i64(apiObj.uvPixelStride);
+    wireObj.format = api2wire_pixel_format(apiObj.format);
+  }
+
+  void _api_fill_to_wire_labeled_image(
+      LabeledImage apiObj, wire_LabeledImage wireObj) {
+    wireObj.label = api2wire_String(apiObj.label);
+    _api_fill_to_wire_dart_image(apiObj.image, wireObj.image);
+  }
+
+  void _api_fill_to_wire_robot_command(
+      RobotCommand apiObj, wire_RobotCommand wireObj) {
+    if (apiObj is RobotCommand_NoOp) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is RobotCommand_Start) {
+      wireObj.tag = 1;
+      return;
+    }
+    if (apiObj is RobotCommand_Stop) {
+      wireObj.tag = 2;
+      return;
+    }
+    if (apiObj is RobotCommand_Drive) {
+      var pre_speed = api2wire_i64(apiObj.speed);
+      wireObj.tag = 3;
+      wireObj.kind = inner.inflate_RobotCommand_Drive();
+      wireObj.kind.ref.Drive.ref.speed = pre_speed;
+      return;
+    }
+    if (apiObj is RobotCommand_Turn) {
+      var pre_speed = api2wire_i64(apiObj.speed);
+      wireObj.tag = 4;
+      wireObj.kind = inner.inflate_RobotCommand_Turn();
+      wireObj.kind.ref.Turn.ref.speed = pre_speed;
+      return;
+    }
+    if (apiObj is RobotCommand_SetSpeeds) {
+      var pre_left = api2wire_i64(apiObj.left);
+      var pre_right = api2wire_i64(apiObj.right);
+      wireObj.tag = 5;
+      wireObj.kind = inner.inflate_RobotCommand_SetSpeeds();
+      wireObj.kind.ref.SetSpeeds.ref.left = pre_left;
+      wireObj.kind.ref.SetSpeeds.ref.right = pre_right;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_sensor_data(
+      SensorData apiObj, wire_SensorData wireObj) {
+    wireObj.sonar_front = api2wire_i64(apiObj.sonarFront);
+    wireObj.sonar_left = api2wire_i64(apiObj.sonarLeft);
+    wireObj.sonar_right = api2wire_i64(apiObj.sonarRight);
+    wireObj.left_count = api2wire_i64(apiObj.leftCount);
+    wireObj.right_count = api2wire_i64(apiObj.rightCount);
+    wireObj.left_speed = api2wire_i64(apiObj.leftSpeed);
+    wireObj.right_speed = api2wire_i64(apiObj.rightSpeed);
+  }
+
+  void _api_fill_to_wire_sonar_settings(
+      SonarSettings apiObj, wire_SonarSettings wireObj) {
+    wireObj.obstacle_distance = api2wire_i64(apiObj.obstacleDistance);
+    wireObj.wall_distance = api2wire_i64(apiObj.wallDistance);
+    wireObj.cruise_speed = api2wire_i64(apiObj.cruiseSpeed);
+    wireObj.turn_speed = api2wire_i64(apiObj.turnSpeed);
+  }
+}
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+
+/// generated by flutter_rust_bridge
+class NativeWire implements FlutterRustBridgeWireBase {
+  @internal
+  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  NativeWire(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  NativeWire.fromLookup(
+      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+          lookup)
+      : _lookup = lookup;
+
+  void store_dart_post_cobject(
+    DartPostCObjectFnType ptr,
+  ) {
+    return _store_dart_post_cobject(
+      ptr,
+    );
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+          'store_dart_post_cobject');
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
+      .asFunction<void Function(DartPostCObjectFnType)>();
+
+  Object get_dart_object(
+    int ptr,
+  ) {
+    return _get_dart_object(
+      ptr,
+    );
+  }
+
+  late final _get_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
+          'get_dart_object');
+  late final _get_dart_object =
+      _get_dart_objectPtr.asFunction<Object Function(int)>();
+
+  void drop_dart_object(
+    int ptr,
+  ) {
+    return _drop_dart_object(
+      ptr,
+    );
+  }
+
+  late final _drop_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
+          'drop_dart_object');
+  late final _drop_dart_object =
+      _drop_dart_objectPtr.asFunction<void Function(int)>();
+
+  int new_dart_opaque(
+    Object handle,
+  ) {
+    return _new_dart_opaque(
+      handle,
+    );
+  }
+
+  late final _new_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
+          'new_dart_opaque');
+  late final _new_dart_opaque =
+      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+
+  int init_frb_dart_api_dl(
+    ffi.Pointer<ffi.Void> obj,
+  ) {
+    return _init_frb_dart_api_dl(
+      obj,
+    );
+  }
+
+  late final _init_frb_dart_api_dlPtr =
+      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
+          'init_frb_dart_api_dl');
+  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
+      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+
+  void wire_train_knn(
+    int port_,
+    int k,
+    ffi.Pointer<wire_list_labeled_image> examples,
+  ) {
+    return _wire_train_knn(
+      port_,
+      k,
+      examples,
+    );
+  }
+
+  late final _wire_train_knnPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.UintPtr,
+              ffi.Pointer<wire_list_labeled_image>)>>('wire_train_knn');
+  late final _wire_train_knn = _wire_train_knnPtr.asFunction<
+      void Function(int, int, ffi.Pointer<wire_list_labeled_image>)>();
+
+  void wire_classify_knn(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> img,
+  ) {
+    return _wire_classify_knn(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_classify_knnPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_classify_knn');
+  late final _wire_classify_knn = _wire_classify_knnPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_train_knn_akaze_pos(
+    int port_,
+    int k,
+    ffi.Pointer<wire_list_labeled_image> examples,
+  ) {
+    return _wire_train_knn_akaze_pos(
+      port_,
+      k,
+      examples,
+    );
+  }
+
+  late final _wire_train_knn_akaze_posPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr,
+                  ffi.Pointer<wire_list_labeled_image>)>>(
+      'wire_train_knn_akaze_pos');
+  late final _wire_train_knn_akaze_pos =
+      _wire_train_knn_akaze_posPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_list_labeled_image>)>();
+
+  void wire_train_knn_akaze_features(
+    int port_,
+    int k,
+    ffi.Pointer<wire_list_labeled_image> examples,
+  ) {
+    return _wire_train_knn_akaze_features(
+      port_,
+      k,
+      examples,
+    );
+  }
+
+  late final _wire_train_knn_akaze_featuresPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr,
+                  ffi.Pointer<wire_list_labeled_image>)>>(
+      'wire_train_knn_akaze_features');
+  late final _wire_train_knn_akaze_features =
+      _wire_train_knn_akaze_featuresPtr.asFunction<
+          void Function(int, int, ffi.Pointer<wire_list_labeled_image>)>();
+
+  void wire_train_knn_hash(
+    int port_,
+    int k,
+    ffi.Pointer<wire_list_labeled_image> examples,
+    int kind,
+  ) {
+    return _wire_train_knn_hash(
+      port_,
+      k,
+      examples,
+      kind,
+    );
+  }
+
+  late final _wire_train_knn_hashPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.UintPtr,
+              ffi.Pointer<wire_list_labeled_image>,
+              ffi.Int32)>>('wire_train_knn_hash');
+  late final _wire_train_knn_hash = _wire_train_knn_hashPtr.asFunction<
+      void Function(int, int, ffi.Pointer<wire_list_labeled_image>, int)>();
+
+  void wire_classify_knn_hash(
+    int port_,
+    ffi.Pointer<wire_DartImage> img,
+  ) {
+    return _wire_classify_knn_hash(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_classify_knn_hashPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_DartImage>)>>('wire_classify_knn_hash');
+  late final _wire_classify_knn_hash = _wire_classify_knn_hashPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_DartImage>)>();
+
+  void wire_classify_knn_hash_frame(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_classify_knn_hash_frame(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_classify_knn_hash_framePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_ImageData>)>>('wire_classify_knn_hash_frame');
+  late final _wire_classify_knn_hash_frame = _wire_classify_knn_hash_framePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_perceptual_hash(
+    int port_,
+    ffi.Pointer<wire_DartImage> img,
+    int kind,
+  ) {
+    return _wire_perceptual_hash(
+      port_,
+      img,
+      kind,
+    );
+  }
+
+  late final _wire_perceptual_hashPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DartImage>,
+              ffi.Int32)>>('wire_perceptual_hash');
+  late final _wire_perceptual_hash = _wire_perceptual_hashPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_DartImage>, int)>();
+
+  void wire_frame_perceptual_hash(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+    int kind,
+  ) {
+    return _wire_frame_perceptual_hash(
+      port_,
+      img,
+      kind,
+    );
+  }
+
+  late final _wire_frame_perceptual_hashPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ImageData>,
+              ffi.Int32)>>('wire_frame_perceptual_hash');
+  late final _wire_frame_perceptual_hash = _wire_frame_perceptual_hashPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>, int)>();
+
+  void wire_hash_distance(
+    int port_,
+    int a,
+    int b,
+  ) {
+    return _wire_hash_distance(
+      port_,
+      a,
+      b,
+    );
+  }
+
+  late final _wire_hash_distancePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Uint64, ffi.Uint64)>>('wire_hash_distance');
+  late final _wire_hash_distance =
+      _wire_hash_distancePtr.asFunction<void Function(int, int, int)>();
+
+  void wire_augment_examples(
+    int port_,
+    ffi.Pointer<wire_list_labeled_image> examples,
+    ffi.Pointer<wire_AugmentSettings> settings,
+  ) {
+    return _wire_augment_examples(
+      port_,
+      examples,
+      settings,
+    );
+  }
+
+  late final _wire_augment_examplesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_labeled_image>,
+              ffi.Pointer<wire_AugmentSettings>)>>('wire_augment_examples');
+  late final _wire_augment_examples = _wire_augment_examplesPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_list_labeled_image>,
+          ffi.Pointer<wire_AugmentSettings>)>();
+
+  void wire_load_project(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> dir,
+    ffi.Pointer<wire_uint_8_list> project,
+    int k,
+    int model,
+  ) {
+    return _wire_load_project(
+      port_,
+      dir,
+      project,
+      k,
+      model,
+    );
+  }
+
+  late final _wire_load_projectPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>, ffi.UintPtr,
+              ffi.Int32)>>('wire_load_project');
+  late final _wire_load_project = _wire_load_projectPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_uint_8_list>, int, int)>();
+
+  void wire_export_project(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> dir,
+    ffi.Pointer<wire_uint_8_list> project,
+    ffi.Pointer<wire_uint_8_list> archive,
+  ) {
+    return _wire_export_project(
+      port_,
+      dir,
+      project,
+      archive,
+    );
   }
 
-  Uint8List _wire2api_uint_8_list(dynamic raw) {
-    return raw as Uint8List;
+  late final _wire_export_projectPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_export_project');
+  late final _wire_export_project = _wire_export_projectPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_import_project(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> dir,
+    ffi.Pointer<wire_uint_8_list> project,
+    ffi.Pointer<wire_uint_8_list> archive,
+  ) {
+    return _wire_import_project(
+      port_,
+      dir,
+      project,
+      archive,
+    );
   }
 
-  void _wire2api_unit(dynamic raw) {
-    return;
+  late final _wire_import_projectPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_import_project');
+  late final _wire_import_project = _wire_import_projectPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_find_duplicates(
+    int port_,
+    ffi.Pointer<wire_list_labeled_image> examples,
+    double max_distance,
+  ) {
+    return _wire_find_duplicates(
+      port_,
+      examples,
+      max_distance,
+    );
   }
-}
 
-// Section: api2wire
+  late final _wire_find_duplicatesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_labeled_image>,
+              ffi.Double)>>('wire_find_duplicates');
+  late final _wire_find_duplicates = _wire_find_duplicatesPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_list_labeled_image>, double)>();
 
-@protected
-int api2wire_u8(int raw) {
-  return raw;
-}
+  void wire_find_hash_duplicates(
+    int port_,
+    ffi.Pointer<wire_list_labeled_image> examples,
+    int kind,
+    int max_bits,
+  ) {
+    return _wire_find_hash_duplicates(
+      port_,
+      examples,
+      kind,
+      max_bits,
+    );
+  }
 
-@protected
-int api2wire_usize(int raw) {
-  return raw;
-}
-// Section: finalizer
+  late final _wire_find_hash_duplicatesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_labeled_image>,
+              ffi.Int32, ffi.Uint32)>>('wire_find_hash_duplicates');
+  late final _wire_find_hash_duplicates =
+      _wire_find_hash_duplicatesPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_list_labeled_image>, int, int)>();
 
-class NativePlatform extends FlutterRustBridgeBase<NativeWire> {
-  NativePlatform(ffi.DynamicLibrary dylib) : super(NativeWire(dylib));
+  void wire_project_duplicates(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> dir,
+    ffi.Pointer<wire_uint_8_list> project,
+    double max_distance,
+  ) {
+    return _wire_project_duplicates(
+      port_,
+      dir,
+      project,
+      max_distance,
+    );
+  }
 
-// Section: api2wire
+  late final _wire_project_duplicatesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Double)>>('wire_project_duplicates');
+  late final _wire_project_duplicates = _wire_project_duplicatesPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_uint_8_list>, double)>();
+
+  void wire_project_hash_duplicates(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> dir,
+    ffi.Pointer<wire_uint_8_list> project,
+    int kind,
+    int max_bits,
+  ) {
+    return _wire_project_hash_duplicates(
+      port_,
+      dir,
+      project,
+      kind,
+      max_bits,
+    );
+  }
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
-    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  late final _wire_project_hash_duplicatesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Int32,
+              ffi.Uint32)>>('wire_project_hash_duplicates');
+  late final _wire_project_hash_duplicates =
+      _wire_project_hash_duplicatesPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>, int, int)>();
+
+  void wire_classify_knn_akaze_pos(
+    int port_,
+    ffi.Pointer<wire_DartImage> img,
+  ) {
+    return _wire_classify_knn_akaze_pos(
+      port_,
+      img,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_DartImage> api2wire_box_autoadd_dart_image(DartImage raw) {
-    final ptr = inner.new_box_autoadd_dart_image_0();
-    _api_fill_to_wire_dart_image(raw, ptr.ref);
-    return ptr;
+  late final _wire_classify_knn_akaze_posPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_DartImage>)>>('wire_classify_knn_akaze_pos');
+  late final _wire_classify_knn_akaze_pos = _wire_classify_knn_akaze_posPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_DartImage>)>();
+
+  void wire_classify_knn_akaze_feature(
+    int port_,
+    ffi.Pointer<wire_DartImage> img,
+  ) {
+    return _wire_classify_knn_akaze_feature(
+      port_,
+      img,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_ImageData> api2wire_box_autoadd_image_data(ImageData raw) {
-    final ptr = inner.new_box_autoadd_image_data_0();
-    _api_fill_to_wire_image_data(raw, ptr.ref);
-    return ptr;
+  late final _wire_classify_knn_akaze_featurePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_DartImage>)>>('wire_classify_knn_akaze_feature');
+  late final _wire_classify_knn_akaze_feature =
+      _wire_classify_knn_akaze_featurePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_DartImage>)>();
+
+  void wire_set_pyramid_level(
+    int port_,
+    int level,
+  ) {
+    return _wire_set_pyramid_level(
+      port_,
+      level,
+    );
   }
 
-  @protected
-  int api2wire_i64(int raw) {
-    return raw;
+  late final _wire_set_pyramid_levelPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>(
+          'wire_set_pyramid_level');
+  late final _wire_set_pyramid_level =
+      _wire_set_pyramid_levelPtr.asFunction<void Function(int, int)>();
+
+  void wire_kmeans_ready(
+    int port_,
+  ) {
+    return _wire_kmeans_ready(
+      port_,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_list_labeled_image> api2wire_list_labeled_image(
-      List<LabeledImage> raw) {
-    final ans = inner.new_list_labeled_image_0(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      _api_fill_to_wire_labeled_image(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
+  late final _wire_kmeans_readyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_kmeans_ready');
+  late final _wire_kmeans_ready =
+      _wire_kmeans_readyPtr.asFunction<void Function(int)>();
+
+  void wire_training_time(
+    int port_,
+  ) {
+    return _wire_training_time(
+      port_,
+    );
+  }
+
+  late final _wire_training_timePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_training_time');
+  late final _wire_training_time =
+      _wire_training_timePtr.asFunction<void Function(int)>();
+
+  void wire_intensity_rgba(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> intensities,
+    int width,
+    int height,
+  ) {
+    return _wire_intensity_rgba(
+      port_,
+      intensities,
+      width,
+      height,
+    );
+  }
+
+  late final _wire_intensity_rgbaPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Int64, ffi.Int64)>>('wire_intensity_rgba');
+  late final _wire_intensity_rgba = _wire_intensity_rgbaPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>, int, int)>();
+
+  void wire_set_color_conversion(
+    int port_,
+    int standard,
+    int range,
+  ) {
+    return _wire_set_color_conversion(
+      port_,
+      standard,
+      range,
+    );
+  }
+
+  late final _wire_set_color_conversionPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Int32, ffi.Int32)>>('wire_set_color_conversion');
+  late final _wire_set_color_conversion =
+      _wire_set_color_conversionPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_set_frame_transform(
+    int port_,
+    ffi.Pointer<wire_FrameTransform> transform,
+  ) {
+    return _wire_set_frame_transform(
+      port_,
+      transform,
+    );
+  }
+
+  late final _wire_set_frame_transformPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_FrameTransform>)>>('wire_set_frame_transform');
+  late final _wire_set_frame_transform = _wire_set_frame_transformPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_FrameTransform>)>();
+
+  void wire_yuv_rgba(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_yuv_rgba(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_yuv_rgbaPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_yuv_rgba');
+  late final _wire_yuv_rgba = _wire_yuv_rgbaPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_color_count(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_color_count(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_color_countPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_color_count');
+  late final _wire_color_count = _wire_color_countPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_color_clusterer(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_color_clusterer(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_color_clustererPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_color_clusterer');
+  late final _wire_color_clusterer = _wire_color_clustererPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_floor_free_space(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_floor_free_space(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_floor_free_spacePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_ImageData>)>>('wire_floor_free_space');
+  late final _wire_floor_free_space = _wire_floor_free_spacePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_floor_view(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_floor_view(
+      port_,
+      img,
+    );
+  }
+
+  late final _wire_floor_viewPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_floor_view');
+  late final _wire_floor_view = _wire_floor_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_set_min_blob_area(
+    int port_,
+    int min_area,
+  ) {
+    return _wire_set_min_blob_area(
+      port_,
+      min_area,
+    );
   }
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
-    final ans = inner.new_uint_8_list_0(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+  late final _wire_set_min_blob_areaPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>(
+          'wire_set_min_blob_area');
+  late final _wire_set_min_blob_area =
+      _wire_set_min_blob_areaPtr.asFunction<void Function(int, int)>();
 
-// Section: finalizer
+  void wire_track_blob_color(
+    int port_,
+    int red,
+    int green,
+    int blue,
+  ) {
+    return _wire_track_blob_color(
+      port_,
+      red,
+      green,
+      blue,
+    );
+  }
 
-// Section: api_fill_to_wire
+  late final _wire_track_blob_colorPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Int64, ffi.Int64,
+              ffi.Int64)>>('wire_track_blob_color');
+  late final _wire_track_blob_color =
+      _wire_track_blob_colorPtr.asFunction<void Function(int, int, int, int)>();
 
-  void _api_fill_to_wire_box_autoadd_dart_image(
-      DartImage apiObj, ffi.Pointer<wire_DartImage> wireObj) {
-    _api_fill_to_wire_dart_image(apiObj, wireObj.ref);
+  void wire_stop_blob_tracking(
+    int port_,
+  ) {
+    return _wire_stop_blob_tracking(
+      port_,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_image_data(
-      ImageData apiObj, ffi.Pointer<wire_ImageData> wireObj) {
-    _api_fill_to_wire_image_data(apiObj, wireObj.ref);
+  late final _wire_stop_blob_trackingPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_stop_blob_tracking');
+  late final _wire_stop_blob_tracking =
+      _wire_stop_blob_trackingPtr.asFunction<void Function(int)>();
+
+  void wire_detect_blobs(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_detect_blobs(
+      port_,
+      img,
+    );
   }
 
-  void _api_fill_to_wire_dart_image(DartImage apiObj, wire_DartImage wireObj) {
-    wireObj.bytes = api2wire_uint_8_list(apiObj.bytes);
-    wireObj.width = api2wire_i64(apiObj.width);
-    wireObj.height = api2wire_i64(apiObj.height);
+  late final _wire_detect_blobsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_detect_blobs');
+  late final _wire_detect_blobs = _wire_detect_blobsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_blob_view(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_blob_view(
+      port_,
+      img,
+    );
   }
 
-  void _api_fill_to_wire_image_data(ImageData apiObj, wire_ImageData wireObj) {
-    wireObj.ys = api2wire_uint_8_list(apiObj.ys);
-    wireObj.us = api2wire_uint_8_list(apiObj.us);
-    wireObj.vs = api2wire_uint_8_list(apiObj.vs);
-    wireObj.width = api2wire_i64(apiObj.width);
-    wireObj.height = api2wire_i64(apiObj.height);
-    wireObj.uv_row_stride = api2wire_i64(apiObj.uvRowStride);
-    wireObj.uv_pixel_stride = api2wire_i64(apiObj.uvPixelStride);
+  late final _wire_blob_viewPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_blob_view');
+  late final _wire_blob_view = _wire_blob_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_set_color_target(
+    int port_,
+    ffi.Pointer<wire_ColorTarget> target,
+  ) {
+    return _wire_set_color_target(
+      port_,
+      target,
+    );
   }
 
-  void _api_fill_to_wire_labeled_image(
-      LabeledImage apiObj, wire_LabeledImage wireObj) {
-    wireObj.label = api2wire_String(apiObj.label);
-    _api_fill_to_wire_dart_image(apiObj.image, wireObj.image);
+  late final _wire_set_color_targetPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_ColorTarget>)>>('wire_set_color_target');
+  late final _wire_set_color_target = _wire_set_color_targetPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ColorTarget>)>();
+
+  void wire_set_tracking_speeds(
+    int port_,
+    int cruise,
+    int turn,
+  ) {
+    return _wire_set_tracking_speeds(
+      port_,
+      cruise,
+      turn,
+    );
   }
-}
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+  late final _wire_set_tracking_speedsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Int64, ffi.Int64)>>('wire_set_tracking_speeds');
+  late final _wire_set_tracking_speeds =
+      _wire_set_tracking_speedsPtr.asFunction<void Function(int, int, int)>();
 
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
+  void wire_track_color_target(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_track_color_target(
+      port_,
+      img,
+    );
+  }
 
-/// generated by flutter_rust_bridge
-class NativeWire implements FlutterRustBridgeWireBase {
-  @internal
-  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+  late final _wire_track_color_targetPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_ImageData>)>>('wire_track_color_target');
+  late final _wire_track_color_target = _wire_track_color_targetPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+  void wire_color_target_view(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_color_target_view(
+      port_,
+      img,
+    );
+  }
 
-  /// The symbols are looked up in [dynamicLibrary].
-  NativeWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
+  late final _wire_color_target_viewPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_ImageData>)>>('wire_color_target_view');
+  late final _wire_color_target_view = _wire_color_target_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  /// The symbols are looked up with [lookup].
-  NativeWire.fromLookup(
-      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-          lookup)
-      : _lookup = lookup;
+  void wire_sobel_view(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
+  ) {
+    return _wire_sobel_view(
+      port_,
+      img,
+    );
+  }
 
-  void store_dart_post_cobject(
-    DartPostCObjectFnType ptr,
+  late final _wire_sobel_viewPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_sobel_view');
+  late final _wire_sobel_view = _wire_sobel_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+
+  void wire_set_canny_thresholds(
+    int port_,
+    double low,
+    double high,
   ) {
-    return _store_dart_post_cobject(
-      ptr,
+    return _wire_set_canny_thresholds(
+      port_,
+      low,
+      high,
     );
   }
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-          'store_dart_post_cobject');
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+  late final _wire_set_canny_thresholdsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Double, ffi.Double)>>('wire_set_canny_thresholds');
+  late final _wire_set_canny_thresholds = _wire_set_canny_thresholdsPtr
+      .asFunction<void Function(int, double, double)>();
 
-  Object get_dart_object(
-    int ptr,
+  void wire_canny_view(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
   ) {
-    return _get_dart_object(
-      ptr,
+    return _wire_canny_view(
+      port_,
+      img,
     );
   }
 
-  late final _get_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
-          'get_dart_object');
-  late final _get_dart_object =
-      _get_dart_objectPtr.asFunction<Object Function(int)>();
+  late final _wire_canny_viewPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_canny_view');
+  late final _wire_canny_view = _wire_canny_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  void drop_dart_object(
-    int ptr,
+  void wire_set_line_detection(
+    int port_,
+    int min_votes,
+    int max_lines,
   ) {
-    return _drop_dart_object(
-      ptr,
+    return _wire_set_line_detection(
+      port_,
+      min_votes,
+      max_lines,
     );
   }
 
-  late final _drop_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
-          'drop_dart_object');
-  late final _drop_dart_object =
-      _drop_dart_objectPtr.asFunction<void Function(int)>();
+  late final _wire_set_line_detectionPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Int64, ffi.Int64)>>('wire_set_line_detection');
+  late final _wire_set_line_detection =
+      _wire_set_line_detectionPtr.asFunction<void Function(int, int, int)>();
 
-  int new_dart_opaque(
-    Object handle,
+  void wire_detect_lines(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
   ) {
-    return _new_dart_opaque(
-      handle,
+    return _wire_detect_lines(
+      port_,
+      img,
     );
   }
 
-  late final _new_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
-          'new_dart_opaque');
-  late final _new_dart_opaque =
-      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+  late final _wire_detect_linesPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_detect_lines');
+  late final _wire_detect_lines = _wire_detect_linesPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  int init_frb_dart_api_dl(
-    ffi.Pointer<ffi.Void> obj,
+  void wire_lines_view(
+    int port_,
+    ffi.Pointer<wire_ImageData> img,
   ) {
-    return _init_frb_dart_api_dl(
-      obj,
+    return _wire_lines_view(
+      port_,
+      img,
     );
   }
 
-  late final _init_frb_dart_api_dlPtr =
-      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
-          'init_frb_dart_api_dl');
-  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
-      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+  late final _wire_lines_viewPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_lines_view');
+  late final _wire_lines_view = _wire_lines_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  void wire_train_knn(
+  void wire_akaze_view(
     int port_,
-    int k,
-    ffi.Pointer<wire_list_labeled_image> examples,
+    ffi.Pointer<wire_ImageData> img,
   ) {
-    return _wire_train_knn(
+    return _wire_akaze_view(
       port_,
-      k,
-      examples,
+      img,
     );
   }
 
-  late final _wire_train_knnPtr = _lookup<
+  late final _wire_akaze_viewPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.UintPtr,
-              ffi.Pointer<wire_list_labeled_image>)>>('wire_train_knn');
-  late final _wire_train_knn = _wire_train_knnPtr.asFunction<
-      void Function(int, int, ffi.Pointer<wire_list_labeled_image>)>();
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_akaze_view');
+  late final _wire_akaze_view = _wire_akaze_viewPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  void wire_classify_knn(
+  void wire_akaze_flow(
     int port_,
-    ffi.Pointer<wire_uint_8_list> img,
+    ffi.Pointer<wire_ImageData> img,
   ) {
-    return _wire_classify_knn(
+    return _wire_akaze_flow(
       port_,
       img,
     );
   }
 
-  late final _wire_classify_knnPtr = _lookup<
+  late final _wire_akaze_flowPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_classify_knn');
-  late final _wire_classify_knn = _wire_classify_knnPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_akaze_flow');
+  late final _wire_akaze_flow = _wire_akaze_flowPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
 
-  void wire_train_knn_akaze_pos(
+  void wire_reset_akaze_flow(
     int port_,
-    int k,
-    ffi.Pointer<wire_list_labeled_image> examples,
   ) {
-    return _wire_train_knn_akaze_pos(
+    return _wire_reset_akaze_flow(
       port_,
-      k,
-      examples,
     );
   }
 
-  late final _wire_train_knn_akaze_posPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.UintPtr,
-                  ffi.Pointer<wire_list_labeled_image>)>>(
-      'wire_train_knn_akaze_pos');
-  late final _wire_train_knn_akaze_pos =
-      _wire_train_knn_akaze_posPtr.asFunction<
-          void Function(int, int, ffi.Pointer<wire_list_labeled_image>)>();
+  late final _wire_reset_akaze_flowPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_reset_akaze_flow');
+  late final _wire_reset_akaze_flow =
+      _wire_reset_akaze_flowPtr.asFunction<void Function(int)>();
 
-  void wire_train_knn_akaze_features(
+  void wire_reset_position_estimate(
     int port_,
-    int k,
-    ffi.Pointer<wire_list_labeled_image> examples,
   ) {
-    return _wire_train_knn_akaze_features(
+    return _wire_reset_position_estimate(
       port_,
-      k,
-      examples,
     );
   }
 
-  late final _wire_train_knn_akaze_featuresPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.UintPtr,
-                  ffi.Pointer<wire_list_labeled_image>)>>(
-      'wire_train_knn_akaze_features');
-  late final _wire_train_knn_akaze_features =
-      _wire_train_knn_akaze_featuresPtr.asFunction<
-          void Function(int, int, ffi.Pointer<wire_list_labeled_image>)>();
+  late final _wire_reset_position_estimatePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_reset_position_estimate');
+  late final _wire_reset_position_estimate =
+      _wire_reset_position_estimatePtr.asFunction<void Function(int)>();
 
-  void wire_classify_knn_akaze_pos(
+  void wire_process_sensor_data(
     int port_,
-    ffi.Pointer<wire_DartImage> img,
+    ffi.Pointer<wire_uint_8_list> incoming_data,
   ) {
-    return _wire_classify_knn_akaze_pos(
+    return _wire_process_sensor_data(
       port_,
-      img,
+      incoming_data,
     );
   }
 
-  late final _wire_classify_knn_akaze_posPtr = _lookup<
+  late final _wire_process_sensor_dataPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_DartImage>)>>('wire_classify_knn_akaze_pos');
-  late final _wire_classify_knn_akaze_pos = _wire_classify_knn_akaze_posPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_DartImage>)>();
+              ffi.Pointer<wire_uint_8_list>)>>('wire_process_sensor_data');
+  late final _wire_process_sensor_data = _wire_process_sensor_dataPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_classify_knn_akaze_feature(
+  void wire_start_recording(
     int port_,
-    ffi.Pointer<wire_DartImage> img,
+    ffi.Pointer<wire_uint_8_list> path,
   ) {
-    return _wire_classify_knn_akaze_feature(
+    return _wire_start_recording(
       port_,
-      img,
+      path,
     );
   }
 
-  late final _wire_classify_knn_akaze_featurePtr = _lookup<
+  late final _wire_start_recordingPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_DartImage>)>>('wire_classify_knn_akaze_feature');
-  late final _wire_classify_knn_akaze_feature =
-      _wire_classify_knn_akaze_featurePtr
-          .asFunction<void Function(int, ffi.Pointer<wire_DartImage>)>();
+              ffi.Pointer<wire_uint_8_list>)>>('wire_start_recording');
+  late final _wire_start_recording = _wire_start_recordingPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_kmeans_ready(
+  void wire_stop_recording(
     int port_,
   ) {
-    return _wire_kmeans_ready(
+    return _wire_stop_recording(
       port_,
     );
   }
 
-  late final _wire_kmeans_readyPtr =
+  late final _wire_stop_recordingPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_kmeans_ready');
-  late final _wire_kmeans_ready =
-      _wire_kmeans_readyPtr.asFunction<void Function(int)>();
+          'wire_stop_recording');
+  late final _wire_stop_recording =
+      _wire_stop_recordingPtr.asFunction<void Function(int)>();
 
-  void wire_training_time(
+  void wire_is_recording(
     int port_,
   ) {
-    return _wire_training_time(
+    return _wire_is_recording(
       port_,
     );
   }
 
-  late final _wire_training_timePtr =
+  late final _wire_is_recordingPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_training_time');
-  late final _wire_training_time =
-      _wire_training_timePtr.asFunction<void Function(int)>();
+          'wire_is_recording');
+  late final _wire_is_recording =
+      _wire_is_recordingPtr.asFunction<void Function(int)>();
 
-  void wire_intensity_rgba(
+  void wire_replay_recording(
     int port_,
-    ffi.Pointer<wire_uint_8_list> intensities,
+    ffi.Pointer<wire_uint_8_list> path,
   ) {
-    return _wire_intensity_rgba(
+    return _wire_replay_recording(
       port_,
-      intensities,
+      path,
     );
   }
 
-  late final _wire_intensity_rgbaPtr = _lookup<
+  late final _wire_replay_recordingPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_intensity_rgba');
-  late final _wire_intensity_rgba = _wire_intensity_rgbaPtr
+              ffi.Pointer<wire_uint_8_list>)>>('wire_replay_recording');
+  late final _wire_replay_recording = _wire_replay_recordingPtr
       .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_yuv_rgba(
+  void wire_set_sonar_settings(
     int port_,
-    ffi.Pointer<wire_ImageData> img,
+    ffi.Pointer<wire_SonarSettings> settings,
   ) {
-    return _wire_yuv_rgba(
+    return _wire_set_sonar_settings(
       port_,
-      img,
+      settings,
     );
   }
 
-  late final _wire_yuv_rgbaPtr = _lookup<
+  late final _wire_set_sonar_settingsPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_yuv_rgba');
-  late final _wire_yuv_rgba = _wire_yuv_rgbaPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_SonarSettings>)>>('wire_set_sonar_settings');
+  late final _wire_set_sonar_settings = _wire_set_sonar_settingsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_SonarSettings>)>();
 
-  void wire_color_count(
+  void wire_set_controller(
     int port_,
-    ffi.Pointer<wire_ImageData> img,
+    int kind,
   ) {
-    return _wire_color_count(
+    return _wire_set_controller(
       port_,
-      img,
+      kind,
     );
   }
 
-  late final _wire_color_countPtr = _lookup<
+  late final _wire_set_controllerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
+          'wire_set_controller');
+  late final _wire_set_controller =
+      _wire_set_controllerPtr.asFunction<void Function(int, int)>();
+
+  void wire_queue_robot_command(
+    int port_,
+    ffi.Pointer<wire_RobotCommand> command,
+  ) {
+    return _wire_queue_robot_command(
+      port_,
+      command,
+    );
+  }
+
+  late final _wire_queue_robot_commandPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_color_count');
-  late final _wire_color_count = _wire_color_countPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_RobotCommand>)>>('wire_queue_robot_command');
+  late final _wire_queue_robot_command = _wire_queue_robot_commandPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_RobotCommand>)>();
 
-  void wire_color_clusterer(
+  void wire_next_robot_command(
     int port_,
-    ffi.Pointer<wire_ImageData> img,
+    ffi.Pointer<wire_SensorData> sensors,
   ) {
-    return _wire_color_clusterer(
+    return _wire_next_robot_command(
       port_,
-      img,
+      sensors,
     );
   }
 
-  late final _wire_color_clustererPtr = _lookup<
+  late final _wire_next_robot_commandPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_color_clusterer');
-  late final _wire_color_clusterer = _wire_color_clustererPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_SensorData>)>>('wire_next_robot_command');
+  late final _wire_next_robot_command = _wire_next_robot_commandPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_SensorData>)>();
 
-  void wire_akaze_view(
+  void wire_set_label_action(
     int port_,
-    ffi.Pointer<wire_ImageData> img,
+    ffi.Pointer<wire_uint_8_list> label,
+    ffi.Pointer<wire_RobotCommand> command,
   ) {
-    return _wire_akaze_view(
+    return _wire_set_label_action(
       port_,
-      img,
+      label,
+      command,
     );
   }
 
-  late final _wire_akaze_viewPtr = _lookup<
+  late final _wire_set_label_actionPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_akaze_view');
-  late final _wire_akaze_view = _wire_akaze_viewPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_RobotCommand>)>>('wire_set_label_action');
+  late final _wire_set_label_action = _wire_set_label_actionPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_RobotCommand>)>();
 
-  void wire_akaze_flow(
+  void wire_clear_label_actions(
     int port_,
-    ffi.Pointer<wire_ImageData> img,
   ) {
-    return _wire_akaze_flow(
+    return _wire_clear_label_actions(
       port_,
-      img,
     );
   }
 
-  late final _wire_akaze_flowPtr = _lookup<
+  late final _wire_clear_label_actionsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_clear_label_actions');
+  late final _wire_clear_label_actions =
+      _wire_clear_label_actionsPtr.asFunction<void Function(int)>();
+
+  void wire_set_label_voting(
+    int port_,
+    int window,
+    int hold,
+  ) {
+    return _wire_set_label_voting(
+      port_,
+      window,
+      hold,
+    );
+  }
+
+  late final _wire_set_label_votingPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_ImageData>)>>('wire_akaze_flow');
-  late final _wire_akaze_flow = _wire_akaze_flowPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_ImageData>)>();
+              ffi.Int64, ffi.Int64, ffi.Int64)>>('wire_set_label_voting');
+  late final _wire_set_label_voting =
+      _wire_set_label_votingPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_reset_position_estimate(
+  void wire_observe_label(
     int port_,
+    ffi.Pointer<wire_uint_8_list> label,
   ) {
-    return _wire_reset_position_estimate(
+    return _wire_observe_label(
       port_,
+      label,
     );
   }
 
-  late final _wire_reset_position_estimatePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_reset_position_estimate');
-  late final _wire_reset_position_estimate =
-      _wire_reset_position_estimatePtr.asFunction<void Function(int)>();
+  late final _wire_observe_labelPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_observe_label');
+  late final _wire_observe_label = _wire_observe_labelPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_process_sensor_data(
+  void wire_robot_command_wire(
     int port_,
-    ffi.Pointer<wire_uint_8_list> incoming_data,
+    ffi.Pointer<wire_RobotCommand> command,
   ) {
-    return _wire_process_sensor_data(
+    return _wire_robot_command_wire(
       port_,
-      incoming_data,
+      command,
     );
   }
 
-  late final _wire_process_sensor_dataPtr = _lookup<
+  late final _wire_robot_command_wirePtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_process_sensor_data');
-  late final _wire_process_sensor_data = _wire_process_sensor_dataPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Pointer<wire_RobotCommand>)>>('wire_robot_command_wire');
+  late final _wire_robot_command_wire = _wire_robot_command_wirePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_RobotCommand>)>();
 
   void wire_parse_sensor_data(
     int port_,
@@ -867,6 +3077,28 @@ class NativeWire implements FlutterRustBridgeWireBase {
   late final _wire_parse_sensor_data = _wire_parse_sensor_dataPtr
       .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  ffi.Pointer<wire_AugmentSettings> new_box_autoadd_augment_settings_0() {
+    return _new_box_autoadd_augment_settings_0();
+  }
+
+  late final _new_box_autoadd_augment_settings_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_AugmentSettings> Function()>>(
+          'new_box_autoadd_augment_settings_0');
+  late final _new_box_autoadd_augment_settings_0 =
+      _new_box_autoadd_augment_settings_0Ptr
+          .asFunction<ffi.Pointer<wire_AugmentSettings> Function()>();
+
+  ffi.Pointer<wire_ColorTarget> new_box_autoadd_color_target_0() {
+    return _new_box_autoadd_color_target_0();
+  }
+
+  late final _new_box_autoadd_color_target_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ColorTarget> Function()>>(
+          'new_box_autoadd_color_target_0');
+  late final _new_box_autoadd_color_target_0 =
+      _new_box_autoadd_color_target_0Ptr
+          .asFunction<ffi.Pointer<wire_ColorTarget> Function()>();
+
   ffi.Pointer<wire_DartImage> new_box_autoadd_dart_image_0() {
     return _new_box_autoadd_dart_image_0();
   }
@@ -877,6 +3109,17 @@ class NativeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_dart_image_0 = _new_box_autoadd_dart_image_0Ptr
       .asFunction<ffi.Pointer<wire_DartImage> Function()>();
 
+  ffi.Pointer<wire_FrameTransform> new_box_autoadd_frame_transform_0() {
+    return _new_box_autoadd_frame_transform_0();
+  }
+
+  late final _new_box_autoadd_frame_transform_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_FrameTransform> Function()>>(
+          'new_box_autoadd_frame_transform_0');
+  late final _new_box_autoadd_frame_transform_0 =
+      _new_box_autoadd_frame_transform_0Ptr
+          .asFunction<ffi.Pointer<wire_FrameTransform> Function()>();
+
   ffi.Pointer<wire_ImageData> new_box_autoadd_image_data_0() {
     return _new_box_autoadd_image_data_0();
   }
@@ -887,6 +3130,38 @@ class NativeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_image_data_0 = _new_box_autoadd_image_data_0Ptr
       .asFunction<ffi.Pointer<wire_ImageData> Function()>();
 
+  ffi.Pointer<wire_RobotCommand> new_box_autoadd_robot_command_0() {
+    return _new_box_autoadd_robot_command_0();
+  }
+
+  late final _new_box_autoadd_robot_command_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_RobotCommand> Function()>>(
+          'new_box_autoadd_robot_command_0');
+  late final _new_box_autoadd_robot_command_0 =
+      _new_box_autoadd_robot_command_0Ptr
+          .asFunction<ffi.Pointer<wire_RobotCommand> Function()>();
+
+  ffi.Pointer<wire_SensorData> new_box_autoadd_sensor_data_0() {
+    return _new_box_autoadd_sensor_data_0();
+  }
+
+  late final _new_box_autoadd_sensor_data_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_SensorData> Function()>>(
+          'new_box_autoadd_sensor_data_0');
+  late final _new_box_autoadd_sensor_data_0 = _new_box_autoadd_sensor_data_0Ptr
+      .asFunction<ffi.Pointer<wire_SensorData> Function()>();
+
+  ffi.Pointer<wire_SonarSettings> new_box_autoadd_sonar_settings_0() {
+    return _new_box_autoadd_sonar_settings_0();
+  }
+
+  late final _new_box_autoadd_sonar_settings_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_SonarSettings> Function()>>(
+          'new_box_autoadd_sonar_settings_0');
+  late final _new_box_autoadd_sonar_settings_0 =
+      _new_box_autoadd_sonar_settings_0Ptr
+          .asFunction<ffi.Pointer<wire_SonarSettings> Function()>();
+
   ffi.Pointer<wire_list_labeled_image> new_list_labeled_image_0(
     int len,
   ) {
@@ -917,6 +3192,37 @@ class NativeWire implements FlutterRustBridgeWireBase {
   late final _new_uint_8_list_0 = _new_uint_8_list_0Ptr
       .asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
+  ffi.Pointer<RobotCommandKind> inflate_RobotCommand_Drive() {
+    return _inflate_RobotCommand_Drive();
+  }
+
+  late final _inflate_RobotCommand_DrivePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<RobotCommandKind> Function()>>(
+          'inflate_RobotCommand_Drive');
+  late final _inflate_RobotCommand_Drive = _inflate_RobotCommand_DrivePtr
+      .asFunction<ffi.Pointer<RobotCommandKind> Function()>();
+
+  ffi.Pointer<RobotCommandKind> inflate_RobotCommand_Turn() {
+    return _inflate_RobotCommand_Turn();
+  }
+
+  late final _inflate_RobotCommand_TurnPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<RobotCommandKind> Function()>>(
+          'inflate_RobotCommand_Turn');
+  late final _inflate_RobotCommand_Turn = _inflate_RobotCommand_TurnPtr
+      .asFunction<ffi.Pointer<RobotCommandKind> Function()>();
+
+  ffi.Pointer<RobotCommandKind> inflate_RobotCommand_SetSpeeds() {
+    return _inflate_RobotCommand_SetSpeeds();
+  }
+
+  late final _inflate_RobotCommand_SetSpeedsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<RobotCommandKind> Function()>>(
+          'inflate_RobotCommand_SetSpeeds');
+  late final _inflate_RobotCommand_SetSpeeds =
+      _inflate_RobotCommand_SetSpeedsPtr
+          .asFunction<ffi.Pointer<RobotCommandKind> Function()>();
+
   void free_WireSyncReturn(
     WireSyncReturn ptr,
   ) {
@@ -964,6 +3270,52 @@ class wire_list_labeled_image extends ffi.Struct {
   external int len;
 }
 
+class wire_AugmentSettings extends ffi.Struct {
+  @ffi.Int64()
+  external int copies;
+
+  @ffi.Bool()
+  external bool flip;
+
+  @ffi.Double()
+  external double max_rotation_degrees;
+
+  @ffi.Double()
+  external double max_shift;
+
+  @ffi.Double()
+  external double max_brightness;
+
+  @ffi.Double()
+  external double max_contrast;
+
+  @ffi.Double()
+  external double max_noise;
+
+  @ffi.Int64()
+  external int seed;
+}
+
+class wire_FrameTransform extends ffi.Struct {
+  @ffi.Int32()
+  external int rotation;
+
+  @ffi.Bool()
+  external bool mirror;
+
+  @ffi.Int64()
+  external int crop_x;
+
+  @ffi.Int64()
+  external int crop_y;
+
+  @ffi.Int64()
+  external int crop_width;
+
+  @ffi.Int64()
+  external int crop_height;
+}
+
 class wire_ImageData extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> ys;
 
@@ -977,11 +3329,119 @@ class wire_ImageData extends ffi.Struct {
   @ffi.Int64()
   external int height;
 
+  @ffi.Int64()
+  external int y_row_stride;
+
   @ffi.Int64()
   external int uv_row_stride;
 
   @ffi.Int64()
   external int uv_pixel_stride;
+
+  @ffi.Int32()
+  external int format;
+}
+
+class wire_ColorTarget extends ffi.Struct {
+  @ffi.Int64()
+  external int red;
+
+  @ffi.Int64()
+  external int green;
+
+  @ffi.Int64()
+  external int blue;
+
+  @ffi.Int64()
+  external int tolerance;
+
+  @ffi.Int32()
+  external int space;
+
+  @ffi.Int64()
+  external int min_area;
+}
+
+class wire_SonarSettings extends ffi.Struct {
+  @ffi.Int64()
+  external int obstacle_distance;
+
+  @ffi.Int64()
+  external int wall_distance;
+
+  @ffi.Int64()
+  external int cruise_speed;
+
+  @ffi.Int64()
+  external int turn_speed;
+}
+
+class wire_RobotCommand_NoOp extends ffi.Opaque {}
+
+class wire_RobotCommand_Start extends ffi.Opaque {}
+
+class wire_RobotCommand_Stop extends ffi.Opaque {}
+
+class wire_RobotCommand_Drive extends ffi.Struct {
+  @ffi.Int64()
+  external int speed;
+}
+
+class wire_RobotCommand_Turn extends ffi.Struct {
+  @ffi.Int64()
+  external int speed;
+}
+
+class wire_RobotCommand_SetSpeeds extends ffi.Struct {
+  @ffi.Int64()
+  external int left;
+
+  @ffi.Int64()
+  external int right;
+}
+
+class RobotCommandKind extends ffi.Union {
+  external ffi.Pointer<wire_RobotCommand_NoOp> NoOp;
+
+  external ffi.Pointer<wire_RobotCommand_Start> Start;
+
+  external ffi.Pointer<wire_RobotCommand_Stop> Stop;
+
+  external ffi.Pointer<wire_RobotCommand_Drive> Drive;
+
+  external ffi.Pointer<wire_RobotCommand_Turn> Turn;
+
+  external ffi.Pointer<wire_RobotCommand_SetSpeeds> SetSpeeds;
+}
+
+class wire_RobotCommand extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<RobotCommandKind> kind;
+}
+
+class wire_SensorData extends ffi.Struct {
+  @ffi.Int64()
+  external int sonar_front;
+
+  @ffi.Int64()
+  external int sonar_left;
+
+  @ffi.Int64()
+  external int sonar_right;
+
+  @ffi.Int64()
+  external int left_count;
+
+  @ffi.Int64()
+  external int right_count;
+
+  @ffi.Int64()
+  external int left_speed;
+
+  @ffi.Int64()
+  external int right_speed;
 }
 
 typedef DartPostCObjectFnType = ffi.Pointer<
@@ -1,4 +1,3 @@
-import 'dart:collection';
 import 'dart:io';
 import 'package:path_provider/path_provider.dart';
 import 'package:vision_bot/robot.dart';
@@ -64,18 +63,25 @@ abstract class VisionRunner {
   Widget display(SelectorPageState selector);
   CameraImagePainter livePicture();
 
-  String getReply(String message, Queue<String> requests, Directory fileSystemPath) {
-    if (requests.isNotEmpty) {
-      return requests.removeFirst();
-    } else {
-      return "None";
-    }
+  Future<String> getReply(String message, SensorData sensors, Directory fileSystemPath) {
+    return nextCommandWire(sensors);
   }
 }
 
+Future<String> nextCommandWire(SensorData sensors) async {
+  RobotCommand command = await api.nextRobotCommand(sensors: sensors);
+  return await api.robotCommandWire(command: command);
+}
+
+/// Whether `msg` is one of the robot's sensor packets, as opposed to a request
+/// such as `cmd`, `classify` or `knn`.
+bool isSensorPacket(String msg) {
+  return msg.contains("SF:");
+}
+
 class SelectorPageState extends State<SelectorPage> {
   late CameraController controller;
-  final Queue<String> _requests = Queue();
+  SensorData _sensors = const SensorData(sonarFront: 0, sonarLeft: 0, sonarRight: 0, leftCount: 0, rightCount: 0, leftSpeed: 0, rightSpeed: 0);
   VisionRunner? running;
 
   String ipAddr = "Awaiting IP Address...";
@@ -105,7 +111,7 @@ class SelectorPageState extends State<SelectorPage> {
           setState(() {
             _robotStatus = RobotStatus.started;
           });
-          _requests.addLast('Start');
+          api.queueRobotCommand(command: RobotCommand.start());
           print("Sending Start");
         });
       });
@@ -115,7 +121,7 @@ class SelectorPageState extends State<SelectorPage> {
           setState(() {
             _robotStatus = RobotStatus.notStarted;
           });
-          _requests.addLast('Stop');
+          api.queueRobotCommand(command: RobotCommand.stop());
           print("Sending Stop");
         });
       });
@@ -320,11 +326,14 @@ class SelectorPageState extends State<SelectorPage> {
   }
 
   void _listenToSocket(Socket socket) {
-    socket.listen((data) {
+    socket.listen((data) async {
       String msg = String.fromCharCodes(data);
       print("received $msg");
+      if (isSensorPacket(msg)) {
+        _sensors = await api.parseSensorData(incomingData: msg);
+      }
       if (running != null) {
-        socket.write(running!.getReply(msg, _requests, appDir()));
+        socket.write(await running!.getReply(msg, _sensors, appDir()));
       } else {
         // The "if" clause represents what I should really be doing on an
         // engineering level - each VisionRunner should process incoming
@@ -334,11 +343,7 @@ class SelectorPageState extends State<SelectorPage> {
         // It assumes a very specific robot layout.
         // I should probably delete it, but I am hesitant for some reason.
         if (msg == "cmd") {
-          if (_requests.isEmpty) {
-            socket.write("None");
-          } else {
-            socket.write(_requests.removeFirst());
-          }
+          socket.write(await nextCommandWire(_sensors));
         } else {
           getProcessedData(msg);
         }
@@ -349,8 +354,7 @@ class SelectorPageState extends State<SelectorPage> {
 
   Future<void> getProcessedData(String incomingData) async {
     String processed = await api.processSensorData(incomingData: incomingData);
-    SensorData data = await api.parseSensorData(incomingData: incomingData);
-    _robotState = RobotState.decode(data);
+    _robotState = RobotState.decode(_sensors);
     setState(() {
       incoming = processed;
     });
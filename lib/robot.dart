@@ -1,4 +1,3 @@
-import 'dart:collection';
 import 'dart:io';
 import 'dart:ui' as dartui;
 
@@ -254,7 +253,7 @@ class KnnImageRunner extends VisionRunner {
     return _livePicture;
   }
 
-  String getReply(String message, Queue<String> requests, Directory fileSystemPath) {
+  Future<String> getReply(String message, SensorData sensors, Directory fileSystemPath) async {
     if (message.startsWith('knn')) {
       List<String> parts = message.split(' ');
       int k = int.parse(parts[1]);
@@ -365,7 +364,7 @@ class CameraImagePainter extends CustomPainter {
       _initialized = true;
     }
     ImageResponse response = await imageMaker(img: from(img));
-    _lastImage = await makeImageFrom(response.img, img.width, img.height);
+    _lastImage = await makeImageFrom(response.img, response.width, response.height);
     lastMessage = response.msg;
     _width = _lastImage.width;
     _height = _lastImage.height;
@@ -400,7 +399,15 @@ class CameraImagePainter extends CustomPainter {
 }
 
 ImageData from(CameraImage img) {
-  return ImageData(ys: img.planes[0].bytes, us: img.planes[1].bytes, vs: img.planes[2].bytes, width: img.width, height: img.height, uvRowStride: img.planes[1].bytesPerRow, uvPixelStride: img.planes[1].bytesPerPixel!);
+  switch (img.format.group) {
+    case ImageFormatGroup.bgra8888:
+      return ImageData(ys: img.planes[0].bytes, us: Uint8List(0), vs: Uint8List(0), width: img.width, height: img.height, yRowStride: img.planes[0].bytesPerRow, uvRowStride: 0, uvPixelStride: 0, format: PixelFormat.Bgra8888);
+    case ImageFormatGroup.nv21:
+      Uint8List chroma = img.planes.length > 1 ? img.planes[1].bytes : Uint8List(0);
+      return ImageData(ys: img.planes[0].bytes, us: chroma, vs: Uint8List(0), width: img.width, height: img.height, yRowStride: img.planes[0].bytesPerRow, uvRowStride: img.planes[0].bytesPerRow, uvPixelStride: 2, format: PixelFormat.Nv21);
+    default:
+      return ImageData(ys: img.planes[0].bytes, us: img.planes[1].bytes, vs: img.planes[2].bytes, width: img.width, height: img.height, yRowStride: img.planes[0].bytesPerRow, uvRowStride: img.planes[1].bytesPerRow, uvPixelStride: img.planes[1].bytesPerPixel!, format: PixelFormat.Yuv420);
+  }
 }
 
 // This is super-clunky. I wonder if there's a better way...
@@ -0,0 +1,83 @@
+// Compares the row-based converter against the per-pixel loop it replaced.
+//
+// cargo bench --bench yuv_rgba
+// cargo bench --bench yuv_rgba --features parallel
+//
+// Only timing lives here; tests/yuv_conversion.rs checks the output.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use native::api::{ImageData, PixelFormat};
+use native::image_proc::inner_yuv_rgba;
+use std::cmp::{max, min};
+
+const WIDTH: i64 = 640;
+const HEIGHT: i64 = 480;
+
+fn camera_frame() -> ImageData {
+    // Semi-planar layout, as delivered by most Android cameras.
+    let uv_len = (WIDTH * HEIGHT / 2) as usize;
+    ImageData {
+        ys: (0..WIDTH * HEIGHT).map(|i| (i % 251) as u8).collect(),
+        us: (0..uv_len).map(|i| (i % 241) as u8).collect(),
+        vs: (0..uv_len).map(|i| (i % 239) as u8).collect(),
+        width: WIDTH,
+        height: HEIGHT,
+        y_row_stride: WIDTH,
+        uv_row_stride: WIDTH,
+        uv_pixel_stride: 2,
+        format: PixelFormat::Yuv420,
+    }
+}
+
+/// The original converter: one closure call and three `i64` multiply-divides
+/// per pixel.
+fn per_pixel_yuv_rgba(img: &ImageData) -> Vec<u8> {
+    let mut result = Vec::new();
+    generic_yuv_rgba(img, |_, _, (r, g, b)| {
+        result.push(r);
+        result.push(g);
+        result.push(b);
+        result.push(u8::MAX);
+    });
+    result
+}
+
+fn generic_yuv_rgba<F: FnMut(i64, i64, (u8, u8, u8))>(img: &ImageData, mut add: F) {
+    for y in 0..img.height {
+        for x in 0..img.width {
+            let uv_index = (img.uv_pixel_stride * (x / 2) + img.uv_row_stride * (y / 2)) as usize;
+            let index = (y * img.width + x) as usize;
+            let rgb = yuv2rgb(
+                img.ys[index] as i64,
+                img.us[uv_index] as i64,
+                img.vs[uv_index] as i64,
+            );
+            add(x, y, rgb);
+        }
+    }
+}
+
+fn yuv2rgb(yp: i64, up: i64, vp: i64) -> (u8, u8, u8) {
+    (
+        clamp_u8(yp + vp * 1436 / 1024 - 179),
+        clamp_u8(yp - up * 46549 / 131072 + 44 - vp * 93604 / 131072 + 91),
+        clamp_u8(yp + up * 1814 / 1024 - 227),
+    )
+}
+
+fn clamp_u8(value: i64) -> u8 {
+    min(max(value, 0), u8::MAX as i64) as u8
+}
+
+fn yuv_rgba_benchmark(c: &mut Criterion) {
+    let img = camera_frame();
+    c.bench_function("per_pixel_yuv_rgba 640x480", |b| {
+        b.iter(|| per_pixel_yuv_rgba(black_box(&img)))
+    });
+    c.bench_function("inner_yuv_rgba 640x480", |b| {
+        b.iter(|| inner_yuv_rgba(black_box(&img)))
+    });
+}
+
+criterion_group!(benches, yuv_rgba_benchmark);
+criterion_main!(benches);
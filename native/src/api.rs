@@ -1,14 +1,15 @@
 use cv::bitarray::BitArray;
 use cv::feature::akaze::KeyPoint;
+use cv::image::imageproc::drawing::BresenhamLinePixelIterMut;
 use cv::{feature::akaze::Akaze, image::image::DynamicImage};
 use flutter_rust_bridge::support::lazy_static;
 use flutter_rust_bridge::ZeroCopyBuffer;
-use image::{ImageBuffer, Rgba, RgbaImage, Pixel};
+use image::{GrayImage, ImageBuffer, Rgba, RgbaImage, Pixel};
 use kmeans::Kmeans;
 use knn::Knn;
 pub use particle_filter::sonar3bot::{MotorData, RobotSensorPosition, BOT};
 use supervised_learning::Classifier;
-use std::cmp::min;
+use std::cmp::{max, min};
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::{
@@ -16,9 +17,25 @@ use std::{
     sync::atomic::{AtomicBool, AtomicU64, Ordering},
 };
 
+use crate::augmentation::augment;
+use crate::behavior::LabelBehavior;
+use crate::control::{CommandQueue, Controller, Manual, SonarController, WallSide};
+use crate::datasets;
+use crate::duplicates::{self, pixel_distance, Duplicates};
+use crate::edges::{canny, sobel_magnitude};
 use crate::image_proc::{
-    convert, inner_yuv_rgba, simple_yuv_rgb, KeyPointMovements, U8ColorTriple, KeyPointInfo, kp_distance_f64, kp_feature_distance_f64,
+    self, distance_u8, downsample, luma_image, upsample, KeyPointMovements, U8ColorTriple, KeyPointInfo, kp_distance_f64, kp_feature_distance_f64,
+    set_yuv_conversion, to_full_scale, RgbaFrame, YuvConversion,
 };
+use crate::lines::{hough_lines, HoughLine};
+use crate::phash::{hash_dart_image, hash_distance_f64, hash_image_data, PerceptualHash};
+use crate::projects::{labeled_images, load_image, project_dir, project_photos};
+use crate::recording::{self, read_log, Recorder};
+use crate::segmentation::{color_blobs, column_free_space, free_space_mask, Blob, Palette, FRAME_PALETTE_SIZE};
+use crate::tracking::{steer_toward, ColorMatcher};
+
+/// Hash k-NN classifier, with the kind of hash its examples were trained on.
+type HashKnn = (HashKind, Knn<String, PerceptualHash, f64>);
 
 lazy_static! {
     static ref POS: Mutex<RobotSensorPosition> = Mutex::new(RobotSensorPosition::new(BOT));
@@ -26,6 +43,17 @@ lazy_static! {
     static ref KMEANS_READY: AtomicBool = AtomicBool::new(false);
     static ref TRAINING_TIME: AtomicU64 = AtomicU64::new(0);
     static ref TOTAL_KEYPOINTS: AtomicU64 = AtomicU64::new(0);
+    static ref PYRAMID_LEVEL: AtomicU64 = AtomicU64::new(0);
+    static ref CANNY_THRESHOLDS: Mutex<(f32, f32)> = Mutex::new((40.0, 100.0));
+    static ref LINE_DETECTION: Mutex<(u32, usize)> = Mutex::new((40, 8));
+    static ref BLOB_TRACKING: Mutex<(u32, Option<U8ColorTriple>)> = Mutex::new((50, None));
+    static ref COLOR_TARGET: Mutex<Option<ColorMatcher>> = Mutex::new(None);
+    static ref TRACKING_SPEEDS: Mutex<(i64, i64)> = Mutex::new((100, 50));
+    static ref COMMAND_QUEUE: Mutex<CommandQueue> = Mutex::new(CommandQueue::default());
+    static ref CONTROLLER: Mutex<Box<dyn Controller>> = Mutex::new(Box::new(Manual));
+    static ref SONAR_SETTINGS: Mutex<SonarSettings> = Mutex::new(SonarSettings::default());
+    static ref LABEL_BEHAVIOR: Mutex<LabelBehavior> = Mutex::new(LabelBehavior::default());
+    static ref RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
     static ref LAST_POINTS: Arc<Mutex<Vec<KeyPoint>>> = Arc::new(Mutex::new(vec![]));
     static ref LAST_FEATURES: Arc<Mutex<Vec<BitArray<64>>>> = Arc::new(Mutex::new(vec![]));
     static ref ALL_FEATURES: Arc<Mutex<HashSet<BitArray<64>>>> =
@@ -34,6 +62,7 @@ lazy_static! {
     static ref KNN_IMAGES: Arc<Mutex<Knn<String, Vec<u8>, f64>>> = Arc::new(Mutex::new(Knn::new(3, Arc::new(distance_u8))));
     static ref KNN_AKAZE_POS: Arc<Mutex<Knn<String, Vec<KeyPointInfo>, f64>>> = Arc::new(Mutex::new(Knn::new(3, Arc::new(distance_keypoint_positions))));
     static ref KNN_AKAZE_FEATURE: Arc<Mutex<Knn<String, Vec<KeyPointInfo>, f64>>> = Arc::new(Mutex::new(Knn::new(3, Arc::new(distance_keypoint_features))));
+    static ref KNN_HASH: Arc<Mutex<HashKnn>> = Arc::new(Mutex::new((HashKind::Dct, Knn::new(3, Arc::new(hash_distance_f64)))));
 }
 
 pub fn train_knn(k: usize, examples: Vec<LabeledImage>) -> String {
@@ -79,7 +108,215 @@ pub fn train_knn_akaze_features(k: usize, examples: Vec<LabeledImage>) -> String
     format!("Training finished; {} examples", knn_images.len())
 }
 
+/// Trains on 64-bit perceptual hashes of the examples instead of their
+/// pixels: each comparison is a single Hamming distance, whatever the photo
+/// size. `classify_knn_hash` hashes with the same `kind`.
+pub fn train_knn_hash(k: usize, examples: Vec<LabeledImage>, kind: HashKind) -> String {
+    let mut knn_hash = KNN_HASH.lock().unwrap();
+    knn_hash.0 = kind;
+    let knn_images = &mut knn_hash.1;
+    knn_images.clear_examples();
+    for example in examples {
+        knn_images.add_example((example.label, hash_dart_image(&example.image, kind)));
+    }
+    knn_images.set_k(k);
+    format!("Training finished; {} examples", knn_images.len())
+}
+
+pub fn classify_knn_hash(img: DartImage) -> String {
+    match KNN_HASH.lock() {
+        Ok(knn_hash) => {
+            let (kind, knn_images) = &*knn_hash;
+            if knn_images.has_enough_examples() {
+                knn_images.classify(&hash_dart_image(&img, *kind))
+            } else {
+                format!("Need more examples; {} < {}", knn_images.len(), knn_images.get_k())
+            }
+        }
+        Err(e) => format!("Lock error: {e}")
+    }
+}
+
+/// `classify_knn_hash` on a camera frame, hashed from its Y plane.
+pub fn classify_knn_hash_frame(img: ImageData) -> String {
+    record_frame(&img);
+    match KNN_HASH.lock() {
+        Ok(knn_hash) => {
+            let (kind, knn_images) = &*knn_hash;
+            if knn_images.has_enough_examples() {
+                knn_images.classify(&hash_image_data(&img, *kind))
+            } else {
+                format!("Need more examples; {} < {}", knn_images.len(), knn_images.get_k())
+            }
+        }
+        Err(e) => format!("Lock error: {e}")
+    }
+}
+
+/// Ways to reduce a photo to a 64-bit perceptual hash.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HashKind {
+    /// Average hash: each cell of an 8x8 thumbnail against the mean.
+    Average,
+    /// Difference hash: each cell of a 9x8 thumbnail against its right
+    /// neighbor. Cheap, and robust to brightness changes.
+    Difference,
+    /// pHash: the signs of the lowest DCT frequencies of a 32x32 thumbnail.
+    /// The slowest, and the most robust to blur and noise.
+    Dct,
+}
+
+pub fn perceptual_hash(img: DartImage, kind: HashKind) -> u64 {
+    hash_dart_image(&img, kind).0
+}
+
+pub fn frame_perceptual_hash(img: ImageData, kind: HashKind) -> u64 {
+    record_frame(&img);
+    hash_image_data(&img, kind).0
+}
+
+/// Number of bits in which two perceptual hashes differ, from 0 to 64.
+pub fn hash_distance(a: u64, b: u64) -> u32 {
+    PerceptualHash(a).distance(&PerceptualHash(b))
+}
+
+/// Pads out a small training set with jittered copies of its photos; see
+/// `AugmentSettings`. Pass the result to any of the `train_knn*` functions.
+pub fn augment_examples(examples: Vec<LabeledImage>, settings: AugmentSettings) -> Vec<LabeledImage> {
+    augment(&examples, &settings)
+}
+
+/// The kNN classifiers that `load_project` can train.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KnnModel {
+    Pixels,
+    AkazePositions,
+    AkazeFeatures,
+    AverageHash,
+    DifferenceHash,
+    DctHash,
+}
+
+/// Trains `model` on the photos of `project`, read straight from the
+/// `projects/<project>/<label>/*.png` layout under the app directory `dir`.
+pub fn load_project(dir: String, project: String, k: usize, model: KnnModel) -> anyhow::Result<String> {
+    let examples = labeled_images(project_dir(dir, &project))?;
+    Ok(match model {
+        KnnModel::Pixels => train_knn(k, examples),
+        KnnModel::AkazePositions => train_knn_akaze_pos(k, examples),
+        KnnModel::AkazeFeatures => train_knn_akaze_features(k, examples),
+        KnnModel::AverageHash => train_knn_hash(k, examples, HashKind::Average),
+        KnnModel::DifferenceHash => train_knn_hash(k, examples, HashKind::Difference),
+        KnnModel::DctHash => train_knn_hash(k, examples, HashKind::Dct),
+    })
+}
+
+/// Packs `project` into a zip archive at `archive`, with a manifest of every
+/// photo's label, file name, size and modification time.
+pub fn export_project(dir: String, project: String, archive: String) -> anyhow::Result<String> {
+    let manifest = datasets::export_project(dir, &project, archive)?;
+    Ok(format!("Exported {} photos", manifest.len()))
+}
+
+/// Adds the photos of an archive from `export_project` to `project`.
+pub fn import_project(dir: String, project: String, archive: String) -> anyhow::Result<String> {
+    let manifest = datasets::import_project(dir, &project, archive)?;
+    Ok(format!("Imported {} photos", manifest.len()))
+}
+
+/// Photos of one label that nearly repeat one another, by index into the
+/// report's `names`.
+pub struct DuplicateGroup {
+    pub label: String,
+    pub members: Vec<i64>,
+}
+
+/// Two nearly identical photos with different labels.
+pub struct LabelConflict {
+    pub first: i64,
+    pub second: i64,
+    /// Root-mean-square difference per byte, in 8-bit levels, or differing
+    /// hash bits for the `*_hash_duplicates` functions.
+    pub distance: f64,
+}
+
+pub struct DuplicateReport {
+    /// Every photo scanned: `label/filename` for a project, `label #index`
+    /// for a list of examples.
+    pub names: Vec<String>,
+    pub groups: Vec<DuplicateGroup>,
+    pub conflicts: Vec<LabelConflict>,
+}
+
+/// Finds photos no more than `max_distance` apart (see `LabelConflict::distance`):
+/// groups of near-duplicates under one label, which skew kNN votes, and pairs
+/// under different labels, which contradict each other.
+pub fn find_duplicates(examples: Vec<LabeledImage>, max_distance: f64) -> DuplicateReport {
+    let names = example_names(&examples);
+    let duplicates = duplicates::find_duplicates(&examples, max_distance, pixel_distance);
+    duplicate_report(&examples, names, duplicates)
+}
 
+/// `find_duplicates` comparing perceptual hashes, which also catches photos
+/// of different sizes: pairs whose hashes differ in no more than `max_bits`.
+pub fn find_hash_duplicates(examples: Vec<LabeledImage>, kind: HashKind, max_bits: u32) -> DuplicateReport {
+    let names = example_names(&examples);
+    let duplicates = hash_duplicates(&examples, kind, max_bits);
+    duplicate_report(&examples, names, duplicates)
+}
+
+/// `find_duplicates` over the photos of `project` under the app directory `dir`.
+pub fn project_duplicates(dir: String, project: String, max_distance: f64) -> anyhow::Result<DuplicateReport> {
+    let (names, examples) = named_project_photos(dir, &project)?;
+    let duplicates = duplicates::find_duplicates(&examples, max_distance, pixel_distance);
+    Ok(duplicate_report(&examples, names, duplicates))
+}
+
+/// `find_hash_duplicates` over the photos of `project` under the app directory `dir`.
+pub fn project_hash_duplicates(dir: String, project: String, kind: HashKind, max_bits: u32) -> anyhow::Result<DuplicateReport> {
+    let (names, examples) = named_project_photos(dir, &project)?;
+    let duplicates = hash_duplicates(&examples, kind, max_bits);
+    Ok(duplicate_report(&examples, names, duplicates))
+}
+
+fn example_names(examples: &[LabeledImage]) -> Vec<String> {
+    examples.iter().enumerate().map(|(i, e)| format!("{} #{i}", e.label)).collect()
+}
+
+fn named_project_photos(dir: String, project: &str) -> anyhow::Result<(Vec<String>, Vec<LabeledImage>)> {
+    let mut names = vec![];
+    let mut examples = vec![];
+    for (label, file) in project_photos(project_dir(dir, project))? {
+        names.push(format!("{label}/{}", file.file_name().unwrap().to_string_lossy()));
+        examples.push(LabeledImage { label, image: load_image(&file)? });
+    }
+    Ok((names, examples))
+}
+
+fn hash_duplicates(examples: &[LabeledImage], kind: HashKind, max_bits: u32) -> Duplicates {
+    let labels: Vec<&str> = examples.iter().map(|e| e.label.as_str()).collect();
+    let hashes: Vec<PerceptualHash> = examples.iter().map(|e| hash_dart_image(&e.image, kind)).collect();
+    duplicates::find_duplicates_by(&labels, &hashes, max_bits as f64, |a, b| Some(hash_distance_f64(a, b)))
+}
+
+fn duplicate_report(examples: &[LabeledImage], names: Vec<String>, duplicates: Duplicates) -> DuplicateReport {
+    DuplicateReport {
+        names,
+        groups: duplicates
+            .groups
+            .into_iter()
+            .map(|members| DuplicateGroup {
+                label: examples[members[0]].label.clone(),
+                members: members.into_iter().map(|i| i as i64).collect(),
+            })
+            .collect(),
+        conflicts: duplicates
+            .conflicts
+            .into_iter()
+            .map(|(first, second, distance)| LabelConflict { first: first as i64, second: second as i64, distance })
+            .collect(),
+    }
+}
 
 pub fn classify_knn_akaze_pos(img: DartImage) -> String {
     match KNN_AKAZE_POS.lock() {
@@ -115,12 +352,6 @@ fn distance_rgba(img1: &RgbaImage, img2: &RgbaImage) -> f64 {
         .sum()
 }
 
-fn distance_u8(img1: &Vec<u8>, img2: &Vec<u8>) -> f64 {
-    (0..min(img1.len(), img2.len()))
-        .map(|i| (img1[i] as f64 - img2[i] as f64).powf(2.0))
-        .sum()
-}
-
 fn distance_keypoint_positions(img1: &Vec<KeyPointInfo>, img2: &Vec<KeyPointInfo>) -> f64 {
     (0..min(img1.len(), img2.len()))
         .map(|i| kp_distance_f64(&img1[i].point, &img2[i].point))
@@ -134,6 +365,21 @@ fn distance_keypoint_features(img1: &Vec<KeyPointInfo>, img2: &Vec<KeyPointInfo>
 }
 
 
+/// Selects the pyramid level that `akaze_view`, `akaze_flow` and `color_clusterer`
+/// process at; each level halves the resolution. Results are still drawn at full
+/// resolution.
+pub fn set_pyramid_level(level: i64) {
+    PYRAMID_LEVEL.store(level.clamp(0, 8) as u64, Ordering::SeqCst);
+}
+
+pub(crate) fn pyramid_level() -> i64 {
+    PYRAMID_LEVEL.load(Ordering::SeqCst) as i64
+}
+
+fn pyramid_scale() -> u32 {
+    1 << pyramid_level()
+}
+
 pub fn kmeans_ready() -> bool {
     KMEANS_READY.load(Ordering::SeqCst)
 }
@@ -157,17 +403,9 @@ impl Default for DartImage {
 
 impl DartImage {
     fn akazeify(&self) -> Vec<KeyPointInfo> {
-        let mut img = RgbaImage::new(self.width as u32, self.height as u32);
-        for i in (0..self.bytes.len()).step_by(4) {
-            let mut pixel_bytes = [0; 4];
-            pixel_bytes.clone_from_slice(&self.bytes[i..i+4]);
-            let x = (i as u32 / 4) % self.width as u32;
-            let y = (i as u32 / 4) / self.width as u32;
-            img.put_pixel(x, y, Rgba(pixel_bytes));
-        }
-        let wrapped = DynamicImage::ImageRgba8(img);
-        let akaze = Akaze::dense();
-        let (keypoints, features) = akaze.extract(&wrapped);
+        let frame = RgbaFrame::from_rgba_bytes(&self.bytes, self.width as u32, self.height as u32);
+        let (_, luma) = frame.into_parts();
+        let (keypoints, features) = akaze_features(luma);
         keypoints.iter().zip(features.iter())
             .map(|(point, feature)| KeyPointInfo {point: *point, feature: *feature})
             .collect()
@@ -180,17 +418,268 @@ pub struct LabeledImage {
     pub image: DartImage,
 }
 
-#[derive(Clone)]
+/// Memory layout of the planes in an `ImageData`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// Android `YUV_420_888`: separate `ys`, `us` and `vs` planes, addressed
+    /// with `uv_row_stride` and `uv_pixel_stride`.
+    Yuv420,
+    /// Semi-planar with interleaved V/U samples (V first). The chroma plane is
+    /// passed in `us`; if `us` is empty it is taken from `ys` after the luma rows.
+    Nv21,
+    /// Semi-planar with interleaved U/V samples (U first). Chroma as for `Nv21`.
+    Nv12,
+    /// Fully planar `us` and `vs`, one byte per sample. If `us` and `vs` are
+    /// empty, both are taken from `ys` after the luma rows.
+    I420,
+    /// iOS `BGRA8888`: `ys` holds four bytes per pixel and `y_row_stride` is
+    /// the number of bytes per row. `us` and `vs` are ignored.
+    Bgra8888,
+}
+
+/// Clockwise rotation from sensor orientation to display orientation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    Rotate0,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+/// Orientation and region of interest applied to every frame before processing.
+///
+/// The frame is rotated, then mirrored left-to-right, then cropped. The crop
+/// rectangle is in rotated and mirrored coordinates; a zero `crop_width` or
+/// `crop_height` keeps the whole frame.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FrameTransform {
+    pub rotation: Rotation,
+    pub mirror: bool,
+    pub crop_x: i64,
+    pub crop_y: i64,
+    pub crop_width: i64,
+    pub crop_height: i64,
+}
+
+impl Default for FrameTransform {
+    fn default() -> Self {
+        Self {
+            rotation: Rotation::Rotate0,
+            mirror: false,
+            crop_x: 0,
+            crop_y: 0,
+            crop_width: 0,
+            crop_height: 0,
+        }
+    }
+}
+
+/// Matrix coefficients used to turn camera YUV into RGB.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorStandard {
+    /// SD video and JPEG; what most phone cameras produce.
+    Bt601,
+    /// HD video.
+    Bt709,
+}
+
+/// How `ColorTarget::tolerance` is measured.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Per-channel difference, 0..=255.
+    Rgb,
+    /// Hue difference in degrees; dim or washed-out pixels never match.
+    Hsv,
+}
+
+/// A color for the robot to drive toward.
+#[derive(Copy, Clone, Debug)]
+pub struct ColorTarget {
+    pub red: i64,
+    pub green: i64,
+    pub blue: i64,
+    pub tolerance: i64,
+    pub space: ColorSpace,
+    /// Matching regions smaller than this many pixels are ignored.
+    pub min_area: i64,
+}
+
+/// Whether luma spans 16..=235 (chroma 16..=240) or the full 0..=255.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorRange {
+    Limited,
+    Full,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ImageData {
     pub ys: Vec<u8>,
     pub us: Vec<u8>,
     pub vs: Vec<u8>,
     pub width: i64,
     pub height: i64,
+    pub y_row_stride: i64,
     pub uv_row_stride: i64,
     pub uv_pixel_stride: i64,
+    pub format: PixelFormat,
+}
+
+/// A straight line in a frame, `x cos(theta) + y sin(theta) = rho` in pixels and
+/// radians, with the endpoints of the edge pixels that support it.
+pub struct LineSegment {
+    pub rho: f64,
+    pub theta: f64,
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+    pub votes: i64,
+}
+
+impl From<HoughLine> for LineSegment {
+    fn from(line: HoughLine) -> Self {
+        Self {
+            rho: line.rho as f64,
+            theta: line.theta as f64,
+            x1: line.start.0 as f64,
+            y1: line.start.1 as f64,
+            x2: line.end.0 as f64,
+            y2: line.end.1 as f64,
+            votes: line.votes as i64,
+        }
+    }
+}
+
+/// A connected region of one color cluster in a clustered frame. The bounding
+/// box is inclusive.
+pub struct ColorBlob {
+    pub red: i64,
+    pub green: i64,
+    pub blue: i64,
+    pub area: i64,
+    pub centroid_x: f64,
+    pub centroid_y: f64,
+    pub left: i64,
+    pub top: i64,
+    pub right: i64,
+    pub bottom: i64,
+}
+
+impl From<Blob> for ColorBlob {
+    fn from(blob: Blob) -> Self {
+        Self {
+            red: blob.color.0 as i64,
+            green: blob.color.1 as i64,
+            blue: blob.color.2 as i64,
+            area: blob.area as i64,
+            centroid_x: blob.centroid.0,
+            centroid_y: blob.centroid.1,
+            left: blob.top_left.0 as i64,
+            top: blob.top_left.1 as i64,
+            right: blob.bottom_right.0 as i64,
+            bottom: blob.bottom_right.1 as i64,
+        }
+    }
+}
+
+/// Where the tracked color is and how to drive toward it. Speeds are in the same
+/// units as `SensorData::left_speed` and `right_speed`; both are 0 when the target
+/// is not in view or has been reached.
+pub struct TrackingCommand {
+    pub found: bool,
+    pub left_speed: i64,
+    pub right_speed: i64,
+    pub centroid_x: f64,
+    pub centroid_y: f64,
+    pub area: i64,
+}
+
+/// Something for the robot to do, sent over its socket as `RobotCommand::to_wire`.
+/// Positive speeds drive forward; `Turn` spins in place, clockwise when positive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RobotCommand {
+    /// Keep doing whatever the robot is already doing.
+    NoOp,
+    Start,
+    Stop,
+    Drive { speed: i64 },
+    Turn { speed: i64 },
+    SetSpeeds { left: i64, right: i64 },
+}
+
+/// Which controller picks commands when none are queued.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ControllerKind {
+    /// Only queued commands are sent.
+    Manual,
+    AvoidObstacles,
+    FollowLeftWall,
+    FollowRightWall,
+    /// Carries out the action of the label that `observe_label` settled on.
+    Vision,
+}
+
+/// Thresholds for the sonar controllers. Distances are in the units of the
+/// `SensorData` sonar readings; speeds in the units of its wheel speeds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SonarSettings {
+    /// Anything closer than this ahead makes the robot turn.
+    pub obstacle_distance: i64,
+    /// How far a wall follower stays from its wall.
+    pub wall_distance: i64,
+    pub cruise_speed: i64,
+    pub turn_speed: i64,
 }
 
+impl Default for SonarSettings {
+    fn default() -> Self {
+        Self {
+            obstacle_distance: 20,
+            wall_distance: 15,
+            cruise_speed: 100,
+            turn_speed: 50,
+        }
+    }
+}
+
+/// How `augment_examples` varies each photo. Every copy gets its own random
+/// rotation, shift, brightness, contrast and noise, each uniform up to these
+/// limits; the same `seed` always gives the same copies.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AugmentSettings {
+    /// Extra copies made of each photo.
+    pub copies: i64,
+    /// Mirrors every other copy left to right. Off by default, since it turns
+    /// a photo of a left turn into one of a right turn.
+    pub flip: bool,
+    pub max_rotation_degrees: f64,
+    /// As a fraction of the photo's width and height.
+    pub max_shift: f64,
+    /// Added to every channel, in 8-bit levels.
+    pub max_brightness: f64,
+    /// Fractional change of the spread of every channel around mid-gray.
+    pub max_contrast: f64,
+    /// Per-channel noise, in 8-bit levels.
+    pub max_noise: f64,
+    pub seed: i64,
+}
+
+impl Default for AugmentSettings {
+    fn default() -> Self {
+        Self {
+            copies: 4,
+            flip: false,
+            max_rotation_degrees: 5.0,
+            max_shift: 0.05,
+            max_brightness: 20.0,
+            max_contrast: 0.15,
+            max_noise: 6.0,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct SensorData {
     pub sonar_front: i64,
     pub sonar_left: i64,
@@ -204,6 +693,19 @@ pub struct SensorData {
 pub struct ImageResponse {
     pub img: ZeroCopyBuffer<Vec<u8>>,
     pub msg: String,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl ImageResponse {
+    fn from_rgba(rgba: RgbaImage, msg: String) -> Self {
+        Self {
+            width: rgba.width() as i64,
+            height: rgba.height() as i64,
+            img: ZeroCopyBuffer(rgba.into_raw()),
+            msg,
+        }
+    }
 }
 
 impl SensorData {
@@ -217,7 +719,7 @@ impl SensorData {
     }
 }
 
-pub fn intensity_rgba(intensities: Vec<u8>) -> ImageResponse {
+pub fn intensity_rgba(intensities: Vec<u8>, width: i64, height: i64) -> ImageResponse {
     let mut result = Vec::new();
     for byte in intensities.iter().copied() {
         for _ in 0..3 {
@@ -228,141 +730,367 @@ pub fn intensity_rgba(intensities: Vec<u8>) -> ImageResponse {
     ImageResponse {
         img: ZeroCopyBuffer(result),
         msg: "Ok".to_owned(),
+        width,
+        height,
     }
 }
 
+pub fn set_color_conversion(standard: ColorStandard, range: ColorRange) {
+    set_yuv_conversion(YuvConversion::new(standard, range));
+}
+
+pub fn set_frame_transform(transform: FrameTransform) {
+    image_proc::set_frame_transform(transform);
+}
+
 pub fn yuv_rgba(img: ImageData) -> ImageResponse {
-    ImageResponse {
-        img: ZeroCopyBuffer(inner_yuv_rgba(&img)),
-        msg: "Ok".to_owned(),
-    }
+    record_frame(&img);
+    rgba_response(&img)
+}
+
+fn rgba_response(img: &ImageData) -> ImageResponse {
+    ImageResponse::from_rgba(image_proc::convert(img), "Ok".to_owned())
 }
 
 pub fn color_count(img: ImageData) -> i64 {
-    let rgba = inner_yuv_rgba(&img);
-    let mut distinct_colors = BTreeSet::new();
-    for i in (0..rgba.len()).step_by(4) {
-        let color = (rgba[i], rgba[i + 1], rgba[i + 2]);
-        distinct_colors.insert(color);
-    }
+    record_frame(&img);
+    let rgba = image_proc::convert(&img);
+    let distinct_colors: BTreeSet<U8ColorTriple> = rgba.pixels().map(|p| (p[0], p[1], p[2])).collect();
     distinct_colors.len() as i64
 }
 
-fn cluster_colored(img: ImageData) -> Vec<u8> {
-    let image = simple_yuv_rgb(&img);
-    RGB_MEANS.lock().unwrap().as_ref().map_or_else(
-        || {
-            (0..(img.height * img.width * 4))
-                .map(|i| if i % 4 == 0 { u8::MAX } else { 0 })
-                .collect()
-        },
+/// The frame with every pixel replaced by its nearest color cluster: a mean of
+/// the trained color model or, until one is trained, a color of the frame's
+/// own `Palette`.
+fn cluster_colored(img: ImageData) -> RgbaImage {
+    let rgba = image_proc::convert(&img);
+    let (width, height) = rgba.dimensions();
+    let scale = pyramid_scale();
+    let rgba = if scale > 1 { downsample(&rgba, scale) } else { rgba };
+    let result = RGB_MEANS.lock().unwrap().as_ref().map_or_else(
+        || Palette::of_frame(&rgba, FRAME_PALETTE_SIZE).recolor(&rgba),
         |kmeans| {
-            let mut result = vec![];
-            for color in image {
-                let mean = kmeans.best_matching_mean(&color);
+            let mut result = RgbaImage::new(rgba.width(), rgba.height());
+            for (pixel, src) in result.pixels_mut().zip(rgba.pixels()) {
+                let mean = kmeans.best_matching_mean(&(src[0], src[1], src[2]));
                 let bytes: (u8, u8, u8) = mean.into();
-                result.push(bytes.0);
-                result.push(bytes.1);
-                result.push(bytes.2);
-                result.push(u8::MAX);
+                *pixel = Rgba([bytes.0, bytes.1, bytes.2, u8::MAX]);
             }
             result
         },
-    )
+    );
+    if scale > 1 {
+        upsample(&result, scale, width, height)
+    } else {
+        result
+    }
 }
 
 pub fn color_clusterer(img: ImageData) -> ImageResponse {
+    record_frame(&img);
     if kmeans_ready() {
-        ImageResponse {
-            img: ZeroCopyBuffer(cluster_colored(img)),
-            msg: "Ok".to_owned(),
-        }
+        ImageResponse::from_rgba(cluster_colored(img), "Ok".to_owned())
     } else {
-        yuv_rgba(img)
+        rgba_response(&img)
     }
 }
 
-pub fn akaze_view(img: ImageData) -> ImageResponse {
-    let rgba = convert(&img);
-    let wrapped = DynamicImage::ImageRgba8(rgba);
-    let akaze = Akaze::dense();
-    let (keypoints, features) = akaze.extract(&wrapped);
-    if let DynamicImage::ImageRgba8(mut unwrapped) = wrapped {
-        let num_points = keypoints.len();
-        TOTAL_KEYPOINTS.fetch_add(num_points as u64, Ordering::SeqCst);
-        plot_keypoints_on(&keypoints, &mut unwrapped, [255, 0, 0, 255]);
-        let total_features_seen = {
-            let mut all_features = ALL_FEATURES.lock().unwrap();
-            let last_features = LAST_FEATURES.lock().unwrap();
-            for feature in last_features.iter() {
-                all_features.insert(*feature);
-            }
-            all_features.len()
-        };
+/// Free space in front of the robot, found by treating the color clusters of the
+/// frame's bottom-center region as floor. Until a color model is trained, the
+/// clusters come from the frame itself.
+pub struct FreeSpace {
+    /// One byte per pixel, row-major: 255 for floor, 0 for anything else.
+    pub mask: ZeroCopyBuffer<Vec<u8>>,
+    pub width: i64,
+    pub height: i64,
+    /// For each column, the number of floor pixels above the bottom row before
+    /// the first obstacle pixel.
+    pub column_heights: Vec<i64>,
+}
 
-        {
-            *LAST_POINTS.lock().unwrap() = keypoints;
-        }
-        {
-            *LAST_FEATURES.lock().unwrap() = features;
-        }
+fn floor_mask(img: ImageData) -> GrayImage {
+    free_space_mask(&cluster_colored(img))
+}
+
+pub fn floor_free_space(img: ImageData) -> FreeSpace {
+    record_frame(&img);
+    let mask = floor_mask(img);
+    let (width, height) = mask.dimensions();
+    let column_heights = column_free_space(&mask).into_iter().map(|h| h as i64).collect();
+    FreeSpace {
+        mask: ZeroCopyBuffer(mask.into_raw()),
+        width: width as i64,
+        height: height as i64,
+        column_heights,
+    }
+}
+
+pub fn floor_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let mask = floor_mask(img);
+    let (width, height) = mask.dimensions();
+    intensity_rgba(mask.into_raw(), width as i64, height as i64)
+}
+
+/// Blobs smaller than `min_area` pixels are left out of `detect_blobs` and `blob_view`.
+pub fn set_min_blob_area(min_area: i64) {
+    BLOB_TRACKING.lock().unwrap().0 = max(min_area, 1) as u32;
+}
+
+/// Restricts `detect_blobs` and `blob_view` to the color cluster whose mean is
+/// nearest to the given color.
+pub fn track_blob_color(red: i64, green: i64, blue: i64) {
+    let color = (red.clamp(0, 255) as u8, green.clamp(0, 255) as u8, blue.clamp(0, 255) as u8);
+    BLOB_TRACKING.lock().unwrap().1 = Some(color);
+}
 
-        let total = TOTAL_KEYPOINTS.load(Ordering::SeqCst);
-        ImageResponse {
-            img: ZeroCopyBuffer(unwrapped.into_vec()),
-            msg: format!(
-                "points: {num_points} total features: {total_features_seen} ({} total, {} repeats)",
-                total,
-                total - total_features_seen as u64
-            ),
+pub fn stop_blob_tracking() {
+    BLOB_TRACKING.lock().unwrap().1 = None;
+}
+
+fn find_blobs(clustered: &RgbaImage) -> Vec<Blob> {
+    let (min_area, target) = *BLOB_TRACKING.lock().unwrap();
+    let target = target.map(|color| match RGB_MEANS.lock().unwrap().as_ref() {
+        Some(kmeans) => kmeans.best_matching_mean(&color),
+        None => {
+            let present: BTreeSet<U8ColorTriple> = clustered.pixels().map(|p| (p[0], p[1], p[2])).collect();
+            Palette::new(present.into_iter().collect()).nearest(color)
         }
+    });
+    color_blobs(clustered, min_area)
+        .into_iter()
+        .filter(|blob| target.is_none() || target == Some(blob.color))
+        .collect()
+}
+
+/// Blobs of the clustered frame, largest first.
+pub fn detect_blobs(img: ImageData) -> Vec<ColorBlob> {
+    record_frame(&img);
+    find_blobs(&cluster_colored(img)).into_iter().map(ColorBlob::from).collect()
+}
+
+pub fn blob_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let mut clustered = cluster_colored(img);
+    let blobs = find_blobs(&clustered);
+    plot_blobs_on(&blobs, &mut clustered, [u8::MAX, u8::MAX, u8::MAX, u8::MAX]);
+    ImageResponse::from_rgba(clustered, format!("blobs: {}", blobs.len()))
+}
+
+pub fn set_color_target(target: ColorTarget) {
+    *COLOR_TARGET.lock().unwrap() = Some(ColorMatcher::new(&target));
+}
+
+/// Sets the straight-ahead speed and the largest steering correction that
+/// `track_color_target` uses.
+pub fn set_tracking_speeds(cruise: i64, turn: i64) {
+    *TRACKING_SPEEDS.lock().unwrap() = (cruise, turn);
+}
+
+/// The largest region matching the color target, at full resolution.
+fn find_color_target(rgba: &RgbaImage) -> Option<Blob> {
+    let matcher = (*COLOR_TARGET.lock().unwrap())?;
+    let scale = pyramid_scale();
+    if scale > 1 {
+        let min_area = matcher.min_area().div_ceil(scale * scale);
+        Some(matcher.largest_region(&downsample(rgba, scale), min_area)?.to_full_scale(scale))
     } else {
-        panic!("This shouldn't happen");
+        matcher.largest_region(rgba, matcher.min_area())
     }
 }
 
-pub fn akaze_flow(img: ImageData) -> ImageResponse {
-    let rgba = convert(&img);
-    let wrapped = DynamicImage::ImageRgba8(rgba);
-    let akaze = Akaze::dense();
-    let (keypoints, features) = akaze.extract(&wrapped);
-    if let DynamicImage::ImageRgba8(mut unwrapped) = wrapped {
-        {
-            let last_keypoints = LAST_POINTS.lock().unwrap();
-            let last_features = LAST_FEATURES.lock().unwrap();
-            let movements = KeyPointMovements::feature_match(
-                &last_keypoints,
-                &last_features,
-                &keypoints,
-                &features,
-            );
-            //movements.render_on(&mut unwrapped, [0, 255, 0, 255]);
-            movements.render_mean_on(&mut unwrapped, [255, 0, 0, 255]);
-        }
-        {
-            let last_keypoints = LAST_POINTS.lock().unwrap();
-            let last_features = LAST_FEATURES.lock().unwrap();
-            let movements = KeyPointMovements::keypoint_match(
-                &last_keypoints,
-                &last_features,
-                &keypoints,
-                &features,
-            );
-            //movements.render_on(&mut unwrapped, [0, 0, 255, 255]);
-            movements.render_mean_on(&mut unwrapped, [0, 255, 0, 255]);
-        }
-        {
-            *LAST_POINTS.lock().unwrap() = keypoints;
+/// Steers toward the color target and queues the wheel speeds for the robot
+/// as `SetSpeeds`.
+pub fn track_color_target(img: ImageData) -> TrackingCommand {
+    record_frame(&img);
+    let rgba = image_proc::convert(&img);
+    let target = find_color_target(&rgba);
+    let (cruise, turn) = *TRACKING_SPEEDS.lock().unwrap();
+    let (left_speed, right_speed) = steer_toward(target.as_ref(), rgba.width(), rgba.height(), cruise, turn);
+    COMMAND_QUEUE.lock().unwrap().push_speeds(left_speed, right_speed);
+    TrackingCommand {
+        found: target.is_some(),
+        left_speed,
+        right_speed,
+        centroid_x: target.map_or(0.0, |blob| blob.centroid.0),
+        centroid_y: target.map_or(0.0, |blob| blob.centroid.1),
+        area: target.map_or(0, |blob| blob.area as i64),
+    }
+}
+
+pub fn color_target_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let mut rgba = image_proc::convert(&img);
+    let target = find_color_target(&rgba);
+    let (cruise, turn) = *TRACKING_SPEEDS.lock().unwrap();
+    let (left, right) = steer_toward(target.as_ref(), rgba.width(), rgba.height(), cruise, turn);
+    let targets: Vec<Blob> = target.into_iter().collect();
+    plot_blobs_on(&targets, &mut rgba, [0, u8::MAX, 0, u8::MAX]);
+    ImageResponse::from_rgba(rgba, format!("L: {left} R: {right}"))
+}
+
+pub fn sobel_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let edges = sobel_magnitude(&luma_image(&img));
+    let (width, height) = edges.dimensions();
+    intensity_rgba(edges.into_raw(), width as i64, height as i64)
+}
+
+/// Sets the gradient magnitudes below which `canny_view` discards edge pixels
+/// (`low`) and above which it always keeps them (`high`).
+pub fn set_canny_thresholds(low: f64, high: f64) {
+    *CANNY_THRESHOLDS.lock().unwrap() = (low as f32, high as f32);
+}
+
+pub fn canny_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let (low, high) = *CANNY_THRESHOLDS.lock().unwrap();
+    let edges = canny(&luma_image(&img), low, high);
+    let (width, height) = edges.dimensions();
+    intensity_rgba(edges.into_raw(), width as i64, height as i64)
+}
+
+/// Sets how many Canny edge pixels a line needs (`min_votes`) and how many of
+/// the strongest lines `detect_lines` and `lines_view` report (`max_lines`).
+pub fn set_line_detection(min_votes: i64, max_lines: i64) {
+    *LINE_DETECTION.lock().unwrap() = (max(min_votes, 1) as u32, max(max_lines, 0) as usize);
+}
+
+fn find_lines(luma: &GrayImage) -> Vec<HoughLine> {
+    let (low, high) = *CANNY_THRESHOLDS.lock().unwrap();
+    let (min_votes, max_lines) = *LINE_DETECTION.lock().unwrap();
+    hough_lines(&canny(luma, low, high), min_votes, max_lines)
+}
+
+pub fn detect_lines(img: ImageData) -> Vec<LineSegment> {
+    record_frame(&img);
+    find_lines(&luma_image(&img))
+        .into_iter()
+        .map(LineSegment::from)
+        .collect()
+}
+
+pub fn lines_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let (mut rgba, luma) = RgbaFrame::from_image_data(&img).into_parts();
+    let lines = find_lines(&luma);
+    plot_lines_on(&lines, &mut rgba, [0, 255, 0, 255]);
+    ImageResponse::from_rgba(rgba, format!("lines: {}", lines.len()))
+}
+
+pub fn akaze_view(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let (mut rgba, luma) = RgbaFrame::from_image_data(&img).into_parts();
+    let (keypoints, features) = akaze_at_scale(luma, pyramid_scale());
+    let num_points = keypoints.len();
+    TOTAL_KEYPOINTS.fetch_add(num_points as u64, Ordering::SeqCst);
+    plot_keypoints_on(&keypoints, &mut rgba, [255, 0, 0, 255]);
+    let total_features_seen = {
+        let mut all_features = ALL_FEATURES.lock().unwrap();
+        let last_features = LAST_FEATURES.lock().unwrap();
+        for feature in last_features.iter() {
+            all_features.insert(*feature);
         }
-        {
-            *LAST_FEATURES.lock().unwrap() = features;
+        all_features.len()
+    };
+
+    {
+        *LAST_POINTS.lock().unwrap() = keypoints;
+    }
+    {
+        *LAST_FEATURES.lock().unwrap() = features;
+    }
+
+    let total = TOTAL_KEYPOINTS.load(Ordering::SeqCst);
+    ImageResponse::from_rgba(
+        rgba,
+        format!(
+            "points: {num_points} total features: {total_features_seen} ({} total, {} repeats)",
+            total,
+            total - total_features_seen as u64
+        ),
+    )
+}
+
+pub fn akaze_flow(img: ImageData) -> ImageResponse {
+    record_frame(&img);
+    let (mut rgba, luma) = RgbaFrame::from_image_data(&img).into_parts();
+    let (keypoints, features) = akaze_at_scale(luma, pyramid_scale());
+    {
+        let last_keypoints = LAST_POINTS.lock().unwrap();
+        let last_features = LAST_FEATURES.lock().unwrap();
+        let movements = KeyPointMovements::feature_match(
+            &last_keypoints,
+            &last_features,
+            &keypoints,
+            &features,
+        );
+        //movements.render_on(&mut rgba, [0, 255, 0, 255]);
+        movements.render_mean_on(&mut rgba, [255, 0, 0, 255]);
+    }
+    {
+        let last_keypoints = LAST_POINTS.lock().unwrap();
+        let last_features = LAST_FEATURES.lock().unwrap();
+        let movements = KeyPointMovements::keypoint_match(
+            &last_keypoints,
+            &last_features,
+            &keypoints,
+            &features,
+        );
+        //movements.render_on(&mut rgba, [0, 0, 255, 255]);
+        movements.render_mean_on(&mut rgba, [0, 255, 0, 255]);
+    }
+    {
+        *LAST_POINTS.lock().unwrap() = keypoints;
+    }
+    {
+        *LAST_FEATURES.lock().unwrap() = features;
+    }
+    ImageResponse::from_rgba(rgba, format!(""))
+}
+
+/// AKAZE builds no scale space for images whose shorter side is below this.
+const AKAZE_MIN_SIZE: u32 = 40;
+
+/// AKAZE keypoints and features of `luma`; none if it is too small to search.
+fn akaze_features(luma: GrayImage) -> (Vec<KeyPoint>, Vec<BitArray<64>>) {
+    if min(luma.width(), luma.height()) < AKAZE_MIN_SIZE {
+        return (vec![], vec![]);
+    }
+    Akaze::dense().extract(&DynamicImage::ImageLuma8(luma))
+}
+
+/// Runs AKAZE on `luma` downsampled by `scale`, with keypoints mapped back to
+/// full-resolution coordinates.
+fn akaze_at_scale(luma: GrayImage, scale: u32) -> (Vec<KeyPoint>, Vec<BitArray<64>>) {
+    let luma = if scale > 1 { downsample(&luma, scale) } else { luma };
+    let (mut keypoints, features) = akaze_features(luma);
+    for kp in keypoints.iter_mut() {
+        kp.point = to_full_scale(kp.point, scale);
+        kp.size *= scale as f32;
+    }
+    (keypoints, features)
+}
+
+fn plot_lines_on(lines: &[HoughLine], img: &mut RgbaImage, color: [u8; 4]) {
+    for line in lines.iter() {
+        for p in BresenhamLinePixelIterMut::new(img, line.start, line.end) {
+            *p = Rgba(color);
         }
-        ImageResponse {
-            img: ZeroCopyBuffer(unwrapped.into_vec()),
-            msg: format!(""),
+    }
+}
+
+fn plot_blobs_on(blobs: &[Blob], img: &mut RgbaImage, color: [u8; 4]) {
+    for blob in blobs.iter() {
+        let (left, top) = (blob.top_left.0 as f32, blob.top_left.1 as f32);
+        let (right, bottom) = (blob.bottom_right.0 as f32, blob.bottom_right.1 as f32);
+        let corners = [(left, top), (right, top), (right, bottom), (left, bottom)];
+        for i in 0..corners.len() {
+            let end = corners[(i + 1) % corners.len()];
+            for p in BresenhamLinePixelIterMut::new(img, corners[i], end) {
+                *p = Rgba(color);
+            }
         }
-    } else {
-        panic!("This shouldn't happen");
     }
 }
 
@@ -377,12 +1105,19 @@ fn plot_keypoints_on(
     }
 }
 
+/// Forgets the previous frame, so the next `akaze_flow` shows no motion.
+pub fn reset_akaze_flow() {
+    LAST_POINTS.lock().unwrap().clear();
+    LAST_FEATURES.lock().unwrap().clear();
+}
+
 pub fn reset_position_estimate() {
     let mut pos = POS.lock().unwrap();
     pos.reset();
 }
 
 pub fn process_sensor_data(incoming_data: String) -> String {
+    record_sensors(&incoming_data);
     let parsed = parse_sensor_data(incoming_data);
     let mut pos = POS.lock().unwrap();
     pos.motor_update(parsed.motor_data());
@@ -395,6 +1130,118 @@ pub fn process_sensor_data(incoming_data: String) -> String {
     )
 }
 
+/// Appends every frame and sensor packet the native code receives from now on
+/// to a log at `path`, replacing any recording in progress. The log keeps the
+/// frame conversion settings in effect now; later changes are not recorded.
+pub fn start_recording(path: String) -> anyhow::Result<()> {
+    *RECORDER.lock().unwrap() = Some(Recorder::create(path)?);
+    Ok(())
+}
+
+pub fn stop_recording() -> anyhow::Result<()> {
+    if let Some(mut recorder) = RECORDER.lock().unwrap().take() {
+        recorder.flush()?;
+    }
+    Ok(())
+}
+
+pub fn is_recording() -> bool {
+    RECORDER.lock().unwrap().is_some()
+}
+
+fn record_frame(img: &ImageData) {
+    let mut recorder = RECORDER.lock().unwrap();
+    if recorder.as_mut().is_some_and(|r| r.frame(img).is_err()) {
+        *recorder = None;
+    }
+}
+
+fn record_sensors(packet: &str) {
+    let mut recorder = RECORDER.lock().unwrap();
+    if recorder.as_mut().is_some_and(|r| r.sensors(packet).is_err()) {
+        *recorder = None;
+    }
+}
+
+/// Plays a log from `start_recording` back through `process_sensor_data`,
+/// `akaze_flow` and the kNN classifiers, from a fresh position estimate and
+/// flow history, and returns one message per record. Frames are converted with
+/// the settings they were recorded with; the current settings come back
+/// afterwards. Stops any recording.
+pub fn replay_recording(path: String) -> anyhow::Result<Vec<String>> {
+    stop_recording()?;
+    let log = read_log(path)?;
+    let _restore = log.settings.apply_until_dropped();
+    recording::reset_replay_state();
+    let messages = log
+        .records
+        .into_iter()
+        .map(|record| format!("{} {}", record.millis, recording::replay(record.entry).0))
+        .collect();
+    Ok(messages)
+}
+
+/// Applies to the active sonar controller right away, and to any chosen later.
+pub fn set_sonar_settings(settings: SonarSettings) {
+    *SONAR_SETTINGS.lock().unwrap() = settings;
+    CONTROLLER.lock().unwrap().set_sonar_settings(settings);
+}
+
+pub fn set_controller(kind: ControllerKind) {
+    let settings = *SONAR_SETTINGS.lock().unwrap();
+    let controller: Box<dyn Controller> = match kind {
+        ControllerKind::Manual => Box::new(Manual),
+        ControllerKind::AvoidObstacles => Box::new(SonarController::avoiding(settings)),
+        ControllerKind::FollowLeftWall => Box::new(SonarController::following(settings, WallSide::Left)),
+        ControllerKind::FollowRightWall => Box::new(SonarController::following(settings, WallSide::Right)),
+        ControllerKind::Vision => Box::new(VisionController),
+    };
+    *CONTROLLER.lock().unwrap() = controller;
+}
+
+/// Queues a command to go out ahead of anything the active controller chooses.
+pub fn queue_robot_command(command: RobotCommand) {
+    COMMAND_QUEUE.lock().unwrap().push(command);
+}
+
+pub fn next_robot_command(sensors: SensorData) -> RobotCommand {
+    let queued = COMMAND_QUEUE.lock().unwrap().pop();
+    queued.unwrap_or_else(|| CONTROLLER.lock().unwrap().next_command(&sensors))
+}
+
+struct VisionController;
+
+impl Controller for VisionController {
+    fn next_command(&mut self, _sensors: &SensorData) -> RobotCommand {
+        LABEL_BEHAVIOR.lock().unwrap().command()
+    }
+}
+
+/// Makes the classifier label `label` mean `command` for `observe_label`.
+pub fn set_label_action(label: String, command: RobotCommand) {
+    LABEL_BEHAVIOR.lock().unwrap().set_action(&label, command);
+}
+
+pub fn clear_label_actions() {
+    LABEL_BEHAVIOR.lock().unwrap().clear_actions();
+}
+
+/// Labels vote over the last `window` frames; a new winner must win `hold`
+/// frames in a row before the robot switches to its action.
+pub fn set_label_voting(window: i64, hold: i64) {
+    LABEL_BEHAVIOR.lock().unwrap().set_voting(max(window, 1) as usize, max(hold, 1) as usize);
+}
+
+/// Feeds the latest `classify_knn*` result to the label behavior and returns
+/// the command it settles on.
+pub fn observe_label(label: String) -> RobotCommand {
+    LABEL_BEHAVIOR.lock().unwrap().observe(&label)
+}
+
+pub fn robot_command_wire(command: RobotCommand) -> String {
+    command.to_wire()
+}
+
 pub fn parse_sensor_data(incoming_data: String) -> SensorData {
     let parts: HashMap<&str, i64> = incoming_data
         .split(";")
@@ -0,0 +1,81 @@
+use crate::api::{AugmentSettings, DartImage, LabeledImage};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Geometric and photometric changes applied to one augmented copy.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Jitter {
+    pub flip: bool,
+    /// In radians, about the center of the photo.
+    pub rotation: f64,
+    /// In pixels.
+    pub shift: (f64, f64),
+    pub brightness: f64,
+    pub contrast: f64,
+    pub noise: f64,
+}
+
+impl Jitter {
+    pub fn none() -> Self {
+        Self { flip: false, rotation: 0.0, shift: (0.0, 0.0), brightness: 0.0, contrast: 0.0, noise: 0.0 }
+    }
+
+    fn random(settings: &AugmentSettings, copy: i64, width: i64, height: i64, rng: &mut StdRng) -> Self {
+        let mut uniform = |limit: f64| if limit > 0.0 { rng.gen_range(-limit..=limit) } else { 0.0 };
+        Self {
+            flip: settings.flip && copy % 2 == 1,
+            rotation: uniform(settings.max_rotation_degrees).to_radians(),
+            shift: (uniform(settings.max_shift * width as f64), uniform(settings.max_shift * height as f64)),
+            brightness: uniform(settings.max_brightness),
+            contrast: uniform(settings.max_contrast),
+            noise: settings.max_noise.max(0.0),
+        }
+    }
+}
+
+/// The examples followed by `settings.copies` jittered copies of each, with
+/// the same label. Copies keep the size of their original; pixels moved in
+/// from outside the photo repeat its nearest edge.
+pub fn augment(examples: &[LabeledImage], settings: &AugmentSettings) -> Vec<LabeledImage> {
+    let mut rng = StdRng::seed_from_u64(settings.seed as u64);
+    let mut result = examples.to_vec();
+    for example in examples.iter() {
+        let (width, height) = (example.image.width, example.image.height);
+        for copy in 0..settings.copies {
+            let jitter = Jitter::random(settings, copy, width, height, &mut rng);
+            result.push(LabeledImage {
+                label: example.label.clone(),
+                image: jittered(&example.image, &jitter, &mut rng),
+            });
+        }
+    }
+    result
+}
+
+/// Applies `jitter` to `image`, drawing its noise from `rng`.
+pub fn jittered(image: &DartImage, jitter: &Jitter, rng: &mut StdRng) -> DartImage {
+    let (width, height) = (image.width.max(0) as usize, image.height.max(0) as usize);
+    let (cx, cy) = ((width as f64 - 1.0) / 2.0, (height as f64 - 1.0) / 2.0);
+    let (sin, cos) = jitter.rotation.sin_cos();
+    let mut bytes = Vec::with_capacity(width * height * 4);
+    for y in 0..height {
+        for x in 0..width {
+            // Map each output pixel back to the source: undo the shift, then
+            // the rotation, then the flip.
+            let (dx, dy) = (x as f64 - jitter.shift.0 - cx, y as f64 - jitter.shift.1 - cy);
+            let sx = cos * dx - sin * dy + cx;
+            let sy = sin * dx + cos * dy + cy;
+            let sx = if jitter.flip { width as f64 - 1.0 - sx } else { sx };
+            let sx = (sx.round().max(0.0) as usize).min(width - 1);
+            let sy = (sy.round().max(0.0) as usize).min(height - 1);
+            let src = &image.bytes[(sy * width + sx) * 4..][..4];
+            for channel in src[..3].iter() {
+                let noise = if jitter.noise > 0.0 { rng.gen_range(-jitter.noise..=jitter.noise) } else { 0.0 };
+                let value = (*channel as f64 - 128.0) * (1.0 + jitter.contrast) + 128.0 + jitter.brightness + noise;
+                bytes.push(value.round().clamp(0.0, 255.0) as u8);
+            }
+            bytes.push(src[3]);
+        }
+    }
+    DartImage { bytes, width: image.width, height: image.height }
+}
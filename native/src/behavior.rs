@@ -0,0 +1,118 @@
+use crate::api::RobotCommand;
+use std::collections::{HashMap, VecDeque};
+
+/// Turns a stream of classifier labels into a steady robot command. Each label
+/// votes over a window of recent frames, and a new winning label has to keep
+/// winning for `hold` frames before its action replaces the current one, so a
+/// few misclassified frames do not make the robot twitch.
+#[derive(Clone, Debug)]
+pub struct LabelBehavior {
+    actions: HashMap<String, RobotCommand>,
+    recent: VecDeque<String>,
+    window: usize,
+    hold: usize,
+    current: Option<String>,
+    challenger: Option<(String, usize)>,
+}
+
+impl LabelBehavior {
+    pub fn new(window: usize, hold: usize) -> Self {
+        Self {
+            actions: HashMap::new(),
+            recent: VecDeque::new(),
+            window: window.max(1),
+            hold: hold.max(1),
+            current: None,
+            challenger: None,
+        }
+    }
+
+    pub fn set_action(&mut self, label: &str, command: RobotCommand) {
+        self.actions.insert(label.to_owned(), command);
+    }
+
+    pub fn clear_actions(&mut self) {
+        self.actions.clear();
+    }
+
+    /// Changes the voting window and hold, forgetting recent votes.
+    pub fn set_voting(&mut self, window: usize, hold: usize) {
+        self.window = window.max(1);
+        self.hold = hold.max(1);
+        self.recent.clear();
+        self.challenger = None;
+    }
+
+    /// The label whose action the robot is carrying out.
+    pub fn current_label(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// The current label's action. Labels without an action, and the time before
+    /// any label settles, mean `Stop`.
+    pub fn command(&self) -> RobotCommand {
+        self.current
+            .as_ref()
+            .and_then(|label| self.actions.get(label))
+            .copied()
+            .unwrap_or(RobotCommand::Stop)
+    }
+
+    /// Records the label for the latest frame and returns the resulting command.
+    pub fn observe(&mut self, label: &str) -> RobotCommand {
+        self.recent.push_back(label.to_owned());
+        while self.recent.len() > self.window {
+            self.recent.pop_front();
+        }
+        let winner = self.vote_winner();
+        if self.current.as_ref() == Some(&winner) {
+            self.challenger = None;
+        } else {
+            let streak = match &self.challenger {
+                Some((challenger, streak)) if *challenger == winner => streak + 1,
+                _ => 1,
+            };
+            if streak >= self.hold {
+                self.current = Some(winner);
+                self.challenger = None;
+            } else {
+                self.challenger = Some((winner, streak));
+            }
+        }
+        self.command()
+    }
+
+    /// Most frequent recent label. Ties favor the current label, then the label
+    /// seen most recently.
+    fn vote_winner(&self) -> String {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for label in self.recent.iter() {
+            *counts.entry(label).or_insert(0) += 1;
+        }
+        let most = counts.values().copied().max().unwrap_or(0);
+        if let Some(current) = &self.current {
+            if counts.get(current.as_str()) == Some(&most) {
+                return current.clone();
+            }
+        }
+        self.recent
+            .iter()
+            .rev()
+            .find(|label| counts[label.as_str()] == most)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl Default for LabelBehavior {
+    /// Votes over 5 frames with a hold of 2, knowing the labels `forward`, `left`,
+    /// `right` and `stop`.
+    fn default() -> Self {
+        let mut behavior = Self::new(5, 2);
+        behavior.set_action("forward", RobotCommand::Drive { speed: 100 });
+        behavior.set_action("left", RobotCommand::Turn { speed: -50 });
+        behavior.set_action("right", RobotCommand::Turn { speed: 50 });
+        behavior.set_action("stop", RobotCommand::Stop);
+        behavior
+    }
+}
@@ -0,0 +1,205 @@
+// Runs the vision pipelines over saved frames on a workstation, without Flutter.
+//
+// cargo run --release --bin vision -- akaze_flow --out flow frames/*.png
+// cargo run --release --bin vision -- yuv_rgba --size 640x480 --out rgba dump.nv21
+// cargo run --release --bin vision -- knn --train project --k 3 frames/*.png
+// cargo run --release --bin vision -- replay --train project --out replay run.vblog
+
+use anyhow::{anyhow, bail, Context, Result};
+use image::RgbaImage;
+use native::api::{
+    akaze_flow, akaze_view, classify_knn, classify_knn_akaze_feature, classify_knn_akaze_pos, classify_knn_hash,
+    color_clusterer, train_knn, train_knn_akaze_features, train_knn_akaze_pos, train_knn_hash, yuv_rgba, DartImage,
+    HashKind, ImageData, ImageResponse, LabeledImage, PixelFormat,
+};
+use native::datasets::archive_images;
+use native::image_proc::bgra_frame;
+use native::projects::labeled_images;
+use native::recording::{read_log, replay, reset_replay_state};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "usage: vision <pipeline> [--out DIR] [--size WxH] [--train DIR] [--k N] FILE...
+
+pipelines:
+  yuv_rgba, akaze_view, akaze_flow, color_clusterer   write one PNG per frame to --out
+  knn, knn_akaze_pos, knn_akaze_feature, knn_hash     print the label of each frame
+  replay                                              play back logs from start_recording,
+                                                      writing the akaze_flow view of each frame
+
+Frames are PNG/JPEG images, or raw dumps named *.i420, *.yuv, *.nv21 or *.nv12,
+which need --size. Frames are processed in the order given, so akaze_flow
+tracks motion from each frame to the next.
+
+kNN training examples are read from --train DIR/<label>/*.png, the layout the
+app saves projects in, or from a zip archive made by export_project. replay
+trains all three classifiers if --train is given.";
+
+struct Options {
+    pipeline: String,
+    out: PathBuf,
+    size: Option<(i64, i64)>,
+    train: Option<PathBuf>,
+    k: usize,
+    files: Vec<PathBuf>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let pipeline = args.next().ok_or_else(|| anyhow!("no pipeline given"))?;
+        let mut options = Self { pipeline, out: PathBuf::from("."), size: None, train: None, k: 3, files: vec![] };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| anyhow!("{arg} needs a value"));
+            match arg.as_str() {
+                "--out" => options.out = PathBuf::from(value()?),
+                "--size" => options.size = Some(parse_size(&value()?)?),
+                "--train" => options.train = Some(PathBuf::from(value()?)),
+                "--k" => options.k = value()?.parse().context("--k")?,
+                _ if arg.starts_with("--") => bail!("unknown option {arg}"),
+                _ => options.files.push(PathBuf::from(arg)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn parse_size(size: &str) -> Result<(i64, i64)> {
+    let (width, height) = size.split_once('x').ok_or_else(|| anyhow!("--size must be WxH, not {size}"))?;
+    Ok((width.parse()?, height.parse()?))
+}
+
+/// Loads a frame as the camera would deliver it. Images are passed as
+/// `Bgra8888`, the iOS layout, so they go through the same conversion as
+/// camera frames.
+fn load_frame(path: &Path, size: Option<(i64, i64)>) -> Result<ImageData> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+    let format = match extension.as_str() {
+        "i420" | "yuv" => Some(PixelFormat::I420),
+        "nv21" => Some(PixelFormat::Nv21),
+        "nv12" => Some(PixelFormat::Nv12),
+        _ => None,
+    };
+    match format {
+        Some(format) => {
+            let (width, height) = size.ok_or_else(|| anyhow!("{}: raw frames need --size", path.display()))?;
+            let mut ys = fs::read(path)?;
+            let frame_len = (width * height * 3 / 2) as usize;
+            if ys.len() < frame_len {
+                bail!("{}: too short for {width}x{height}", path.display());
+            }
+            ys.truncate(frame_len);
+            let (uv_row_stride, uv_pixel_stride) = if format == PixelFormat::I420 { (width / 2, 1) } else { (width, 2) };
+            Ok(ImageData {
+                ys,
+                us: vec![],
+                vs: vec![],
+                width,
+                height,
+                y_row_stride: width,
+                uv_row_stride,
+                uv_pixel_stride,
+                format,
+            })
+        }
+        None => {
+            let rgba = image::open(path).with_context(|| path.display().to_string())?.to_rgba8();
+            Ok(bgra_frame(&rgba))
+        }
+    }
+}
+
+/// The RGBA bytes the app hands to the classifiers for a frame.
+fn dart_image(img: ImageData) -> DartImage {
+    let response = yuv_rgba(img);
+    DartImage { bytes: response.img.0, width: response.width, height: response.height }
+}
+
+/// A project directory, or an archive from `export_project`.
+fn training_examples(path: &Path) -> Result<Vec<LabeledImage>> {
+    if path.extension().is_some_and(|e| e == "zip") {
+        archive_images(path)
+    } else {
+        labeled_images(path)
+    }
+}
+
+fn save_response(response: ImageResponse, path: &Path) -> Result<()> {
+    let rgba = RgbaImage::from_raw(response.width as u32, response.height as u32, response.img.0)
+        .ok_or_else(|| anyhow!("{}: response has the wrong size", path.display()))?;
+    rgba.save(path).with_context(|| path.display().to_string())?;
+    Ok(())
+}
+
+fn run(options: Options) -> Result<()> {
+    let view: Option<fn(ImageData) -> ImageResponse> = match options.pipeline.as_str() {
+        "yuv_rgba" => Some(yuv_rgba),
+        "akaze_view" => Some(akaze_view),
+        "akaze_flow" => Some(akaze_flow),
+        "color_clusterer" => Some(color_clusterer),
+        _ => None,
+    };
+    if let Some(view) = view {
+        fs::create_dir_all(&options.out)?;
+        for file in options.files.iter() {
+            let response = view(load_frame(file, options.size)?);
+            let stem = file.file_stem().unwrap().to_string_lossy();
+            let out = options.out.join(format!("{stem}.{}.png", options.pipeline));
+            println!("{}: {}", file.display(), response.msg);
+            save_response(response, &out)?;
+        }
+        return Ok(());
+    }
+
+    if options.pipeline == "replay" {
+        return replay_logs(&options);
+    }
+
+    type Train = fn(usize, Vec<LabeledImage>) -> String;
+    type Classify = fn(DartImage) -> String;
+    let (train, classify): (Train, Classify) = match options.pipeline.as_str() {
+        "knn" => (train_knn, |img| classify_knn(img.bytes)),
+        "knn_akaze_pos" => (train_knn_akaze_pos, classify_knn_akaze_pos),
+        "knn_akaze_feature" => (train_knn_akaze_features, classify_knn_akaze_feature),
+        "knn_hash" => (|k, examples| train_knn_hash(k, examples, HashKind::Dct), classify_knn_hash),
+        other => bail!("unknown pipeline {other}"),
+    };
+    let dir = options.train.as_ref().ok_or_else(|| anyhow!("{} needs --train", options.pipeline))?;
+    eprintln!("{}", train(options.k, training_examples(dir)?));
+    for file in options.files.iter() {
+        let label = classify(dart_image(load_frame(file, options.size)?));
+        println!("{}: {label}", file.display());
+    }
+    Ok(())
+}
+
+fn replay_logs(options: &Options) -> Result<()> {
+    if let Some(dir) = options.train.as_ref() {
+        let examples = training_examples(dir)?;
+        eprintln!("{}", train_knn(options.k, examples.clone()));
+        eprintln!("{}", train_knn_akaze_pos(options.k, examples.clone()));
+        eprintln!("{}", train_knn_akaze_features(options.k, examples));
+    }
+    fs::create_dir_all(&options.out)?;
+    for file in options.files.iter() {
+        let log = read_log(file).with_context(|| file.display().to_string())?;
+        log.settings.apply();
+        reset_replay_state();
+        let stem = file.file_stem().unwrap().to_string_lossy();
+        for (i, record) in log.records.into_iter().enumerate() {
+            let (msg, view) = replay(record.entry);
+            println!("{} {msg}", record.millis);
+            if let Some(view) = view {
+                save_response(view, &options.out.join(format!("{stem}.{i:05}.png")))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let result = Options::parse(std::env::args().skip(1)).and_then(run);
+    if let Err(e) = result {
+        eprintln!("{e:#}\n\n{USAGE}");
+        std::process::exit(1);
+    }
+}
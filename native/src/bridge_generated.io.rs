@@ -29,6 +29,121 @@ pub extern "C" fn wire_train_knn_akaze_features(
     wire_train_knn_akaze_features_impl(port_, k, examples)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_train_knn_hash(
+    port_: i64,
+    k: usize,
+    examples: *mut wire_list_labeled_image,
+    kind: i32,
+) {
+    wire_train_knn_hash_impl(port_, k, examples, kind)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_classify_knn_hash(port_: i64, img: *mut wire_DartImage) {
+    wire_classify_knn_hash_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_classify_knn_hash_frame(port_: i64, img: *mut wire_ImageData) {
+    wire_classify_knn_hash_frame_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_perceptual_hash(port_: i64, img: *mut wire_DartImage, kind: i32) {
+    wire_perceptual_hash_impl(port_, img, kind)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_frame_perceptual_hash(port_: i64, img: *mut wire_ImageData, kind: i32) {
+    wire_frame_perceptual_hash_impl(port_, img, kind)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_hash_distance(port_: i64, a: u64, b: u64) {
+    wire_hash_distance_impl(port_, a, b)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_augment_examples(
+    port_: i64,
+    examples: *mut wire_list_labeled_image,
+    settings: *mut wire_AugmentSettings,
+) {
+    wire_augment_examples_impl(port_, examples, settings)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_load_project(
+    port_: i64,
+    dir: *mut wire_uint_8_list,
+    project: *mut wire_uint_8_list,
+    k: usize,
+    model: i32,
+) {
+    wire_load_project_impl(port_, dir, project, k, model)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_export_project(
+    port_: i64,
+    dir: *mut wire_uint_8_list,
+    project: *mut wire_uint_8_list,
+    archive: *mut wire_uint_8_list,
+) {
+    wire_export_project_impl(port_, dir, project, archive)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_import_project(
+    port_: i64,
+    dir: *mut wire_uint_8_list,
+    project: *mut wire_uint_8_list,
+    archive: *mut wire_uint_8_list,
+) {
+    wire_import_project_impl(port_, dir, project, archive)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_find_duplicates(
+    port_: i64,
+    examples: *mut wire_list_labeled_image,
+    max_distance: f64,
+) {
+    wire_find_duplicates_impl(port_, examples, max_distance)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_find_hash_duplicates(
+    port_: i64,
+    examples: *mut wire_list_labeled_image,
+    kind: i32,
+    max_bits: u32,
+) {
+    wire_find_hash_duplicates_impl(port_, examples, kind, max_bits)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_project_duplicates(
+    port_: i64,
+    dir: *mut wire_uint_8_list,
+    project: *mut wire_uint_8_list,
+    max_distance: f64,
+) {
+    wire_project_duplicates_impl(port_, dir, project, max_distance)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_project_hash_duplicates(
+    port_: i64,
+    dir: *mut wire_uint_8_list,
+    project: *mut wire_uint_8_list,
+    kind: i32,
+    max_bits: u32,
+) {
+    wire_project_hash_duplicates_impl(port_, dir, project, kind, max_bits)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_classify_knn_akaze_pos(port_: i64, img: *mut wire_DartImage) {
     wire_classify_knn_akaze_pos_impl(port_, img)
@@ -39,6 +154,11 @@ pub extern "C" fn wire_classify_knn_akaze_feature(port_: i64, img: *mut wire_Dar
     wire_classify_knn_akaze_feature_impl(port_, img)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_set_pyramid_level(port_: i64, level: i64) {
+    wire_set_pyramid_level_impl(port_, level)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_kmeans_ready(port_: i64) {
     wire_kmeans_ready_impl(port_)
@@ -50,8 +170,23 @@ pub extern "C" fn wire_training_time(port_: i64) {
 }
 
 #[no_mangle]
-pub extern "C" fn wire_intensity_rgba(port_: i64, intensities: *mut wire_uint_8_list) {
-    wire_intensity_rgba_impl(port_, intensities)
+pub extern "C" fn wire_intensity_rgba(
+    port_: i64,
+    intensities: *mut wire_uint_8_list,
+    width: i64,
+    height: i64,
+) {
+    wire_intensity_rgba_impl(port_, intensities, width, height)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_color_conversion(port_: i64, standard: i32, range: i32) {
+    wire_set_color_conversion_impl(port_, standard, range)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_frame_transform(port_: i64, transform: *mut wire_FrameTransform) {
+    wire_set_frame_transform_impl(port_, transform)
 }
 
 #[no_mangle]
@@ -69,6 +204,91 @@ pub extern "C" fn wire_color_clusterer(port_: i64, img: *mut wire_ImageData) {
     wire_color_clusterer_impl(port_, img)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_floor_free_space(port_: i64, img: *mut wire_ImageData) {
+    wire_floor_free_space_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_floor_view(port_: i64, img: *mut wire_ImageData) {
+    wire_floor_view_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_min_blob_area(port_: i64, min_area: i64) {
+    wire_set_min_blob_area_impl(port_, min_area)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_track_blob_color(port_: i64, red: i64, green: i64, blue: i64) {
+    wire_track_blob_color_impl(port_, red, green, blue)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_stop_blob_tracking(port_: i64) {
+    wire_stop_blob_tracking_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_detect_blobs(port_: i64, img: *mut wire_ImageData) {
+    wire_detect_blobs_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_blob_view(port_: i64, img: *mut wire_ImageData) {
+    wire_blob_view_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_color_target(port_: i64, target: *mut wire_ColorTarget) {
+    wire_set_color_target_impl(port_, target)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_tracking_speeds(port_: i64, cruise: i64, turn: i64) {
+    wire_set_tracking_speeds_impl(port_, cruise, turn)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_track_color_target(port_: i64, img: *mut wire_ImageData) {
+    wire_track_color_target_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_color_target_view(port_: i64, img: *mut wire_ImageData) {
+    wire_color_target_view_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sobel_view(port_: i64, img: *mut wire_ImageData) {
+    wire_sobel_view_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_canny_thresholds(port_: i64, low: f64, high: f64) {
+    wire_set_canny_thresholds_impl(port_, low, high)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_canny_view(port_: i64, img: *mut wire_ImageData) {
+    wire_canny_view_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_line_detection(port_: i64, min_votes: i64, max_lines: i64) {
+    wire_set_line_detection_impl(port_, min_votes, max_lines)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_detect_lines(port_: i64, img: *mut wire_ImageData) {
+    wire_detect_lines_impl(port_, img)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_lines_view(port_: i64, img: *mut wire_ImageData) {
+    wire_lines_view_impl(port_, img)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_akaze_view(port_: i64, img: *mut wire_ImageData) {
     wire_akaze_view_impl(port_, img)
@@ -79,6 +299,11 @@ pub extern "C" fn wire_akaze_flow(port_: i64, img: *mut wire_ImageData) {
     wire_akaze_flow_impl(port_, img)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_reset_akaze_flow(port_: i64) {
+    wire_reset_akaze_flow_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_reset_position_estimate(port_: i64) {
     wire_reset_position_estimate_impl(port_)
@@ -89,6 +314,75 @@ pub extern "C" fn wire_process_sensor_data(port_: i64, incoming_data: *mut wire_
     wire_process_sensor_data_impl(port_, incoming_data)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_start_recording(port_: i64, path: *mut wire_uint_8_list) {
+    wire_start_recording_impl(port_, path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_stop_recording(port_: i64) {
+    wire_stop_recording_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_is_recording(port_: i64) {
+    wire_is_recording_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_replay_recording(port_: i64, path: *mut wire_uint_8_list) {
+    wire_replay_recording_impl(port_, path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_sonar_settings(port_: i64, settings: *mut wire_SonarSettings) {
+    wire_set_sonar_settings_impl(port_, settings)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_controller(port_: i64, kind: i32) {
+    wire_set_controller_impl(port_, kind)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_queue_robot_command(port_: i64, command: *mut wire_RobotCommand) {
+    wire_queue_robot_command_impl(port_, command)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_robot_command(port_: i64, sensors: *mut wire_SensorData) {
+    wire_next_robot_command_impl(port_, sensors)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_label_action(
+    port_: i64,
+    label: *mut wire_uint_8_list,
+    command: *mut wire_RobotCommand,
+) {
+    wire_set_label_action_impl(port_, label, command)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_clear_label_actions(port_: i64) {
+    wire_clear_label_actions_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_label_voting(port_: i64, window: i64, hold: i64) {
+    wire_set_label_voting_impl(port_, window, hold)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_observe_label(port_: i64, label: *mut wire_uint_8_list) {
+    wire_observe_label_impl(port_, label)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_robot_command_wire(port_: i64, command: *mut wire_RobotCommand) {
+    wire_robot_command_wire_impl(port_, command)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_parse_sensor_data(port_: i64, incoming_data: *mut wire_uint_8_list) {
     wire_parse_sensor_data_impl(port_, incoming_data)
@@ -96,16 +390,46 @@ pub extern "C" fn wire_parse_sensor_data(port_: i64, incoming_data: *mut wire_ui
 
 // Section: allocate functions
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_augment_settings_0() -> *mut wire_AugmentSettings {
+    support::new_leak_box_ptr(wire_AugmentSettings::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_color_target_0() -> *mut wire_ColorTarget {
+    support::new_leak_box_ptr(wire_ColorTarget::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_dart_image_0() -> *mut wire_DartImage {
     support::new_leak_box_ptr(wire_DartImage::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_frame_transform_0() -> *mut wire_FrameTransform {
+    support::new_leak_box_ptr(wire_FrameTransform::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_image_data_0() -> *mut wire_ImageData {
     support::new_leak_box_ptr(wire_ImageData::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_robot_command_0() -> *mut wire_RobotCommand {
+    support::new_leak_box_ptr(wire_RobotCommand::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_sensor_data_0() -> *mut wire_SensorData {
+    support::new_leak_box_ptr(wire_SensorData::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_sonar_settings_0() -> *mut wire_SonarSettings {
+    support::new_leak_box_ptr(wire_SonarSettings::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_labeled_image_0(len: i32) -> *mut wire_list_labeled_image {
     let wrap = wire_list_labeled_image {
@@ -134,18 +458,83 @@ impl Wire2Api<String> for *mut wire_uint_8_list {
         String::from_utf8_lossy(&vec).into_owned()
     }
 }
+impl Wire2Api<AugmentSettings> for wire_AugmentSettings {
+    fn wire2api(self) -> AugmentSettings {
+        AugmentSettings {
+            copies: self.copies.wire2api(),
+            flip: self.flip.wire2api(),
+            max_rotation_degrees: self.max_rotation_degrees.wire2api(),
+            max_shift: self.max_shift.wire2api(),
+            max_brightness: self.max_brightness.wire2api(),
+            max_contrast: self.max_contrast.wire2api(),
+            max_noise: self.max_noise.wire2api(),
+            seed: self.seed.wire2api(),
+        }
+    }
+}
+
+impl Wire2Api<AugmentSettings> for *mut wire_AugmentSettings {
+    fn wire2api(self) -> AugmentSettings {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<AugmentSettings>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ColorTarget> for *mut wire_ColorTarget {
+    fn wire2api(self) -> ColorTarget {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ColorTarget>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<DartImage> for *mut wire_DartImage {
     fn wire2api(self) -> DartImage {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<DartImage>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<FrameTransform> for *mut wire_FrameTransform {
+    fn wire2api(self) -> FrameTransform {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<FrameTransform>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<ImageData> for *mut wire_ImageData {
     fn wire2api(self) -> ImageData {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<ImageData>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<RobotCommand> for *mut wire_RobotCommand {
+    fn wire2api(self) -> RobotCommand {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<RobotCommand>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<SensorData> for *mut wire_SensorData {
+    fn wire2api(self) -> SensorData {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<SensorData>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<SonarSettings> for *mut wire_SonarSettings {
+    fn wire2api(self) -> SonarSettings {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<SonarSettings>::wire2api(*wrap).into()
+    }
+}
+
+impl Wire2Api<ColorTarget> for wire_ColorTarget {
+    fn wire2api(self) -> ColorTarget {
+        ColorTarget {
+            red: self.red.wire2api(),
+            green: self.green.wire2api(),
+            blue: self.blue.wire2api(),
+            tolerance: self.tolerance.wire2api(),
+            space: self.space.wire2api(),
+            min_area: self.min_area.wire2api(),
+        }
+    }
+}
+
 impl Wire2Api<DartImage> for wire_DartImage {
     fn wire2api(self) -> DartImage {
         DartImage {
@@ -156,6 +545,19 @@ impl Wire2Api<DartImage> for wire_DartImage {
     }
 }
 
+impl Wire2Api<FrameTransform> for wire_FrameTransform {
+    fn wire2api(self) -> FrameTransform {
+        FrameTransform {
+            rotation: self.rotation.wire2api(),
+            mirror: self.mirror.wire2api(),
+            crop_x: self.crop_x.wire2api(),
+            crop_y: self.crop_y.wire2api(),
+            crop_width: self.crop_width.wire2api(),
+            crop_height: self.crop_height.wire2api(),
+        }
+    }
+}
+
 impl Wire2Api<ImageData> for wire_ImageData {
     fn wire2api(self) -> ImageData {
         ImageData {
@@ -164,11 +566,14 @@ impl Wire2Api<ImageData> for wire_ImageData {
             vs: self.vs.wire2api(),
             width: self.width.wire2api(),
             height: self.height.wire2api(),
+            y_row_stride: self.y_row_stride.wire2api(),
             uv_row_stride: self.uv_row_stride.wire2api(),
             uv_pixel_stride: self.uv_pixel_stride.wire2api(),
+            format: self.format.wire2api(),
         }
     }
 }
+
 impl Wire2Api<LabeledImage> for wire_LabeledImage {
     fn wire2api(self) -> LabeledImage {
         LabeledImage {
@@ -187,6 +592,63 @@ impl Wire2Api<Vec<LabeledImage>> for *mut wire_list_labeled_image {
     }
 }
 
+impl Wire2Api<RobotCommand> for wire_RobotCommand {
+    fn wire2api(self) -> RobotCommand {
+        match self.tag {
+            0 => RobotCommand::NoOp,
+            1 => RobotCommand::Start,
+            2 => RobotCommand::Stop,
+            3 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Drive);
+                RobotCommand::Drive {
+                    speed: ans.speed.wire2api(),
+                }
+            },
+            4 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Turn);
+                RobotCommand::Turn {
+                    speed: ans.speed.wire2api(),
+                }
+            },
+            5 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.SetSpeeds);
+                RobotCommand::SetSpeeds {
+                    left: ans.left.wire2api(),
+                    right: ans.right.wire2api(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Wire2Api<SensorData> for wire_SensorData {
+    fn wire2api(self) -> SensorData {
+        SensorData {
+            sonar_front: self.sonar_front.wire2api(),
+            sonar_left: self.sonar_left.wire2api(),
+            sonar_right: self.sonar_right.wire2api(),
+            left_count: self.left_count.wire2api(),
+            right_count: self.right_count.wire2api(),
+            left_speed: self.left_speed.wire2api(),
+            right_speed: self.right_speed.wire2api(),
+        }
+    }
+}
+impl Wire2Api<SonarSettings> for wire_SonarSettings {
+    fn wire2api(self) -> SonarSettings {
+        SonarSettings {
+            obstacle_distance: self.obstacle_distance.wire2api(),
+            wall_distance: self.wall_distance.wire2api(),
+            cruise_speed: self.cruise_speed.wire2api(),
+            turn_speed: self.turn_speed.wire2api(),
+        }
+    }
+}
+
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
     fn wire2api(self) -> Vec<u8> {
         unsafe {
@@ -198,6 +660,30 @@ impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
 
 // Section: wire structs
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_AugmentSettings {
+    copies: i64,
+    flip: bool,
+    max_rotation_degrees: f64,
+    max_shift: f64,
+    max_brightness: f64,
+    max_contrast: f64,
+    max_noise: f64,
+    seed: i64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ColorTarget {
+    red: i64,
+    green: i64,
+    blue: i64,
+    tolerance: i64,
+    space: i32,
+    min_area: i64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_DartImage {
@@ -206,6 +692,17 @@ pub struct wire_DartImage {
     height: i64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FrameTransform {
+    rotation: i32,
+    mirror: bool,
+    crop_x: i64,
+    crop_y: i64,
+    crop_width: i64,
+    crop_height: i64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ImageData {
@@ -214,8 +711,10 @@ pub struct wire_ImageData {
     vs: *mut wire_uint_8_list,
     width: i64,
     height: i64,
+    y_row_stride: i64,
     uv_row_stride: i64,
     uv_pixel_stride: i64,
+    format: i32,
 }
 
 #[repr(C)]
@@ -232,6 +731,27 @@ pub struct wire_list_labeled_image {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_SensorData {
+    sonar_front: i64,
+    sonar_left: i64,
+    sonar_right: i64,
+    left_count: i64,
+    right_count: i64,
+    left_speed: i64,
+    right_speed: i64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_SonarSettings {
+    obstacle_distance: i64,
+    wall_distance: i64,
+    cruise_speed: i64,
+    turn_speed: i64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_uint_8_list {
@@ -239,6 +759,54 @@ pub struct wire_uint_8_list {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand {
+    tag: i32,
+    kind: *mut RobotCommandKind,
+}
+
+#[repr(C)]
+pub union RobotCommandKind {
+    NoOp: *mut wire_RobotCommand_NoOp,
+    Start: *mut wire_RobotCommand_Start,
+    Stop: *mut wire_RobotCommand_Stop,
+    Drive: *mut wire_RobotCommand_Drive,
+    Turn: *mut wire_RobotCommand_Turn,
+    SetSpeeds: *mut wire_RobotCommand_SetSpeeds,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand_NoOp {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand_Start {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand_Stop {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand_Drive {
+    speed: i64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand_Turn {
+    speed: i64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RobotCommand_SetSpeeds {
+    left: i64,
+    right: i64,
+}
+
 // Section: impl NewWithNullPtr
 
 pub trait NewWithNullPtr {
@@ -251,6 +819,46 @@ impl<T> NewWithNullPtr for *mut T {
     }
 }
 
+impl NewWithNullPtr for wire_AugmentSettings {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            copies: Default::default(),
+            flip: Default::default(),
+            max_rotation_degrees: Default::default(),
+            max_shift: Default::default(),
+            max_brightness: Default::default(),
+            max_contrast: Default::default(),
+            max_noise: Default::default(),
+            seed: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_AugmentSettings {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ColorTarget {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            red: Default::default(),
+            green: Default::default(),
+            blue: Default::default(),
+            tolerance: Default::default(),
+            space: Default::default(),
+            min_area: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_ColorTarget {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_DartImage {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -267,6 +875,25 @@ impl Default for wire_DartImage {
     }
 }
 
+impl NewWithNullPtr for wire_FrameTransform {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            rotation: Default::default(),
+            mirror: Default::default(),
+            crop_x: Default::default(),
+            crop_y: Default::default(),
+            crop_width: Default::default(),
+            crop_height: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_FrameTransform {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_ImageData {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -275,8 +902,10 @@ impl NewWithNullPtr for wire_ImageData {
             vs: core::ptr::null_mut(),
             width: Default::default(),
             height: Default::default(),
+            y_row_stride: Default::default(),
             uv_row_stride: Default::default(),
             uv_pixel_stride: Default::default(),
+            format: Default::default(),
         }
     }
 }
@@ -302,6 +931,80 @@ impl Default for wire_LabeledImage {
     }
 }
 
+impl NewWithNullPtr for wire_RobotCommand {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_RobotCommand_Drive() -> *mut RobotCommandKind {
+    support::new_leak_box_ptr(RobotCommandKind {
+        Drive: support::new_leak_box_ptr(wire_RobotCommand_Drive {
+            speed: Default::default(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_RobotCommand_Turn() -> *mut RobotCommandKind {
+    support::new_leak_box_ptr(RobotCommandKind {
+        Turn: support::new_leak_box_ptr(wire_RobotCommand_Turn {
+            speed: Default::default(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_RobotCommand_SetSpeeds() -> *mut RobotCommandKind {
+    support::new_leak_box_ptr(RobotCommandKind {
+        SetSpeeds: support::new_leak_box_ptr(wire_RobotCommand_SetSpeeds {
+            left: Default::default(),
+            right: Default::default(),
+        }),
+    })
+}
+
+impl NewWithNullPtr for wire_SensorData {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            sonar_front: Default::default(),
+            sonar_left: Default::default(),
+            sonar_right: Default::default(),
+            left_count: Default::default(),
+            right_count: Default::default(),
+            left_speed: Default::default(),
+            right_speed: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_SensorData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_SonarSettings {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            obstacle_distance: Default::default(),
+            wall_distance: Default::default(),
+            cruise_speed: Default::default(),
+            turn_speed: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_SonarSettings {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
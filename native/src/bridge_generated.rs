@@ -88,159 +88,910 @@ fn wire_train_knn_akaze_features_impl(
         },
     )
 }
+fn wire_train_knn_hash_impl(
+    port_: MessagePort,
+    k: impl Wire2Api<usize> + UnwindSafe,
+    examples: impl Wire2Api<Vec<LabeledImage>> + UnwindSafe,
+    kind: impl Wire2Api<HashKind> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "train_knn_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_k = k.wire2api();
+            let api_examples = examples.wire2api();
+            let api_kind = kind.wire2api();
+            move |task_callback| Ok(train_knn_hash(api_k, api_examples, api_kind))
+        },
+    )
+}
+fn wire_classify_knn_hash_impl(port_: MessagePort, img: impl Wire2Api<DartImage> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "classify_knn_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(classify_knn_hash(api_img))
+        },
+    )
+}
+fn wire_classify_knn_hash_frame_impl(
+    port_: MessagePort,
+    img: impl Wire2Api<ImageData> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "classify_knn_hash_frame",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(classify_knn_hash_frame(api_img))
+        },
+    )
+}
+fn wire_perceptual_hash_impl(
+    port_: MessagePort,
+    img: impl Wire2Api<DartImage> + UnwindSafe,
+    kind: impl Wire2Api<HashKind> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "perceptual_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            let api_kind = kind.wire2api();
+            move |task_callback| Ok(perceptual_hash(api_img, api_kind))
+        },
+    )
+}
+fn wire_frame_perceptual_hash_impl(
+    port_: MessagePort,
+    img: impl Wire2Api<ImageData> + UnwindSafe,
+    kind: impl Wire2Api<HashKind> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "frame_perceptual_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            let api_kind = kind.wire2api();
+            move |task_callback| Ok(frame_perceptual_hash(api_img, api_kind))
+        },
+    )
+}
+fn wire_hash_distance_impl(
+    port_: MessagePort,
+    a: impl Wire2Api<u64> + UnwindSafe,
+    b: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "hash_distance",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_a = a.wire2api();
+            let api_b = b.wire2api();
+            move |task_callback| Ok(hash_distance(api_a, api_b))
+        },
+    )
+}
+fn wire_augment_examples_impl(
+    port_: MessagePort,
+    examples: impl Wire2Api<Vec<LabeledImage>> + UnwindSafe,
+    settings: impl Wire2Api<AugmentSettings> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "augment_examples",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_examples = examples.wire2api();
+            let api_settings = settings.wire2api();
+            move |task_callback| Ok(augment_examples(api_examples, api_settings))
+        },
+    )
+}
+fn wire_load_project_impl(
+    port_: MessagePort,
+    dir: impl Wire2Api<String> + UnwindSafe,
+    project: impl Wire2Api<String> + UnwindSafe,
+    k: impl Wire2Api<usize> + UnwindSafe,
+    model: impl Wire2Api<KnnModel> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "load_project",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_dir = dir.wire2api();
+            let api_project = project.wire2api();
+            let api_k = k.wire2api();
+            let api_model = model.wire2api();
+            move |task_callback| load_project(api_dir, api_project, api_k, api_model)
+        },
+    )
+}
+fn wire_export_project_impl(
+    port_: MessagePort,
+    dir: impl Wire2Api<String> + UnwindSafe,
+    project: impl Wire2Api<String> + UnwindSafe,
+    archive: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "export_project",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_dir = dir.wire2api();
+            let api_project = project.wire2api();
+            let api_archive = archive.wire2api();
+            move |task_callback| export_project(api_dir, api_project, api_archive)
+        },
+    )
+}
+fn wire_import_project_impl(
+    port_: MessagePort,
+    dir: impl Wire2Api<String> + UnwindSafe,
+    project: impl Wire2Api<String> + UnwindSafe,
+    archive: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "import_project",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_dir = dir.wire2api();
+            let api_project = project.wire2api();
+            let api_archive = archive.wire2api();
+            move |task_callback| import_project(api_dir, api_project, api_archive)
+        },
+    )
+}
+fn wire_find_duplicates_impl(
+    port_: MessagePort,
+    examples: impl Wire2Api<Vec<LabeledImage>> + UnwindSafe,
+    max_distance: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "find_duplicates",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_examples = examples.wire2api();
+            let api_max_distance = max_distance.wire2api();
+            move |task_callback| Ok(find_duplicates(api_examples, api_max_distance))
+        },
+    )
+}
+fn wire_find_hash_duplicates_impl(
+    port_: MessagePort,
+    examples: impl Wire2Api<Vec<LabeledImage>> + UnwindSafe,
+    kind: impl Wire2Api<HashKind> + UnwindSafe,
+    max_bits: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "find_hash_duplicates",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_examples = examples.wire2api();
+            let api_kind = kind.wire2api();
+            let api_max_bits = max_bits.wire2api();
+            move |task_callback| Ok(find_hash_duplicates(api_examples, api_kind, api_max_bits))
+        },
+    )
+}
+fn wire_project_duplicates_impl(
+    port_: MessagePort,
+    dir: impl Wire2Api<String> + UnwindSafe,
+    project: impl Wire2Api<String> + UnwindSafe,
+    max_distance: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "project_duplicates",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_dir = dir.wire2api();
+            let api_project = project.wire2api();
+            let api_max_distance = max_distance.wire2api();
+            move |task_callback| project_duplicates(api_dir, api_project, api_max_distance)
+        },
+    )
+}
+fn wire_project_hash_duplicates_impl(
+    port_: MessagePort,
+    dir: impl Wire2Api<String> + UnwindSafe,
+    project: impl Wire2Api<String> + UnwindSafe,
+    kind: impl Wire2Api<HashKind> + UnwindSafe,
+    max_bits: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "project_hash_duplicates",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_dir = dir.wire2api();
+            let api_project = project.wire2api();
+            let api_kind = kind.wire2api();
+            let api_max_bits = max_bits.wire2api();
+            move |task_callback| {
+                project_hash_duplicates(api_dir, api_project, api_kind, api_max_bits)
+            }
+        },
+    )
+}
 fn wire_classify_knn_akaze_pos_impl(
     port_: MessagePort,
-    img: impl Wire2Api<DartImage> + UnwindSafe,
+    img: impl Wire2Api<DartImage> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "classify_knn_akaze_pos",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(classify_knn_akaze_pos(api_img))
+        },
+    )
+}
+fn wire_classify_knn_akaze_feature_impl(
+    port_: MessagePort,
+    img: impl Wire2Api<DartImage> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "classify_knn_akaze_feature",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(classify_knn_akaze_feature(api_img))
+        },
+    )
+}
+fn wire_set_pyramid_level_impl(port_: MessagePort, level: impl Wire2Api<i64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_pyramid_level",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_level = level.wire2api();
+            move |task_callback| Ok(set_pyramid_level(api_level))
+        },
+    )
+}
+fn wire_kmeans_ready_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "kmeans_ready",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(kmeans_ready()),
+    )
+}
+fn wire_training_time_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "training_time",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(training_time()),
+    )
+}
+fn wire_intensity_rgba_impl(
+    port_: MessagePort,
+    intensities: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    width: impl Wire2Api<i64> + UnwindSafe,
+    height: impl Wire2Api<i64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "intensity_rgba",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_intensities = intensities.wire2api();
+            let api_width = width.wire2api();
+            let api_height = height.wire2api();
+            move |task_callback| Ok(intensity_rgba(api_intensities, api_width, api_height))
+        },
+    )
+}
+fn wire_set_color_conversion_impl(
+    port_: MessagePort,
+    standard: impl Wire2Api<ColorStandard> + UnwindSafe,
+    range: impl Wire2Api<ColorRange> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_color_conversion",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_standard = standard.wire2api();
+            let api_range = range.wire2api();
+            move |task_callback| Ok(set_color_conversion(api_standard, api_range))
+        },
+    )
+}
+fn wire_set_frame_transform_impl(
+    port_: MessagePort,
+    transform: impl Wire2Api<FrameTransform> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_frame_transform",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_transform = transform.wire2api();
+            move |task_callback| Ok(set_frame_transform(api_transform))
+        },
+    )
+}
+fn wire_yuv_rgba_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "yuv_rgba",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(yuv_rgba(api_img))
+        },
+    )
+}
+fn wire_color_count_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "color_count",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(color_count(api_img))
+        },
+    )
+}
+fn wire_color_clusterer_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "color_clusterer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(color_clusterer(api_img))
+        },
+    )
+}
+fn wire_floor_free_space_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "floor_free_space",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(floor_free_space(api_img))
+        },
+    )
+}
+fn wire_floor_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "floor_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(floor_view(api_img))
+        },
+    )
+}
+fn wire_set_min_blob_area_impl(port_: MessagePort, min_area: impl Wire2Api<i64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_min_blob_area",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_min_area = min_area.wire2api();
+            move |task_callback| Ok(set_min_blob_area(api_min_area))
+        },
+    )
+}
+fn wire_track_blob_color_impl(
+    port_: MessagePort,
+    red: impl Wire2Api<i64> + UnwindSafe,
+    green: impl Wire2Api<i64> + UnwindSafe,
+    blue: impl Wire2Api<i64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "track_blob_color",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_red = red.wire2api();
+            let api_green = green.wire2api();
+            let api_blue = blue.wire2api();
+            move |task_callback| Ok(track_blob_color(api_red, api_green, api_blue))
+        },
+    )
+}
+fn wire_stop_blob_tracking_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "stop_blob_tracking",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(stop_blob_tracking()),
+    )
+}
+fn wire_detect_blobs_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "detect_blobs",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(detect_blobs(api_img))
+        },
+    )
+}
+fn wire_blob_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "blob_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(blob_view(api_img))
+        },
+    )
+}
+fn wire_set_color_target_impl(port_: MessagePort, target: impl Wire2Api<ColorTarget> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_color_target",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_target = target.wire2api();
+            move |task_callback| Ok(set_color_target(api_target))
+        },
+    )
+}
+fn wire_set_tracking_speeds_impl(
+    port_: MessagePort,
+    cruise: impl Wire2Api<i64> + UnwindSafe,
+    turn: impl Wire2Api<i64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_tracking_speeds",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_cruise = cruise.wire2api();
+            let api_turn = turn.wire2api();
+            move |task_callback| Ok(set_tracking_speeds(api_cruise, api_turn))
+        },
+    )
+}
+fn wire_track_color_target_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "track_color_target",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(track_color_target(api_img))
+        },
+    )
+}
+fn wire_color_target_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "color_target_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(color_target_view(api_img))
+        },
+    )
+}
+fn wire_sobel_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sobel_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(sobel_view(api_img))
+        },
+    )
+}
+fn wire_set_canny_thresholds_impl(
+    port_: MessagePort,
+    low: impl Wire2Api<f64> + UnwindSafe,
+    high: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_canny_thresholds",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_low = low.wire2api();
+            let api_high = high.wire2api();
+            move |task_callback| Ok(set_canny_thresholds(api_low, api_high))
+        },
+    )
+}
+fn wire_canny_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "canny_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(canny_view(api_img))
+        },
+    )
+}
+fn wire_set_line_detection_impl(
+    port_: MessagePort,
+    min_votes: impl Wire2Api<i64> + UnwindSafe,
+    max_lines: impl Wire2Api<i64> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "classify_knn_akaze_pos",
+            debug_name: "set_line_detection",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_min_votes = min_votes.wire2api();
+            let api_max_lines = max_lines.wire2api();
+            move |task_callback| Ok(set_line_detection(api_min_votes, api_max_lines))
+        },
+    )
+}
+fn wire_detect_lines_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "detect_lines",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_img = img.wire2api();
-            move |task_callback| Ok(classify_knn_akaze_pos(api_img))
+            move |task_callback| Ok(detect_lines(api_img))
         },
     )
 }
-fn wire_classify_knn_akaze_feature_impl(
+fn wire_lines_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "lines_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(lines_view(api_img))
+        },
+    )
+}
+fn wire_akaze_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "akaze_view",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(akaze_view(api_img))
+        },
+    )
+}
+fn wire_akaze_flow_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "akaze_flow",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_img = img.wire2api();
+            move |task_callback| Ok(akaze_flow(api_img))
+        },
+    )
+}
+fn wire_reset_akaze_flow_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "reset_akaze_flow",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(reset_akaze_flow()),
+    )
+}
+fn wire_reset_position_estimate_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "reset_position_estimate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(reset_position_estimate()),
+    )
+}
+fn wire_process_sensor_data_impl(
     port_: MessagePort,
-    img: impl Wire2Api<DartImage> + UnwindSafe,
+    incoming_data: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "classify_knn_akaze_feature",
+            debug_name: "process_sensor_data",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_img = img.wire2api();
-            move |task_callback| Ok(classify_knn_akaze_feature(api_img))
+            let api_incoming_data = incoming_data.wire2api();
+            move |task_callback| Ok(process_sensor_data(api_incoming_data))
         },
     )
 }
-fn wire_kmeans_ready_impl(port_: MessagePort) {
+fn wire_start_recording_impl(port_: MessagePort, path: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "kmeans_ready",
+            debug_name: "start_recording",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(kmeans_ready()),
+        move || {
+            let api_path = path.wire2api();
+            move |task_callback| start_recording(api_path)
+        },
     )
 }
-fn wire_training_time_impl(port_: MessagePort) {
+fn wire_stop_recording_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "training_time",
+            debug_name: "stop_recording",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(training_time()),
+        move || move |task_callback| stop_recording(),
     )
 }
-fn wire_intensity_rgba_impl(port_: MessagePort, intensities: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+fn wire_is_recording_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "intensity_rgba",
+            debug_name: "is_recording",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(is_recording()),
+    )
+}
+fn wire_replay_recording_impl(port_: MessagePort, path: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "replay_recording",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_intensities = intensities.wire2api();
-            move |task_callback| Ok(intensity_rgba(api_intensities))
+            let api_path = path.wire2api();
+            move |task_callback| replay_recording(api_path)
         },
     )
 }
-fn wire_yuv_rgba_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+fn wire_set_sonar_settings_impl(
+    port_: MessagePort,
+    settings: impl Wire2Api<SonarSettings> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "yuv_rgba",
+            debug_name: "set_sonar_settings",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_img = img.wire2api();
-            move |task_callback| Ok(yuv_rgba(api_img))
+            let api_settings = settings.wire2api();
+            move |task_callback| Ok(set_sonar_settings(api_settings))
         },
     )
 }
-fn wire_color_count_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+fn wire_set_controller_impl(port_: MessagePort, kind: impl Wire2Api<ControllerKind> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "color_count",
+            debug_name: "set_controller",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_img = img.wire2api();
-            move |task_callback| Ok(color_count(api_img))
+            let api_kind = kind.wire2api();
+            move |task_callback| Ok(set_controller(api_kind))
         },
     )
 }
-fn wire_color_clusterer_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+fn wire_queue_robot_command_impl(
+    port_: MessagePort,
+    command: impl Wire2Api<RobotCommand> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "color_clusterer",
+            debug_name: "queue_robot_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_img = img.wire2api();
-            move |task_callback| Ok(color_clusterer(api_img))
+            let api_command = command.wire2api();
+            move |task_callback| Ok(queue_robot_command(api_command))
         },
     )
 }
-fn wire_akaze_view_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+fn wire_next_robot_command_impl(
+    port_: MessagePort,
+    sensors: impl Wire2Api<SensorData> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "akaze_view",
+            debug_name: "next_robot_command",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_img = img.wire2api();
-            move |task_callback| Ok(akaze_view(api_img))
+            let api_sensors = sensors.wire2api();
+            move |task_callback| Ok(next_robot_command(api_sensors))
         },
     )
 }
-fn wire_akaze_flow_impl(port_: MessagePort, img: impl Wire2Api<ImageData> + UnwindSafe) {
+fn wire_set_label_action_impl(
+    port_: MessagePort,
+    label: impl Wire2Api<String> + UnwindSafe,
+    command: impl Wire2Api<RobotCommand> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "akaze_flow",
+            debug_name: "set_label_action",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_img = img.wire2api();
-            move |task_callback| Ok(akaze_flow(api_img))
+            let api_label = label.wire2api();
+            let api_command = command.wire2api();
+            move |task_callback| Ok(set_label_action(api_label, api_command))
         },
     )
 }
-fn wire_reset_position_estimate_impl(port_: MessagePort) {
+fn wire_clear_label_actions_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "reset_position_estimate",
+            debug_name: "clear_label_actions",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(reset_position_estimate()),
+        move || move |task_callback| Ok(clear_label_actions()),
     )
 }
-fn wire_process_sensor_data_impl(
+fn wire_set_label_voting_impl(
     port_: MessagePort,
-    incoming_data: impl Wire2Api<String> + UnwindSafe,
+    window: impl Wire2Api<i64> + UnwindSafe,
+    hold: impl Wire2Api<i64> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "process_sensor_data",
+            debug_name: "set_label_voting",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_incoming_data = incoming_data.wire2api();
-            move |task_callback| Ok(process_sensor_data(api_incoming_data))
+            let api_window = window.wire2api();
+            let api_hold = hold.wire2api();
+            move |task_callback| Ok(set_label_voting(api_window, api_hold))
+        },
+    )
+}
+fn wire_observe_label_impl(port_: MessagePort, label: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "observe_label",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_label = label.wire2api();
+            move |task_callback| Ok(observe_label(api_label))
+        },
+    )
+}
+fn wire_robot_command_wire_impl(
+    port_: MessagePort,
+    command: impl Wire2Api<RobotCommand> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "robot_command_wire",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_command = command.wire2api();
+            move |task_callback| Ok(robot_command_wire(api_command))
         },
     )
 }
@@ -283,12 +1034,129 @@ where
     }
 }
 
+impl Wire2Api<bool> for bool {
+    fn wire2api(self) -> bool {
+        self
+    }
+}
+
+impl Wire2Api<ColorRange> for i32 {
+    fn wire2api(self) -> ColorRange {
+        match self {
+            0 => ColorRange::Limited,
+            1 => ColorRange::Full,
+            _ => unreachable!("Invalid variant for ColorRange: {}", self),
+        }
+    }
+}
+impl Wire2Api<ColorSpace> for i32 {
+    fn wire2api(self) -> ColorSpace {
+        match self {
+            0 => ColorSpace::Rgb,
+            1 => ColorSpace::Hsv,
+            _ => unreachable!("Invalid variant for ColorSpace: {}", self),
+        }
+    }
+}
+impl Wire2Api<ColorStandard> for i32 {
+    fn wire2api(self) -> ColorStandard {
+        match self {
+            0 => ColorStandard::Bt601,
+            1 => ColorStandard::Bt709,
+            _ => unreachable!("Invalid variant for ColorStandard: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<ControllerKind> for i32 {
+    fn wire2api(self) -> ControllerKind {
+        match self {
+            0 => ControllerKind::Manual,
+            1 => ControllerKind::AvoidObstacles,
+            2 => ControllerKind::FollowLeftWall,
+            3 => ControllerKind::FollowRightWall,
+            4 => ControllerKind::Vision,
+            _ => unreachable!("Invalid variant for ControllerKind: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<f64> for f64 {
+    fn wire2api(self) -> f64 {
+        self
+    }
+}
+
+impl Wire2Api<HashKind> for i32 {
+    fn wire2api(self) -> HashKind {
+        match self {
+            0 => HashKind::Average,
+            1 => HashKind::Difference,
+            2 => HashKind::Dct,
+            _ => unreachable!("Invalid variant for HashKind: {}", self),
+        }
+    }
+}
+impl Wire2Api<i32> for i32 {
+    fn wire2api(self) -> i32 {
+        self
+    }
+}
 impl Wire2Api<i64> for i64 {
     fn wire2api(self) -> i64 {
         self
     }
 }
 
+impl Wire2Api<KnnModel> for i32 {
+    fn wire2api(self) -> KnnModel {
+        match self {
+            0 => KnnModel::Pixels,
+            1 => KnnModel::AkazePositions,
+            2 => KnnModel::AkazeFeatures,
+            3 => KnnModel::AverageHash,
+            4 => KnnModel::DifferenceHash,
+            5 => KnnModel::DctHash,
+            _ => unreachable!("Invalid variant for KnnModel: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<PixelFormat> for i32 {
+    fn wire2api(self) -> PixelFormat {
+        match self {
+            0 => PixelFormat::Yuv420,
+            1 => PixelFormat::Nv21,
+            2 => PixelFormat::Nv12,
+            3 => PixelFormat::I420,
+            4 => PixelFormat::Bgra8888,
+            _ => unreachable!("Invalid variant for PixelFormat: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<Rotation> for i32 {
+    fn wire2api(self) -> Rotation {
+        match self {
+            0 => Rotation::Rotate0,
+            1 => Rotation::Rotate90,
+            2 => Rotation::Rotate180,
+            3 => Rotation::Rotate270,
+            _ => unreachable!("Invalid variant for Rotation: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<u32> for u32 {
+    fn wire2api(self) -> u32 {
+        self
+    }
+}
+impl Wire2Api<u64> for u64 {
+    fn wire2api(self) -> u64 {
+        self
+    }
+}
 impl Wire2Api<u8> for u8 {
     fn wire2api(self) -> u8 {
         self
@@ -302,13 +1170,133 @@ impl Wire2Api<usize> for usize {
 }
 // Section: impl IntoDart
 
+impl support::IntoDart for ColorBlob {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.red.into_dart(),
+            self.green.into_dart(),
+            self.blue.into_dart(),
+            self.area.into_dart(),
+            self.centroid_x.into_dart(),
+            self.centroid_y.into_dart(),
+            self.left.into_dart(),
+            self.top.into_dart(),
+            self.right.into_dart(),
+            self.bottom.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ColorBlob {}
+
+impl support::IntoDart for DartImage {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.bytes.into_dart(),
+            self.width.into_dart(),
+            self.height.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DartImage {}
+
+impl support::IntoDart for DuplicateGroup {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.label.into_dart(), self.members.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DuplicateGroup {}
+
+impl support::IntoDart for DuplicateReport {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.names.into_dart(),
+            self.groups.into_dart(),
+            self.conflicts.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DuplicateReport {}
+
+impl support::IntoDart for FreeSpace {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.mask.into_dart(),
+            self.width.into_dart(),
+            self.height.into_dart(),
+            self.column_heights.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FreeSpace {}
+
 impl support::IntoDart for ImageResponse {
     fn into_dart(self) -> support::DartAbi {
-        vec![self.img.into_dart(), self.msg.into_dart()].into_dart()
+        vec![
+            self.img.into_dart(),
+            self.msg.into_dart(),
+            self.width.into_dart(),
+            self.height.into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for ImageResponse {}
 
+impl support::IntoDart for LabelConflict {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.first.into_dart(),
+            self.second.into_dart(),
+            self.distance.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LabelConflict {}
+
+impl support::IntoDart for LabeledImage {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.label.into_dart(), self.image.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LabeledImage {}
+
+impl support::IntoDart for LineSegment {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.rho.into_dart(),
+            self.theta.into_dart(),
+            self.x1.into_dart(),
+            self.y1.into_dart(),
+            self.x2.into_dart(),
+            self.y2.into_dart(),
+            self.votes.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LineSegment {}
+
+impl support::IntoDart for RobotCommand {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::NoOp => vec![0.into_dart()],
+            Self::Start => vec![1.into_dart()],
+            Self::Stop => vec![2.into_dart()],
+            Self::Drive { speed } => vec![3.into_dart(), speed.into_dart()],
+            Self::Turn { speed } => vec![4.into_dart(), speed.into_dart()],
+            Self::SetSpeeds { left, right } => {
+                vec![5.into_dart(), left.into_dart(), right.into_dart()]
+            }
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RobotCommand {}
 impl support::IntoDart for SensorData {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -325,6 +1313,21 @@ impl support::IntoDart for SensorData {
 }
 impl support::IntoDartExceptPrimitive for SensorData {}
 
+impl support::IntoDart for TrackingCommand {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.found.into_dart(),
+            self.left_speed.into_dart(),
+            self.right_speed.into_dart(),
+            self.centroid_x.into_dart(),
+            self.centroid_y.into_dart(),
+            self.area.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TrackingCommand {}
+
 // Section: executor
 
 support::lazy_static! {
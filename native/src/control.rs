@@ -0,0 +1,192 @@
+use crate::api::{RobotCommand, SensorData, SonarSettings};
+use std::collections::VecDeque;
+
+/// Chooses the robot's next command from its latest sensor readings.
+pub trait Controller: Send {
+    fn next_command(&mut self, sensors: &SensorData) -> RobotCommand;
+
+    /// New thresholds from `set_sonar_settings`; controllers without sonar
+    /// behavior ignore them.
+    fn set_sonar_settings(&mut self, _settings: SonarSettings) {}
+}
+
+/// Leaves the robot alone; only queued commands reach it.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Manual;
+
+impl Controller for Manual {
+    fn next_command(&mut self, _sensors: &SensorData) -> RobotCommand {
+        RobotCommand::NoOp
+    }
+}
+
+/// Commands issued in the order they were queued, ahead of whatever the
+/// active controller would choose.
+#[derive(Clone, Debug, Default)]
+pub struct CommandQueue {
+    pending: VecDeque<RobotCommand>,
+}
+
+impl CommandQueue {
+    pub fn push(&mut self, command: RobotCommand) {
+        self.pending.push_back(command);
+    }
+
+    /// Queues new wheel speeds, replacing speeds still waiting at the back of
+    /// the queue so a fast producer never builds a backlog of stale ones.
+    pub fn push_speeds(&mut self, left: i64, right: i64) {
+        if let Some(RobotCommand::SetSpeeds { .. }) = self.pending.back() {
+            self.pending.pop_back();
+        }
+        self.pending.push_back(RobotCommand::SetSpeeds { left, right });
+    }
+
+    pub fn pop(&mut self) -> Option<RobotCommand> {
+        self.pending.pop_front()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl RobotCommand {
+    /// The message the robot expects over its socket, one per `cmd` request:
+    ///
+    /// | command                     | message                |
+    /// |-----------------------------|------------------------|
+    /// | `NoOp`                      | `None`                 |
+    /// | `Start`                     | `Start`                |
+    /// | `Stop`                      | `Stop`                 |
+    /// | `Drive { speed }`           | `Drive:{speed}`        |
+    /// | `Turn { speed }`            | `Turn:{speed}`         |
+    /// | `SetSpeeds { left, right }` | `LS:{left};RS:{right}` |
+    ///
+    /// Numbers are signed decimal integers in the units of
+    /// `SensorData::left_speed` and `right_speed`. Wheel speeds use the `LS`/`RS`
+    /// keys the robot reports them with. A robot that sees `Drive` or `Turn`
+    /// sets both wheels, the right one negated for `Turn`; firmware that only
+    /// handles `None`, `Start` and `Stop` must be updated to parse the rest.
+    pub fn to_wire(&self) -> String {
+        match self {
+            RobotCommand::NoOp => "None".to_owned(),
+            RobotCommand::Start => "Start".to_owned(),
+            RobotCommand::Stop => "Stop".to_owned(),
+            RobotCommand::Drive { speed } => format!("Drive:{speed}"),
+            RobotCommand::Turn { speed } => format!("Turn:{speed}"),
+            RobotCommand::SetSpeeds { left, right } => format!("LS:{left};RS:{right}"),
+        }
+    }
+
+    pub fn from_wire(msg: &str) -> Option<Self> {
+        match msg {
+            "None" => return Some(RobotCommand::NoOp),
+            "Start" => return Some(RobotCommand::Start),
+            "Stop" => return Some(RobotCommand::Stop),
+            _ => {}
+        }
+        if let Some(speed) = msg.strip_prefix("Drive:") {
+            return speed.parse().ok().map(|speed| RobotCommand::Drive { speed });
+        }
+        if let Some(speed) = msg.strip_prefix("Turn:") {
+            return speed.parse().ok().map(|speed| RobotCommand::Turn { speed });
+        }
+        let (left, right) = msg.split_once(';')?;
+        Some(RobotCommand::SetSpeeds {
+            left: left.strip_prefix("LS:")?.parse().ok()?,
+            right: right.strip_prefix("RS:")?.parse().ok()?,
+        })
+    }
+}
+
+/// Which side of the robot a wall follower keeps its wall on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WallSide {
+    Left,
+    Right,
+}
+
+/// Steering correction is damped by this many packets' worth of change in the
+/// wall distance, so the follower settles instead of weaving.
+const WALL_DAMPING: i64 = 4;
+
+/// Reactive sonar behavior. With no wall to follow, drives straight, veering
+/// away from any side closer than `wall_distance`, and turns toward the more open
+/// side whenever something is closer than `obstacle_distance` ahead. A wall
+/// follower holds `wall_distance` on its side and always turns away from its
+/// wall at obstacles.
+#[derive(Copy, Clone, Debug)]
+pub struct SonarController {
+    settings: SonarSettings,
+    wall: Option<WallSide>,
+    last_error: Option<i64>,
+    /// Speed of the turn in progress, kept until the way ahead is clear so the
+    /// robot does not dither between directions.
+    turning: Option<i64>,
+}
+
+impl SonarController {
+    pub fn avoiding(settings: SonarSettings) -> Self {
+        Self { settings, wall: None, last_error: None, turning: None }
+    }
+
+    pub fn following(settings: SonarSettings, wall: WallSide) -> Self {
+        Self { settings, wall: Some(wall), last_error: None, turning: None }
+    }
+}
+
+impl Controller for SonarController {
+    fn next_command(&mut self, sensors: &SensorData) -> RobotCommand {
+        let SonarSettings { obstacle_distance, wall_distance, cruise_speed, turn_speed } = self.settings;
+        if sensors.sonar_front < obstacle_distance {
+            self.last_error = None;
+            let wall = self.wall;
+            let speed = *self.turning.get_or_insert_with(|| {
+                let turn_right = match wall {
+                    Some(WallSide::Left) => true,
+                    Some(WallSide::Right) => false,
+                    None => sensors.sonar_left <= sensors.sonar_right,
+                };
+                if turn_right { turn_speed } else { -turn_speed }
+            });
+            return RobotCommand::Turn { speed };
+        }
+        self.turning = None;
+        match self.wall {
+            None => {
+                let correction = if sensors.sonar_left.min(sensors.sonar_right) >= wall_distance {
+                    0
+                } else if sensors.sonar_left < sensors.sonar_right {
+                    turn_speed
+                } else {
+                    -turn_speed
+                };
+                if correction == 0 {
+                    RobotCommand::Drive { speed: cruise_speed }
+                } else {
+                    RobotCommand::SetSpeeds { left: cruise_speed + correction, right: cruise_speed - correction }
+                }
+            }
+            Some(wall) => {
+                let distance = match wall {
+                    WallSide::Left => sensors.sonar_left,
+                    WallSide::Right => sensors.sonar_right,
+                };
+                let error = distance - wall_distance;
+                let change = self.last_error.map_or(0, |last| error - last);
+                self.last_error = Some(error);
+                let correction = ((error + WALL_DAMPING * change) * turn_speed / wall_distance.max(1))
+                    .clamp(-turn_speed, turn_speed);
+                let (left, right) = match wall {
+                    WallSide::Left => (cruise_speed - correction, cruise_speed + correction),
+                    WallSide::Right => (cruise_speed + correction, cruise_speed - correction),
+                };
+                RobotCommand::SetSpeeds { left, right }
+            }
+        }
+    }
+
+    fn set_sonar_settings(&mut self, settings: SonarSettings) {
+        self.settings = settings;
+    }
+}
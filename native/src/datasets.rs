@@ -0,0 +1,190 @@
+use crate::api::LabeledImage;
+use crate::projects::{decode_image, project_dir, project_photos};
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Name of the manifest inside a dataset archive.
+pub const MANIFEST: &str = "manifest.csv";
+const MANIFEST_HEADER: &str = "label,filename,width,height,modified";
+
+/// One photo of a dataset archive, as listed in its manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub label: String,
+    pub filename: String,
+    pub width: u32,
+    pub height: u32,
+    /// When the photo was last modified on the device that exported it, in
+    /// seconds since the Unix epoch; 0 if unknown.
+    pub modified: u64,
+}
+
+impl ManifestEntry {
+    fn archive_path(&self) -> String {
+        format!("{}/{}", self.label, self.filename)
+    }
+}
+
+/// Packs a project into a zip archive: each photo, unchanged, at
+/// `<label>/<filename>`, plus a `manifest.csv` with one row per photo giving
+/// its label, file name, size and modification time. PNGs are already
+/// compressed, so they are stored as they are. Returns the manifest.
+pub fn export_project<P: AsRef<Path>, A: AsRef<Path>>(
+    dir: P,
+    project: &str,
+    archive: A,
+) -> Result<Vec<ManifestEntry>> {
+    let project_dir = project_dir(dir, project);
+    let mut zip = ZipWriter::new(File::create(archive.as_ref())?);
+    let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+    let mut manifest = vec![];
+    for (label, file) in project_photos(project_dir)? {
+        let (width, height) = image::image_dimensions(&file).with_context(|| file.display().to_string())?;
+        let modified = fs::metadata(&file)?
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        let filename = file.file_name().unwrap().to_string_lossy().into_owned();
+        let entry = ManifestEntry { label, filename, width, height, modified };
+        zip.start_file(entry.archive_path(), stored)?;
+        zip.write_all(&fs::read(&file)?)?;
+        manifest.push(entry);
+    }
+    zip.start_file(MANIFEST, FileOptions::default())?;
+    zip.write_all(write_manifest(&manifest).as_bytes())?;
+    zip.finish()?;
+    Ok(manifest)
+}
+
+/// Unpacks an archive from `export_project` into `project`, creating its label
+/// directories as needed. Photos already in the project are kept; one with the
+/// same label and file name as an archived photo is replaced.
+pub fn import_project<P: AsRef<Path>, A: AsRef<Path>>(
+    dir: P,
+    project: &str,
+    archive: A,
+) -> Result<Vec<ManifestEntry>> {
+    let project_dir = project_dir(dir, project);
+    let mut zip = open_archive(archive.as_ref())?;
+    let manifest = read_manifest_from(&mut zip)?;
+    for entry in manifest.iter() {
+        let label_dir = project_dir.join(&entry.label);
+        fs::create_dir_all(&label_dir)?;
+        fs::write(label_dir.join(&entry.filename), read_file(&mut zip, &entry.archive_path())?)?;
+    }
+    Ok(manifest)
+}
+
+/// Every photo of an archive from `export_project`, decoded for training, in
+/// manifest order.
+pub fn archive_images<A: AsRef<Path>>(archive: A) -> Result<Vec<LabeledImage>> {
+    let mut zip = open_archive(archive.as_ref())?;
+    let manifest = read_manifest_from(&mut zip)?;
+    manifest
+        .iter()
+        .map(|entry| {
+            let path = entry.archive_path();
+            let image = decode_image(&read_file(&mut zip, &path)?).with_context(|| path.clone())?;
+            Ok(LabeledImage { label: entry.label.clone(), image })
+        })
+        .collect()
+}
+
+pub fn write_manifest(entries: &[ManifestEntry]) -> String {
+    let mut csv = format!("{MANIFEST_HEADER}\n");
+    for e in entries.iter() {
+        let row = [
+            csv_field(&e.label),
+            csv_field(&e.filename),
+            e.width.to_string(),
+            e.height.to_string(),
+            e.modified.to_string(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Parses a manifest, rejecting labels and file names that would escape the
+/// project directory.
+pub fn read_manifest(csv: &str) -> Result<Vec<ManifestEntry>> {
+    let mut lines = csv.lines();
+    if lines.next().map(str::trim) != Some(MANIFEST_HEADER) {
+        bail!("manifest must start with {MANIFEST_HEADER}");
+    }
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields = csv_fields(line)?;
+            let [label, filename, width, height, modified]: [String; 5] =
+                fields.try_into().map_err(|_| anyhow!("manifest row needs 5 fields: {line}"))?;
+            for name in [&label, &filename] {
+                if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+                    bail!("bad name in manifest: {name:?}");
+                }
+            }
+            Ok(ManifestEntry {
+                label,
+                filename,
+                width: width.parse()?,
+                height: height.parse()?,
+                modified: modified.parse()?,
+            })
+        })
+        .collect()
+}
+
+fn open_archive(path: &Path) -> Result<ZipArchive<File>> {
+    let file = File::open(path).with_context(|| path.display().to_string())?;
+    ZipArchive::new(file).with_context(|| path.display().to_string())
+}
+
+fn read_manifest_from(zip: &mut ZipArchive<File>) -> Result<Vec<ManifestEntry>> {
+    read_manifest(&String::from_utf8(read_file(zip, MANIFEST)?)?)
+}
+
+fn read_file(zip: &mut ZipArchive<File>, name: &str) -> Result<Vec<u8>> {
+    let mut file = zip.by_name(name).with_context(|| format!("{name} missing from archive"))?;
+    let mut bytes = vec![];
+    file.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn csv_fields(line: &str) -> Result<Vec<String>> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if field.is_empty() => quoted = true,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        bail!("unterminated quote in manifest row: {line}");
+    }
+    fields.push(field);
+    Ok(fields)
+}
@@ -0,0 +1,74 @@
+use crate::api::{DartImage, LabeledImage};
+use crate::image_proc::distance_u8;
+
+/// Near-duplicates among a set of labeled photos, by index into the set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Duplicates {
+    /// Photos of one label that are all within the threshold of one another,
+    /// directly or through a chain of near-duplicates. Each group is in index
+    /// order and has at least two members.
+    pub groups: Vec<Vec<usize>>,
+    /// Pairs of photos within the threshold that carry different labels, with
+    /// their distance.
+    pub conflicts: Vec<(usize, usize, f64)>,
+}
+
+/// Root-mean-square difference per byte of two same-sized images, in 8-bit
+/// levels; `None` if their sizes differ.
+pub fn pixel_distance(a: &DartImage, b: &DartImage) -> Option<f64> {
+    if (a.width, a.height) != (b.width, b.height) || a.bytes.len() != b.bytes.len() || a.bytes.is_empty() {
+        return None;
+    }
+    Some((distance_u8(&a.bytes, &b.bytes) / a.bytes.len() as f64).sqrt())
+}
+
+/// Compares every pair of `examples` with `distance`, treating pairs no more
+/// than `max_distance` apart as near-duplicates.
+pub fn find_duplicates<D>(examples: &[LabeledImage], max_distance: f64, distance: D) -> Duplicates
+where
+    D: Fn(&DartImage, &DartImage) -> Option<f64>,
+{
+    let labels: Vec<&str> = examples.iter().map(|e| e.label.as_str()).collect();
+    let images: Vec<&DartImage> = examples.iter().map(|e| &e.image).collect();
+    find_duplicates_by(&labels, &images, max_distance, |a, b| distance(a, b))
+}
+
+/// `find_duplicates` over anything computed once per photo, such as a
+/// perceptual hash, with `labels[i]` the label of `items[i]`.
+pub fn find_duplicates_by<T, D>(labels: &[&str], items: &[T], max_distance: f64, distance: D) -> Duplicates
+where
+    D: Fn(&T, &T) -> Option<f64>,
+{
+    let mut roots: Vec<usize> = (0..items.len()).collect();
+    let mut conflicts = vec![];
+    for i in 0..items.len() {
+        for j in i + 1..items.len() {
+            let d = match distance(&items[i], &items[j]) {
+                Some(d) if d <= max_distance => d,
+                _ => continue,
+            };
+            if labels[i] == labels[j] {
+                let (ri, rj) = (root(&mut roots, i), root(&mut roots, j));
+                roots[ri.max(rj)] = ri.min(rj);
+            } else {
+                conflicts.push((i, j, d));
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = vec![vec![]; items.len()];
+    for i in 0..items.len() {
+        let r = root(&mut roots, i);
+        groups[r].push(i);
+    }
+    groups.retain(|group| group.len() > 1);
+    Duplicates { groups, conflicts }
+}
+
+fn root(roots: &mut [usize], mut i: usize) -> usize {
+    while roots[i] != i {
+        roots[i] = roots[roots[i]];
+        i = roots[i];
+    }
+    i
+}
@@ -0,0 +1,159 @@
+use image::{GrayImage, Luma};
+use std::collections::VecDeque;
+
+/// Horizontal and vertical Sobel responses of a grayscale image, row-major.
+/// Border pixels replicate their nearest neighbor.
+pub struct Gradients {
+    width: usize,
+    height: usize,
+    gx: Vec<i32>,
+    gy: Vec<i32>,
+}
+
+impl Gradients {
+    pub fn sobel(luma: &GrayImage) -> Self {
+        let (width, height) = (luma.width() as usize, luma.height() as usize);
+        if width == 0 || height == 0 {
+            return Self { width, height, gx: vec![], gy: vec![] };
+        }
+        let at = |x: isize, y: isize| {
+            let x = x.clamp(0, width as isize - 1) as u32;
+            let y = y.clamp(0, height as isize - 1) as u32;
+            luma.get_pixel(x, y)[0] as i32
+        };
+        let mut gx = Vec::with_capacity(width * height);
+        let mut gy = Vec::with_capacity(width * height);
+        for y in 0..height as isize {
+            for x in 0..width as isize {
+                gx.push(
+                    at(x + 1, y - 1) + 2 * at(x + 1, y) + at(x + 1, y + 1)
+                        - at(x - 1, y - 1) - 2 * at(x - 1, y) - at(x - 1, y + 1),
+                );
+                gy.push(
+                    at(x - 1, y + 1) + 2 * at(x, y + 1) + at(x + 1, y + 1)
+                        - at(x - 1, y - 1) - 2 * at(x, y - 1) - at(x + 1, y - 1),
+                );
+            }
+        }
+        Self { width, height, gx, gy }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn magnitude(&self, x: usize, y: usize) -> f32 {
+        let i = y * self.width + x;
+        ((self.gx[i] * self.gx[i] + self.gy[i] * self.gy[i]) as f32).sqrt()
+    }
+
+    /// Gradient direction in radians, in `-PI..=PI`.
+    pub fn direction(&self, x: usize, y: usize) -> f32 {
+        let i = y * self.width + x;
+        (self.gy[i] as f32).atan2(self.gx[i] as f32)
+    }
+}
+
+/// Sobel gradient magnitude, scaled so that the strongest possible edge is 255.
+pub fn sobel_magnitude(luma: &GrayImage) -> GrayImage {
+    let gradients = Gradients::sobel(luma);
+    let max_magnitude = 4.0 * 255.0 * 2.0_f32.sqrt();
+    GrayImage::from_fn(luma.width(), luma.height(), |x, y| {
+        let m = gradients.magnitude(x as usize, y as usize);
+        Luma([(m * 255.0 / max_magnitude).round() as u8])
+    })
+}
+
+/// Canny edge detection: Gaussian smoothing, Sobel gradients, non-maximum
+/// suppression, then hysteresis between `low` and `high` gradient magnitudes.
+/// Edge pixels are 255; all others are 0.
+pub fn canny(luma: &GrayImage, low: f32, high: f32) -> GrayImage {
+    let gradients = Gradients::sobel(&gaussian_blur_5(luma));
+    let (width, height) = (gradients.width(), gradients.height());
+    let thin = non_max_suppression(&gradients);
+
+    let mut edges = GrayImage::new(width as u32, height as u32);
+    let mut pending = VecDeque::new();
+    for y in 0..height {
+        for x in 0..width {
+            if thin[y * width + x] >= high {
+                edges.put_pixel(x as u32, y as u32, Luma([u8::MAX]));
+                pending.push_back((x, y));
+            }
+        }
+    }
+    while let Some((x, y)) = pending.pop_front() {
+        for (nx, ny) in neighbors(x, y, width, height) {
+            if edges.get_pixel(nx as u32, ny as u32)[0] == 0 && thin[ny * width + nx] >= low {
+                edges.put_pixel(nx as u32, ny as u32, Luma([u8::MAX]));
+                pending.push_back((nx, ny));
+            }
+        }
+    }
+    edges
+}
+
+/// Gradient magnitudes, zeroed wherever a pixel is not a local maximum along
+/// its gradient direction.
+fn non_max_suppression(gradients: &Gradients) -> Vec<f32> {
+    let (width, height) = (gradients.width(), gradients.height());
+    let mut result = vec![0.0; width * height];
+    for y in 1..height.saturating_sub(1) {
+        for x in 1..width.saturating_sub(1) {
+            let m = gradients.magnitude(x, y);
+            let angle = gradients.direction(x, y).to_degrees().rem_euclid(180.0);
+            let ((ax, ay), (bx, by)) = if !(22.5..157.5).contains(&angle) {
+                ((x - 1, y), (x + 1, y))
+            } else if angle < 67.5 {
+                ((x - 1, y - 1), (x + 1, y + 1))
+            } else if angle < 112.5 {
+                ((x, y - 1), (x, y + 1))
+            } else {
+                ((x + 1, y - 1), (x - 1, y + 1))
+            };
+            if m > gradients.magnitude(ax, ay) && m >= gradients.magnitude(bx, by) {
+                result[y * width + x] = m;
+            }
+        }
+    }
+    result
+}
+
+fn neighbors(x: usize, y: usize, width: usize, height: usize) -> impl Iterator<Item = (usize, usize)> {
+    (-1..=1_isize)
+        .flat_map(|dy| (-1..=1_isize).map(move |dx| (dx, dy)))
+        .filter(|d| *d != (0, 0))
+        .map(move |(dx, dy)| (x as isize + dx, y as isize + dy))
+        .filter(move |(nx, ny)| *nx >= 0 && *ny >= 0 && *nx < width as isize && *ny < height as isize)
+        .map(|(nx, ny)| (nx as usize, ny as usize))
+}
+
+/// Separable 5x5 Gaussian (binomial 1-4-6-4-1) blur with replicated borders.
+pub fn gaussian_blur_5(luma: &GrayImage) -> GrayImage {
+    const KERNEL: [u32; 5] = [1, 4, 6, 4, 1];
+    let (width, height) = luma.dimensions();
+    if width == 0 || height == 0 {
+        return luma.clone();
+    }
+    let clamp = |v: i64, limit: u32| v.clamp(0, limit as i64 - 1) as u32;
+    let horizontal = GrayImage::from_fn(width, height, |x, y| {
+        let sum: u32 = KERNEL
+            .iter()
+            .enumerate()
+            .map(|(k, w)| w * luma.get_pixel(clamp(x as i64 + k as i64 - 2, width), y)[0] as u32)
+            .sum();
+        Luma([((sum + 8) / 16) as u8])
+    });
+    GrayImage::from_fn(width, height, |x, y| {
+        let sum: u32 = KERNEL
+            .iter()
+            .enumerate()
+            .map(|(k, w)| w * horizontal.get_pixel(x, clamp(y as i64 + k as i64 - 2, height))[0] as u32)
+            .sum();
+        Luma([((sum + 8) / 16) as u8])
+    })
+}
@@ -1,65 +1,711 @@
-use crate::api::ImageData;
+use crate::api::{ColorRange, ColorStandard, FrameTransform, ImageData, PixelFormat, Rotation};
 use cv::{
     bitarray::BitArray, feature::akaze::KeyPoint,
     image::imageproc::drawing::BresenhamLinePixelIterMut,
 };
-use image::{ImageBuffer, Rgba, RgbaImage};
+use flutter_rust_bridge::support::lazy_static;
+use image::{GrayImage, ImageBuffer, Luma, Pixel, Rgba, RgbaImage};
 use ordered_float::OrderedFloat;
 use std::cmp::{max, min};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::Mutex;
 
 pub type U8ColorTriple = (u8, u8, u8);
 
-pub fn convert(img: &ImageData) -> RgbaImage {
-    let mut result = RgbaImage::new(img.width as u32, img.height as u32);
-    generic_yuv_rgba(&img, |x, y, (r, g, b)| {
-        result.put_pixel(x as u32, y as u32, Rgba([r, g, b, 100]));
-    });
-    result
+lazy_static! {
+    static ref YUV_CONVERSION: Mutex<YuvConversion> =
+        Mutex::new(YuvConversion::new(ColorStandard::Bt601, ColorRange::Full));
+    static ref FRAME_TRANSFORM: Mutex<FrameTransform> = Mutex::new(FrameTransform::default());
+}
+
+/// Selects the conversion used by every YUV frame conversion from now on.
+pub fn set_yuv_conversion(conversion: YuvConversion) {
+    *YUV_CONVERSION.lock().unwrap() = conversion;
+}
+
+pub fn yuv_conversion() -> YuvConversion {
+    *YUV_CONVERSION.lock().unwrap()
 }
 
-pub fn simple_yuv_rgb(img: &ImageData) -> Vec<U8ColorTriple> {
-    let mut result = vec![];
-    generic_yuv_rgba(img, |_, _, rgb| result.push(rgb));
+/// Selects the orientation and crop applied by every frame conversion from now on.
+pub fn set_frame_transform(transform: FrameTransform) {
+    *FRAME_TRANSFORM.lock().unwrap() = transform;
+}
+
+pub fn frame_transform() -> FrameTransform {
+    *FRAME_TRANSFORM.lock().unwrap()
+}
+
+/// Size of the converted frame for `img` under the current `FrameTransform`.
+pub fn frame_size(img: &ImageData) -> (u32, u32) {
+    let geometry = FrameGeometry::new(img, frame_transform());
+    (geometry.width as u32, geometry.height as u32)
+}
+
+/// Canonical RGBA form of a camera or training frame.
+///
+/// Alpha is always opaque (`u8::MAX`) whatever the source, so the pixels fed to
+/// feature detectors are exactly the ones displayed and saved for training.
+/// The luma plane is kept alongside for grayscale algorithms.
+pub struct RgbaFrame {
+    rgba: RgbaImage,
+    luma: GrayImage,
+}
+
+impl RgbaFrame {
+    pub fn from_image_data(img: &ImageData) -> Self {
+        let geometry = FrameGeometry::new(img, frame_transform());
+        Self {
+            rgba: geometry.rgba_image(img),
+            luma: geometry.luma_image(img),
+        }
+    }
+
+    /// Wraps `width * height` pixels of RGBA bytes, e.g. from a `DartImage`,
+    /// forcing alpha to opaque.
+    pub fn from_rgba_bytes(bytes: &[u8], width: u32, height: u32) -> Self {
+        let mut rgba = RgbaImage::new(width, height);
+        for (pixel, src) in rgba.pixels_mut().zip(bytes.chunks_exact(4)) {
+            *pixel = Rgba([src[0], src[1], src[2], u8::MAX]);
+        }
+        let luma = luma_from_rgba(&rgba);
+        Self { rgba, luma }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.rgba.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.rgba.height()
+    }
+
+    pub fn rgba(&self) -> &RgbaImage {
+        &self.rgba
+    }
+
+    pub fn rgba_mut(&mut self) -> &mut RgbaImage {
+        &mut self.rgba
+    }
+
+    pub fn luma(&self) -> &GrayImage {
+        &self.luma
+    }
+
+    pub fn rgb_pixels(&self) -> impl Iterator<Item = U8ColorTriple> + '_ {
+        self.rgba.pixels().map(|p| (p[0], p[1], p[2]))
+    }
+
+    pub fn into_rgba(self) -> RgbaImage {
+        self.rgba
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.rgba.into_raw()
+    }
+
+    pub fn into_parts(self) -> (RgbaImage, GrayImage) {
+        (self.rgba, self.luma)
+    }
+}
+
+/// Builds a `GrayImage` straight from the Y plane, without any color conversion.
+///
+/// Tightly packed planes are copied wholesale; padded rows are copied one at a
+/// time. `Bgra8888` frames have no Y plane, so luma is computed from the pixels.
+pub fn luma_image(img: &ImageData) -> GrayImage {
+    FrameGeometry::new(img, frame_transform()).luma_image(img)
+}
+
+fn luma_from_rgba(rgba: &RgbaImage) -> GrayImage {
+    let mut result = GrayImage::new(rgba.width(), rgba.height());
+    for (gray, p) in result.pixels_mut().zip(rgba.pixels()) {
+        *gray = Luma([rgb_luma(p[0], p[1], p[2])]);
+    }
     result
 }
 
+/// BT.601 luma in 8-bit fixed point.
+fn rgb_luma(r: u8, g: u8, b: u8) -> u8 {
+    ((77 * r as u32 + 150 * g as u32 + 29 * b as u32 + 128) >> 8) as u8
+}
+
+/// The RGBA half of `RgbaFrame::from_image_data`, for callers that never look
+/// at the luma plane.
+pub fn convert(img: &ImageData) -> RgbaImage {
+    FrameGeometry::new(img, frame_transform()).rgba_image(img)
+}
+
 /// Translated and adapted from: https://stackoverflow.com/a/57604820/906268
 pub fn inner_yuv_rgba(img: &ImageData) -> Vec<u8> {
-    let mut result = Vec::new();
-    generic_yuv_rgba(img, |_, _, (r, g, b)| {
-        result.push(r);
-        result.push(g);
-        result.push(b);
-        result.push(u8::MAX);
-    });
+    let geometry = FrameGeometry::new(img, frame_transform());
+    let mut result = vec![0; geometry.width * geometry.height * 4];
+    geometry.rgba_into(img, &mut result);
     result
 }
 
+/// Fills `out` (exactly `4 * width * height` bytes of the transformed frame,
+/// see `frame_size`) with the RGBA conversion of `img`.
+pub fn yuv_rgba_into(img: &ImageData, out: &mut [u8]) {
+    FrameGeometry::new(img, frame_transform()).rgba_into(img, out);
+}
+
+/// Calls `add` with the coordinates and color of every pixel of the transformed frame.
 pub fn generic_yuv_rgba<F: FnMut(i64, i64, (u8, u8, u8))>(img: &ImageData, mut add: F) {
-    for y in 0..img.height {
-        for x in 0..img.width {
-            let uv_index = (img.uv_pixel_stride * (x / 2) + img.uv_row_stride * (y / 2)) as usize;
-            let index = (y * img.width + x) as usize;
-            let rgb = yuv2rgb(
-                img.ys[index] as i64,
-                img.us[uv_index] as i64,
-                img.vs[uv_index] as i64,
-            );
-            add(x, y, rgb);
+    let geometry = FrameGeometry::new(img, frame_transform());
+    let planes = FramePlanes::new(img);
+    for y in 0..geometry.height {
+        for x in 0..geometry.width {
+            let (sx, sy) = geometry.source(x, y);
+            add(x as i64, y as i64, planes.rgb(sx, sy));
         }
     }
 }
 
-fn yuv2rgb(yp: i64, up: i64, vp: i64) -> (u8, u8, u8) {
-    (
-        clamp_u8(yp + vp * 1436 / 1024 - 179),
-        clamp_u8(yp - up * 46549 / 131072 + 44 - vp * 93604 / 131072 + 91),
-        clamp_u8(yp + up * 1814 / 1024 - 227),
-    )
+/// Output size of a frame under a `FrameTransform`, and the inverse mapping
+/// from output pixels back to sensor pixels.
+///
+/// The sensor frame is rotated clockwise, then mirrored left-to-right, then
+/// cropped; the crop rectangle is given in rotated and mirrored coordinates
+/// and is clipped to the frame.
+#[derive(Copy, Clone, Debug)]
+pub struct FrameGeometry {
+    rotation: Rotation,
+    mirror: bool,
+    src_width: usize,
+    src_height: usize,
+    oriented_width: usize,
+    crop_x: usize,
+    crop_y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl FrameGeometry {
+    pub fn new(img: &ImageData, transform: FrameTransform) -> Self {
+        let (src_width, src_height) = (img.width as usize, img.height as usize);
+        let (oriented_width, oriented_height) = match transform.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => (src_width, src_height),
+            Rotation::Rotate90 | Rotation::Rotate270 => (src_height, src_width),
+        };
+        let (crop_x, crop_y, width, height) = if transform.crop_width > 0 && transform.crop_height > 0 {
+            let crop_x = min(max(transform.crop_x, 0) as usize, oriented_width);
+            let crop_y = min(max(transform.crop_y, 0) as usize, oriented_height);
+            (
+                crop_x,
+                crop_y,
+                min(transform.crop_width as usize, oriented_width - crop_x),
+                min(transform.crop_height as usize, oriented_height - crop_y),
+            )
+        } else {
+            (0, 0, oriented_width, oriented_height)
+        };
+        Self {
+            rotation: transform.rotation,
+            mirror: transform.mirror,
+            src_width,
+            src_height,
+            oriented_width,
+            crop_x,
+            crop_y,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.rotation == Rotation::Rotate0
+            && !self.mirror
+            && self.width == self.src_width
+            && self.height == self.src_height
+    }
+
+    /// Sensor coordinates of the output pixel at `(x, y)`.
+    pub fn source(&self, x: usize, y: usize) -> (usize, usize) {
+        let mut ux = x + self.crop_x;
+        let uy = y + self.crop_y;
+        if self.mirror {
+            ux = self.oriented_width - 1 - ux;
+        }
+        match self.rotation {
+            Rotation::Rotate0 => (ux, uy),
+            Rotation::Rotate90 => (uy, self.src_height - 1 - ux),
+            Rotation::Rotate180 => (self.src_width - 1 - ux, self.src_height - 1 - uy),
+            Rotation::Rotate270 => (self.src_width - 1 - uy, ux),
+        }
+    }
+
+    /// Works a row at a time so that, for untransformed frames, the inner loop
+    /// has fixed strides and no bounds arithmetic beyond the UV lookup. With the
+    /// `parallel` feature, rows are converted concurrently via `rayon`.
+    pub fn rgba_into(&self, img: &ImageData, out: &mut [u8]) {
+        let row_bytes = self.width * 4;
+        if row_bytes == 0 {
+            return;
+        }
+        let planes = FramePlanes::new(img);
+        let convert_row = |(y, row): (usize, &mut [u8])| {
+            if self.is_identity() {
+                planes.rgba_row(y, row);
+            } else {
+                for (x, pixel) in row.chunks_exact_mut(4).enumerate() {
+                    let (sx, sy) = self.source(x, y);
+                    let (r, g, b) = planes.rgb(sx, sy);
+                    pixel.copy_from_slice(&[r, g, b, u8::MAX]);
+                }
+            }
+        };
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            out.par_chunks_exact_mut(row_bytes).enumerate().for_each(convert_row);
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            out.chunks_exact_mut(row_bytes).enumerate().for_each(convert_row);
+        }
+    }
+
+    fn rgba_image(&self, img: &ImageData) -> RgbaImage {
+        let mut bytes = vec![0; self.width * self.height * 4];
+        self.rgba_into(img, &mut bytes);
+        RgbaImage::from_raw(self.width as u32, self.height as u32, bytes).unwrap()
+    }
+
+    fn luma_image(&self, img: &ImageData) -> GrayImage {
+        let (width, height) = (self.width, self.height);
+        let stride = img.y_row_stride as usize;
+        let luma = if !self.is_identity() {
+            let planes = FramePlanes::new(img);
+            let mut result = Vec::with_capacity(width * height);
+            for y in 0..height {
+                for x in 0..width {
+                    let (sx, sy) = self.source(x, y);
+                    result.push(planes.luma(sx, sy));
+                }
+            }
+            result
+        } else if img.format == PixelFormat::Bgra8888 {
+            img.ys
+                .chunks_exact(stride)
+                .take(height)
+                .flat_map(|row| row[..width * 4].chunks_exact(4).map(|p| rgb_luma(p[2], p[1], p[0])))
+                .collect()
+        } else if stride == width {
+            img.ys[..width * height].to_vec()
+        } else {
+            let mut result = Vec::with_capacity(width * height);
+            for y in 0..height {
+                result.extend_from_slice(&img.ys[y * stride..y * stride + width]);
+            }
+            result
+        };
+        GrayImage::from_raw(width as u32, height as u32, luma).unwrap()
+    }
 }
 
-fn clamp_u8(value: i64) -> u8 {
-    min(max(value, 0), u8::MAX as i64) as u8
+/// Borrowed view of the samples of an `ImageData`, with every supported
+/// `PixelFormat` reduced to plane slices, offsets and strides.
+enum FramePlanes<'a> {
+    Yuv {
+        y: &'a [u8],
+        u: &'a [u8],
+        v: &'a [u8],
+        y_row_stride: usize,
+        uv_row_stride: usize,
+        uv_pixel_stride: usize,
+        conversion: YuvConversion,
+    },
+    Bgra {
+        bytes: &'a [u8],
+        row_stride: usize,
+    },
+}
+
+impl<'a> FramePlanes<'a> {
+    fn new(img: &'a ImageData) -> Self {
+        let y_row_stride = img.y_row_stride as usize;
+        let uv_row_stride = img.uv_row_stride as usize;
+        let uv_pixel_stride = img.uv_pixel_stride as usize;
+        let conversion = yuv_conversion();
+        let luma_len = min(y_row_stride * img.height as usize, img.ys.len());
+        let (y, trailing) = img.ys.split_at(luma_len);
+        match img.format {
+            PixelFormat::Yuv420 => FramePlanes::Yuv {
+                y,
+                u: &img.us,
+                v: &img.vs,
+                y_row_stride,
+                uv_row_stride,
+                uv_pixel_stride,
+                conversion,
+            },
+            PixelFormat::Nv12 | PixelFormat::Nv21 => {
+                let chroma = if img.us.is_empty() { trailing } else { &img.us[..] };
+                let (first, second) = (chroma, chroma.get(1..).unwrap_or(&[]));
+                let (u, v) = if img.format == PixelFormat::Nv12 {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                FramePlanes::Yuv {
+                    y,
+                    u,
+                    v,
+                    y_row_stride,
+                    uv_row_stride,
+                    uv_pixel_stride,
+                    conversion,
+                }
+            }
+            PixelFormat::I420 => {
+                let (u, v) = if img.us.is_empty() && img.vs.is_empty() {
+                    trailing.split_at(trailing.len() / 2)
+                } else {
+                    (&img.us[..], &img.vs[..])
+                };
+                FramePlanes::Yuv {
+                    y,
+                    u,
+                    v,
+                    y_row_stride,
+                    uv_row_stride,
+                    uv_pixel_stride,
+                    conversion,
+                }
+            }
+            PixelFormat::Bgra8888 => FramePlanes::Bgra {
+                bytes: &img.ys,
+                row_stride: y_row_stride,
+            },
+        }
+    }
+
+    fn rgb(&self, x: usize, y: usize) -> U8ColorTriple {
+        match self {
+            FramePlanes::Yuv {
+                y: ys,
+                u,
+                v,
+                y_row_stride,
+                uv_row_stride,
+                uv_pixel_stride,
+                conversion,
+            } => {
+                let uv_index = uv_pixel_stride * (x / 2) + uv_row_stride * (y / 2);
+                conversion.rgb(
+                    ys[y * y_row_stride + x] as i32,
+                    u[uv_index] as i32,
+                    v[uv_index] as i32,
+                )
+            }
+            FramePlanes::Bgra { bytes, row_stride } => {
+                let i = y * row_stride + x * 4;
+                (bytes[i + 2], bytes[i + 1], bytes[i])
+            }
+        }
+    }
+
+    fn luma(&self, x: usize, y: usize) -> u8 {
+        match self {
+            FramePlanes::Yuv {
+                y: ys, y_row_stride, ..
+            } => ys[y * y_row_stride + x],
+            FramePlanes::Bgra { bytes, row_stride } => {
+                let i = y * row_stride + x * 4;
+                rgb_luma(bytes[i + 2], bytes[i + 1], bytes[i])
+            }
+        }
+    }
+
+    fn rgba_row(&self, y: usize, row: &mut [u8]) {
+        let width = row.len() / 4;
+        match self {
+            FramePlanes::Yuv {
+                y: ys,
+                u,
+                v,
+                y_row_stride,
+                uv_row_stride,
+                uv_pixel_stride,
+                conversion,
+            } => {
+                let ys = &ys[y * y_row_stride..y * y_row_stride + width];
+                let uv_row = uv_row_stride * (y / 2);
+                for (x, (pixel, yp)) in row.chunks_exact_mut(4).zip(ys.iter()).enumerate() {
+                    let uv_index = uv_row + uv_pixel_stride * (x / 2);
+                    let (r, g, b) = conversion.rgb(*yp as i32, u[uv_index] as i32, v[uv_index] as i32);
+                    pixel.copy_from_slice(&[r, g, b, u8::MAX]);
+                }
+            }
+            FramePlanes::Bgra { bytes, row_stride } => {
+                let src = &bytes[y * row_stride..y * row_stride + width * 4];
+                for (pixel, bgra) in row.chunks_exact_mut(4).zip(src.chunks_exact(4)) {
+                    pixel.copy_from_slice(&[bgra[2], bgra[1], bgra[0], u8::MAX]);
+                }
+            }
+        }
+    }
+}
+
+/// Box-filter downsampling by an integer `factor` in each dimension. Partial
+/// blocks at the right and bottom edges are dropped; a `factor` of 1 copies.
+pub fn downsample<P>(img: &ImageBuffer<P, Vec<u8>>, factor: u32) -> ImageBuffer<P, Vec<u8>>
+where
+    P: Pixel<Subpixel = u8> + 'static,
+{
+    if factor <= 1 {
+        return img.clone();
+    }
+    let area = factor * factor;
+    let mut result: ImageBuffer<P, Vec<u8>> = ImageBuffer::new(img.width() / factor, img.height() / factor);
+    for (x, y, pixel) in result.enumerate_pixels_mut() {
+        let mut sums = [0; 4];
+        for dy in 0..factor {
+            for dx in 0..factor {
+                let src = img.get_pixel(x * factor + dx, y * factor + dy);
+                for (sum, c) in sums.iter_mut().zip(src.channels()) {
+                    *sum += *c as u32;
+                }
+            }
+        }
+        for (c, sum) in pixel.channels_mut().iter_mut().zip(sums.iter()) {
+            *c = ((sum + area / 2) / area) as u8;
+        }
+    }
+    result
+}
+
+/// Nearest-neighbor upsampling of an image downsampled by `factor` back to
+/// `width` by `height`, e.g. to display a result computed at a coarser level.
+pub fn upsample<P>(img: &ImageBuffer<P, Vec<u8>>, factor: u32, width: u32, height: u32) -> ImageBuffer<P, Vec<u8>>
+where
+    P: Pixel<Subpixel = u8> + 'static,
+{
+    if img.width() == 0 || img.height() == 0 {
+        return ImageBuffer::new(width, height);
+    }
+    let factor = max(factor, 1);
+    ImageBuffer::from_fn(width, height, |x, y| {
+        *img.get_pixel(min(x / factor, img.width() - 1), min(y / factor, img.height() - 1))
+    })
+}
+
+/// Maps a point found in an image downsampled by `factor` back to the full
+/// image: the center of a downsampled pixel is the center of its block.
+pub fn to_full_scale((x, y): (f32, f32), factor: u32) -> (f32, f32) {
+    let factor = max(factor, 1) as f32;
+    let offset = (factor - 1.0) / 2.0;
+    (x * factor + offset, y * factor + offset)
+}
+
+const IMAGE_DATA_MAGIC: &[u8; 4] = b"VBIM";
+const IMAGE_DATA_VERSION: u8 = 1;
+
+/// Writes `img` exactly as the camera delivered it, padding and all, so that a
+/// captured frame can be converted again later, e.g. as a test fixture.
+///
+/// The format, with every integer little-endian:
+///
+/// | bytes | contents                                                         |
+/// |-------|------------------------------------------------------------------|
+/// | 4     | magic `VBIM`                                                     |
+/// | 1     | version, currently 1                                             |
+/// | 1     | format: 0 `Yuv420`, 1 `Nv21`, 2 `Nv12`, 3 `I420`, 4 `Bgra8888`   |
+/// | 20    | `u32` width, height, `y_row_stride`, `uv_row_stride`, `uv_pixel_stride` |
+/// | ...   | `ys`, `us` and `vs`, each a `u32` length followed by its bytes  |
+pub fn write_image_data<W: Write>(out: &mut W, img: &ImageData) -> io::Result<()> {
+    out.write_all(IMAGE_DATA_MAGIC)?;
+    out.write_all(&[IMAGE_DATA_VERSION, format_code(img.format)])?;
+    for value in [img.width, img.height, img.y_row_stride, img.uv_row_stride, img.uv_pixel_stride] {
+        write_u32(out, u32::try_from(value).map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?)?;
+    }
+    for plane in [&img.ys, &img.us, &img.vs] {
+        write_bytes(out, plane)?;
+    }
+    Ok(())
+}
+
+/// Reads one `ImageData` in the format of `write_image_data`.
+pub fn read_image_data<R: Read>(input: &mut R) -> io::Result<ImageData> {
+    let mut header = [0; 6];
+    input.read_exact(&mut header)?;
+    if &header[..4] != IMAGE_DATA_MAGIC {
+        return Err(io::Error::new(ErrorKind::InvalidData, "not an ImageData file"));
+    }
+    if header[4] != IMAGE_DATA_VERSION {
+        return Err(io::Error::new(ErrorKind::InvalidData, format!("ImageData version {}", header[4])));
+    }
+    let format = code_format(header[5])
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, format!("unknown pixel format {}", header[5])))?;
+    let mut sizes = [0; 5];
+    for size in sizes.iter_mut() {
+        *size = read_u32(input)? as i64;
+    }
+    let [width, height, y_row_stride, uv_row_stride, uv_pixel_stride] = sizes;
+    Ok(ImageData {
+        ys: read_bytes(input)?,
+        us: read_bytes(input)?,
+        vs: read_bytes(input)?,
+        width,
+        height,
+        y_row_stride,
+        uv_row_stride,
+        uv_pixel_stride,
+        format,
+    })
+}
+
+pub fn save_image_data<P: AsRef<Path>>(path: P, img: &ImageData) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write_image_data(&mut out, img)?;
+    out.flush()
+}
+
+pub fn load_image_data<P: AsRef<Path>>(path: P) -> io::Result<ImageData> {
+    read_image_data(&mut BufReader::new(File::open(path)?))
+}
+
+/// `rgba` as an unpadded `Bgra8888` frame, the way iOS delivers camera images.
+pub fn bgra_frame(rgba: &RgbaImage) -> ImageData {
+    ImageData {
+        ys: rgba.pixels().flat_map(|p| [p[2], p[1], p[0], p[3]]).collect(),
+        us: vec![],
+        vs: vec![],
+        width: rgba.width() as i64,
+        height: rgba.height() as i64,
+        y_row_stride: rgba.width() as i64 * 4,
+        uv_row_stride: 0,
+        uv_pixel_stride: 0,
+        format: PixelFormat::Bgra8888,
+    }
+}
+
+fn format_code(format: PixelFormat) -> u8 {
+    match format {
+        PixelFormat::Yuv420 => 0,
+        PixelFormat::Nv21 => 1,
+        PixelFormat::Nv12 => 2,
+        PixelFormat::I420 => 3,
+        PixelFormat::Bgra8888 => 4,
+    }
+}
+
+fn code_format(code: u8) -> Option<PixelFormat> {
+    match code {
+        0 => Some(PixelFormat::Yuv420),
+        1 => Some(PixelFormat::Nv21),
+        2 => Some(PixelFormat::Nv12),
+        3 => Some(PixelFormat::I420),
+        4 => Some(PixelFormat::Bgra8888),
+        _ => None,
+    }
+}
+
+pub(crate) fn write_u32<W: Write>(out: &mut W, value: u32) -> io::Result<()> {
+    out.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn read_u32<R: Read>(input: &mut R) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    input.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Writes `bytes` preceded by their `u32` length.
+pub(crate) fn write_bytes<W: Write>(out: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(bytes.len()).map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?;
+    write_u32(out, len)?;
+    out.write_all(bytes)
+}
+
+/// Reads bytes written by `write_bytes`.
+pub(crate) fn read_bytes<R: Read>(input: &mut R) -> io::Result<Vec<u8>> {
+    let len = read_u32(input)? as u64;
+    let mut bytes = vec![];
+    input.take(len).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 != len {
+        return Err(ErrorKind::UnexpectedEof.into());
+    }
+    Ok(bytes)
+}
+
+const FIXED_SHIFT: u32 = 16;
+const FIXED_HALF: i32 = 1 << (FIXED_SHIFT - 1);
+
+/// Fixed-point YUV to RGB coefficients for one color standard and range.
+///
+/// Coefficients are derived from the standard's luma weights (Kr, Kb) and
+/// scaled by 2^16, so every conversion is a few integer multiply-adds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct YuvConversion {
+    standard: ColorStandard,
+    range: ColorRange,
+    y_offset: i32,
+    y_scale: i32,
+    r_v: i32,
+    g_u: i32,
+    g_v: i32,
+    b_u: i32,
+}
+
+impl YuvConversion {
+    pub fn new(standard: ColorStandard, range: ColorRange) -> Self {
+        let (kr, kb) = match standard {
+            ColorStandard::Bt601 => (0.299, 0.114),
+            ColorStandard::Bt709 => (0.2126, 0.0722),
+        };
+        let kg = 1.0 - kr - kb;
+        let (y_offset, y_scale, c_scale) = match range {
+            ColorRange::Full => (0, 1.0, 1.0),
+            ColorRange::Limited => (16, 255.0 / 219.0, 255.0 / 224.0),
+        };
+        let fixed = |value: f64| (value * (1 << FIXED_SHIFT) as f64).round() as i32;
+        Self {
+            standard,
+            range,
+            y_offset,
+            y_scale: fixed(y_scale),
+            r_v: fixed(2.0 * (1.0 - kr) * c_scale),
+            g_u: fixed(2.0 * kb * (1.0 - kb) / kg * c_scale),
+            g_v: fixed(2.0 * kr * (1.0 - kr) / kg * c_scale),
+            b_u: fixed(2.0 * (1.0 - kb) * c_scale),
+        }
+    }
+
+    pub fn standard(&self) -> ColorStandard {
+        self.standard
+    }
+
+    pub fn range(&self) -> ColorRange {
+        self.range
+    }
+
+    #[inline(always)]
+    pub fn rgb(&self, y: i32, u: i32, v: i32) -> U8ColorTriple {
+        let luma = (y - self.y_offset) * self.y_scale + FIXED_HALF;
+        let (u, v) = (u - 128, v - 128);
+        (
+            clamp_u8((luma + self.r_v * v) >> FIXED_SHIFT),
+            clamp_u8((luma - self.g_u * u - self.g_v * v) >> FIXED_SHIFT),
+            clamp_u8((luma + self.b_u * u) >> FIXED_SHIFT),
+        )
+    }
+}
+
+#[inline(always)]
+fn clamp_u8(value: i32) -> u8 {
+    min(max(value, 0), u8::MAX as i32) as u8
 }
 
 pub fn correspondences(
@@ -85,6 +731,13 @@ pub fn kp_distance_f64(kp1: &KeyPoint, kp2: &KeyPoint) -> f64 {
     ((kp1.point.0 as f64 - kp2.point.0 as f64)).powf(2.0) + ((kp1.point.1 as f64 - kp2.point.1 as f64)).powf(2.0)
 }
 
+/// Sum of squared differences of the bytes the two images have in common.
+pub fn distance_u8(img1: &Vec<u8>, img2: &Vec<u8>) -> f64 {
+    (0..min(img1.len(), img2.len()))
+        .map(|i| (img1[i] as f64 - img2[i] as f64).powf(2.0))
+        .sum()
+}
+
 pub fn kp_feature_distance_f64(f1: &BitArray<64>, f2: &BitArray<64>) -> f64 {
     f1.distance(&f2) as f64
 }
@@ -6,6 +6,19 @@
 // To compile, after generating bridge:
 // cargo ndk -o ..\android\app\src\main\jniLibs build --release
 
-mod api;
+pub mod api;
+pub mod augmentation;
+pub mod behavior;
 mod bridge_generated;
-mod image_proc;
+pub mod control;
+pub mod datasets;
+pub mod duplicates;
+pub mod edges;
+pub mod image_proc;
+pub mod lines;
+pub mod phash;
+pub mod projects;
+pub mod recording;
+pub mod segmentation;
+pub mod simulation;
+pub mod tracking;
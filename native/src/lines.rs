@@ -0,0 +1,152 @@
+use image::GrayImage;
+use std::cmp::{min, Reverse};
+use std::f32::consts::PI;
+
+/// Angular resolution of the Hough accumulator: one bin per degree.
+pub const THETA_STEPS: usize = 180;
+
+/// A line `x cos(theta) + y sin(theta) = rho` found by the Hough transform,
+/// with the number of edge pixels that support it and their endpoints.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HoughLine {
+    pub rho: f32,
+    pub theta: f32,
+    pub votes: u32,
+    pub start: (f32, f32),
+    pub end: (f32, f32),
+}
+
+/// Finds up to `max_lines` lines supported by at least `min_votes` edge pixels,
+/// strongest first. Any nonzero pixel of `edges` counts as an edge, and counts
+/// toward one line only.
+pub fn hough_lines(edges: &GrayImage, min_votes: u32, max_lines: usize) -> Vec<HoughLine> {
+    let (width, height) = edges.dimensions();
+    let points: Vec<(f32, f32)> = edges
+        .enumerate_pixels()
+        .filter(|(_, _, p)| p[0] > 0)
+        .map(|(x, y, _)| (x as f32, y as f32))
+        .collect();
+    let max_rho = (width as f64).hypot(height as f64).ceil() as usize;
+    let rho_bins = 2 * max_rho + 1;
+    let trig: Vec<(f32, f32)> = (0..THETA_STEPS)
+        .map(|t| {
+            let theta = t as f32 * PI / THETA_STEPS as f32;
+            (theta.cos(), theta.sin())
+        })
+        .collect();
+    let rho_bin = |(x, y): (f32, f32), (cos, sin): (f32, f32)| {
+        ((x * cos + y * sin).round() as isize + max_rho as isize) as usize
+    };
+
+    let mut accumulator = vec![0u32; rho_bins * THETA_STEPS];
+    for point in points.iter() {
+        for (t, angle) in trig.iter().enumerate() {
+            accumulator[rho_bin(*point, *angle) * THETA_STEPS + t] += 1;
+        }
+    }
+
+    let mut peaks = vec![];
+    for r in 0..rho_bins {
+        for t in 0..THETA_STEPS {
+            let votes = accumulator[r * THETA_STEPS + t];
+            if votes >= min_votes && is_peak(&accumulator, rho_bins, r, t) {
+                peaks.push((votes, r, t));
+            }
+        }
+    }
+    peaks.sort_by_key(|(votes, _, _)| Reverse(*votes));
+
+    // Each edge pixel supports at most one line, so the side lobes a segment
+    // leaves around its own peak come away empty.
+    // A point within 1 of a peak's line falls in the peak's rho bin or one of
+    // its neighbors, so the points are bucketed by rho bin once per angle a
+    // peak needs rather than rescanned for every peak.
+    let mut claimed = vec![false; points.len()];
+    let mut buckets: Vec<Option<Vec<Vec<usize>>>> = vec![None; THETA_STEPS];
+    let mut lines = vec![];
+    for (_, r, t) in peaks {
+        if lines.len() == max_lines {
+            break;
+        }
+        let rho = r as f32 - max_rho as f32;
+        let (cos, sin) = trig[t];
+        let by_rho = buckets[t].get_or_insert_with(|| {
+            let mut by_rho = vec![vec![]; rho_bins];
+            for (i, point) in points.iter().enumerate() {
+                by_rho[rho_bin(*point, trig[t])].push(i);
+            }
+            by_rho
+        });
+        let mut support: Vec<usize> = (r.saturating_sub(1)..min(r + 2, rho_bins))
+            .flat_map(|bin| by_rho[bin].iter().copied())
+            .filter(|i| !claimed[*i])
+            .filter(|i| (points[*i].0 * cos + points[*i].1 * sin - rho).abs() <= 1.0)
+            .collect();
+        support.sort_unstable();
+        if (support.len() as u32) < min_votes {
+            continue;
+        }
+        for i in support.iter() {
+            claimed[*i] = true;
+        }
+        lines.push(fit_line(support.iter().map(|i| points[*i])));
+    }
+    lines
+}
+
+/// Total least squares line through `points`, which the accumulator only
+/// locates to within a bin, with their extent along it as endpoints.
+fn fit_line<I: Iterator<Item = (f32, f32)> + Clone>(points: I) -> HoughLine {
+    let n = points.clone().count() as f32;
+    let (sum_x, sum_y) = points.clone().fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+    let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+    let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+    for (x, y) in points.clone() {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        sxx += dx * dx;
+        syy += dy * dy;
+        sxy += dx * dy;
+    }
+    let direction = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+    let theta = (direction + PI / 2.0).rem_euclid(PI);
+    let (cos, sin) = (theta.cos(), theta.sin());
+    let rho = mean_x * cos + mean_y * sin;
+    let mut extent = (f32::MAX, f32::MIN);
+    for (x, y) in points {
+        let along = y * cos - x * sin;
+        extent = (extent.0.min(along), extent.1.max(along));
+    }
+    let point_at = |along: f32| (rho * cos - along * sin, rho * sin + along * cos);
+    HoughLine {
+        rho,
+        theta,
+        votes: n as u32,
+        start: point_at(extent.0),
+        end: point_at(extent.1),
+    }
+}
+
+/// True if bin `(r, t)` beats its 8 neighbors. Theta wraps around, with
+/// `(rho, PI)` the same line as `(-rho, 0)`. Ties go to the bin that comes
+/// first in row-major order, so a plateau yields exactly one peak.
+fn is_peak(accumulator: &[u32], rho_bins: usize, r: usize, t: usize) -> bool {
+    let votes = accumulator[r * THETA_STEPS + t];
+    for dr in -1..=1_isize {
+        for dt in -1..=1_isize {
+            let (mut nr, mut nt) = (r as isize + dr, t as isize + dt);
+            if nt < 0 || nt >= THETA_STEPS as isize {
+                nr = rho_bins as isize - 1 - nr;
+                nt = nt.rem_euclid(THETA_STEPS as isize);
+            }
+            if (dr, dt) == (0, 0) || nr < 0 || nr >= rho_bins as isize {
+                continue;
+            }
+            let neighbor = accumulator[nr as usize * THETA_STEPS + nt as usize];
+            let earlier = (nr, nt) < (r as isize, t as isize);
+            if neighbor > votes || (earlier && neighbor == votes) {
+                return false;
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,102 @@
+use crate::api::{DartImage, HashKind, ImageData};
+use crate::image_proc::{luma_image, RgbaFrame};
+use image::imageops::{resize, FilterType};
+use image::GrayImage;
+use std::f64::consts::PI;
+
+const DCT_SIZE: usize = 32;
+const HASH_SIDE: usize = 8;
+
+/// A 64-bit perceptual hash: similar-looking images get hashes that differ in
+/// few bits, whatever their size. Eight bytes, against the 64 of an AKAZE
+/// `BitArray<64>` descriptor.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PerceptualHash(pub u64);
+
+impl PerceptualHash {
+    /// Number of differing bits, from 0 for lookalikes to 64.
+    pub fn distance(&self, other: &Self) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    fn from_bits<I: Iterator<Item = bool>>(bits: I) -> Self {
+        Self(bits.take(64).fold(0, |hash, bit| (hash << 1) | bit as u64))
+    }
+}
+
+pub fn hash_distance_f64(a: &PerceptualHash, b: &PerceptualHash) -> f64 {
+    a.distance(b) as f64
+}
+
+pub fn hash_dart_image(img: &DartImage, kind: HashKind) -> PerceptualHash {
+    let frame = RgbaFrame::from_rgba_bytes(&img.bytes, img.width as u32, img.height as u32);
+    hash_luma(frame.luma(), kind)
+}
+
+/// Hashes a camera frame straight from its Y plane.
+pub fn hash_image_data(img: &ImageData, kind: HashKind) -> PerceptualHash {
+    hash_luma(&luma_image(img), kind)
+}
+
+pub fn hash_luma(luma: &GrayImage, kind: HashKind) -> PerceptualHash {
+    if luma.width() == 0 || luma.height() == 0 {
+        return PerceptualHash::default();
+    }
+    match kind {
+        HashKind::Average => average_hash(luma),
+        HashKind::Difference => difference_hash(luma),
+        HashKind::Dct => dct_hash(luma),
+    }
+}
+
+/// One bit per cell of an 8x8 thumbnail: brighter than the thumbnail's mean.
+fn average_hash(luma: &GrayImage) -> PerceptualHash {
+    let small = resize(luma, HASH_SIDE as u32, HASH_SIDE as u32, FilterType::Triangle);
+    let mean = small.pixels().map(|p| p[0] as u32).sum::<u32>() as f64 / (HASH_SIDE * HASH_SIDE) as f64;
+    PerceptualHash::from_bits(small.pixels().map(|p| p[0] as f64 > mean))
+}
+
+/// One bit per horizontally adjacent pair in a 9x8 thumbnail: brightness
+/// rising to the right.
+fn difference_hash(luma: &GrayImage) -> PerceptualHash {
+    let small = resize(luma, HASH_SIDE as u32 + 1, HASH_SIDE as u32, FilterType::Triangle);
+    PerceptualHash::from_bits(
+        (0..HASH_SIDE as u32)
+            .flat_map(|y| (0..HASH_SIDE as u32).map(move |x| (x, y)))
+            .map(|(x, y)| small.get_pixel(x, y)[0] < small.get_pixel(x + 1, y)[0]),
+    )
+}
+
+/// The classic pHash: one bit per lowest-frequency 8x8 DCT coefficient of a
+/// 32x32 thumbnail, set when above the median of those coefficients, leaving
+/// out the overall brightness.
+fn dct_hash(luma: &GrayImage) -> PerceptualHash {
+    let small = resize(luma, DCT_SIZE as u32, DCT_SIZE as u32, FilterType::Triangle);
+    let pixels: Vec<f64> = small.pixels().map(|p| p[0] as f64).collect();
+    let basis: Vec<Vec<f64>> = (0..HASH_SIDE)
+        .map(|k| {
+            (0..DCT_SIZE)
+                .map(|n| (PI / DCT_SIZE as f64 * (n as f64 + 0.5) * k as f64).cos())
+                .collect()
+        })
+        .collect();
+    // Rows first, keeping only the low frequencies, then columns.
+    let rows: Vec<Vec<f64>> = pixels
+        .chunks_exact(DCT_SIZE)
+        .map(|row| basis.iter().map(|b| dot(b, row)).collect())
+        .collect();
+    let mut coefficients = Vec::with_capacity(HASH_SIDE * HASH_SIDE);
+    for column_basis in basis.iter() {
+        for u in 0..HASH_SIDE {
+            coefficients.push(column_basis.iter().zip(rows.iter()).map(|(b, row)| b * row[u]).sum::<f64>());
+        }
+    }
+    let mut ac = coefficients[1..].to_vec();
+    ac.sort_by(|a, b| a.total_cmp(b));
+    let median = ac[ac.len() / 2];
+    PerceptualHash::from_bits(coefficients.iter().map(|c| *c > median))
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
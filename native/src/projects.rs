@@ -0,0 +1,70 @@
+use crate::api::{DartImage, LabeledImage};
+use anyhow::{Context, Result};
+use image::DynamicImage;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory under the app's support directory that holds one directory per project.
+pub const PROJECT_DIR_NAME: &str = "projects";
+
+/// Path of `project` in the app's support directory `dir`.
+pub fn project_dir<P: AsRef<Path>>(dir: P, project: &str) -> PathBuf {
+    dir.as_ref().join(PROJECT_DIR_NAME).join(project)
+}
+
+/// Every photo of a project directory laid out as the app saves it: one
+/// directory per label, holding that label's PNGs. Labels and photos come back
+/// in name order, so training is repeatable; anything else is skipped.
+pub fn labeled_images<P: AsRef<Path>>(project_dir: P) -> Result<Vec<LabeledImage>> {
+    project_photos(project_dir)?
+        .into_iter()
+        .map(|(label, file)| Ok(LabeledImage { label, image: load_image(&file)? }))
+        .collect()
+}
+
+/// The label and path of every photo in a project directory, in the order of
+/// `labeled_images`.
+pub fn project_photos<P: AsRef<Path>>(project_dir: P) -> Result<Vec<(String, PathBuf)>> {
+    let mut photos = vec![];
+    for label_dir in sorted_entries(project_dir.as_ref())? {
+        if !label_dir.is_dir() {
+            continue;
+        }
+        let label = label_dir.file_name().unwrap().to_string_lossy().into_owned();
+        for file in sorted_entries(&label_dir)? {
+            if file.extension().is_some_and(|e| e.eq_ignore_ascii_case("png")) {
+                photos.push((label.clone(), file));
+            }
+        }
+    }
+    Ok(photos)
+}
+
+/// Decodes an image file into the RGBA bytes that Dart passes as a `DartImage`.
+pub fn load_image<P: AsRef<Path>>(path: P) -> Result<DartImage> {
+    let path = path.as_ref();
+    Ok(dart_image(image::open(path).with_context(|| path.display().to_string())?))
+}
+
+/// Like `load_image`, for an image file already in memory.
+pub fn decode_image(bytes: &[u8]) -> Result<DartImage> {
+    Ok(dart_image(image::load_from_memory(bytes)?))
+}
+
+fn dart_image(img: DynamicImage) -> DartImage {
+    let rgba = img.to_rgba8();
+    DartImage {
+        width: rgba.width() as i64,
+        height: rgba.height() as i64,
+        bytes: rgba.into_raw(),
+    }
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| dir.display().to_string())?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
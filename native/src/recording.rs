@@ -0,0 +1,250 @@
+use crate::api::{
+    akaze_flow, classify_knn, classify_knn_akaze_feature, classify_knn_akaze_pos, process_sensor_data, pyramid_level,
+    reset_akaze_flow, reset_position_estimate, set_pyramid_level, yuv_rgba, ColorRange, ColorStandard, DartImage,
+    FrameTransform, ImageData, ImageResponse, Rotation,
+};
+use crate::image_proc::{
+    frame_transform, read_bytes, read_image_data, set_frame_transform, set_yuv_conversion, write_bytes,
+    write_image_data, yuv_conversion, YuvConversion,
+};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::Instant;
+
+const MAGIC: &[u8; 6] = b"VBLOG2";
+const FRAME_TAG: u8 = b'F';
+const SENSORS_TAG: u8 = b'S';
+
+/// One thing the robot saw: a camera frame or a sensor packet string.
+#[derive(Clone)]
+pub enum LogEntry {
+    Frame(ImageData),
+    Sensors(String),
+}
+
+/// How frames were being converted when a log was recorded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LogSettings {
+    pub standard: ColorStandard,
+    pub range: ColorRange,
+    pub transform: FrameTransform,
+    pub pyramid_level: i64,
+}
+
+impl LogSettings {
+    /// The settings every frame conversion uses right now.
+    pub fn current() -> Self {
+        let conversion = yuv_conversion();
+        Self {
+            standard: conversion.standard(),
+            range: conversion.range(),
+            transform: frame_transform(),
+            pyramid_level: pyramid_level(),
+        }
+    }
+
+    /// Makes these the settings for every frame conversion from now on.
+    pub fn apply(&self) {
+        set_yuv_conversion(YuvConversion::new(self.standard, self.range));
+        set_frame_transform(self.transform);
+        set_pyramid_level(self.pyramid_level);
+    }
+
+    /// Applies these settings until the returned guard is dropped, which puts
+    /// back the current ones even if the caller returns early or panics.
+    pub fn apply_until_dropped(&self) -> RestoreSettings {
+        let saved = Self::current();
+        self.apply();
+        RestoreSettings(saved)
+    }
+
+    fn write<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let standard = match self.standard {
+            ColorStandard::Bt601 => 0,
+            ColorStandard::Bt709 => 1,
+        };
+        let range = match self.range {
+            ColorRange::Limited => 0,
+            ColorRange::Full => 1,
+        };
+        let rotation = match self.transform.rotation {
+            Rotation::Rotate0 => 0,
+            Rotation::Rotate90 => 1,
+            Rotation::Rotate180 => 2,
+            Rotation::Rotate270 => 3,
+        };
+        out.write_all(&[standard, range, rotation, self.transform.mirror as u8, self.pyramid_level as u8])?;
+        let t = self.transform;
+        for value in [t.crop_x, t.crop_y, t.crop_width, t.crop_height] {
+            out.write_all(&value.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(input: &mut R) -> io::Result<Self> {
+        let mut bytes = [0; 5];
+        input.read_exact(&mut bytes)?;
+        let [standard, range, rotation, mirror, pyramid_level] = bytes;
+        let invalid = || io::Error::new(ErrorKind::InvalidData, "bad recording settings");
+        let standard = match standard {
+            0 => ColorStandard::Bt601,
+            1 => ColorStandard::Bt709,
+            _ => return Err(invalid()),
+        };
+        let range = match range {
+            0 => ColorRange::Limited,
+            1 => ColorRange::Full,
+            _ => return Err(invalid()),
+        };
+        let rotation = match rotation {
+            0 => Rotation::Rotate0,
+            1 => Rotation::Rotate90,
+            2 => Rotation::Rotate180,
+            3 => Rotation::Rotate270,
+            _ => return Err(invalid()),
+        };
+        let mut crop = [0; 4];
+        for value in crop.iter_mut() {
+            let mut bytes = [0; 8];
+            input.read_exact(&mut bytes)?;
+            *value = i64::from_le_bytes(bytes);
+        }
+        let [crop_x, crop_y, crop_width, crop_height] = crop;
+        let transform = FrameTransform { rotation, mirror: mirror != 0, crop_x, crop_y, crop_width, crop_height };
+        Ok(Self { standard, range, transform, pyramid_level: pyramid_level as i64 })
+    }
+}
+
+/// Settings to put back when dropped; see `LogSettings::apply_until_dropped`.
+pub struct RestoreSettings(LogSettings);
+
+impl Drop for RestoreSettings {
+    fn drop(&mut self) {
+        self.0.apply();
+    }
+}
+
+/// A `LogEntry` with the milliseconds since recording started.
+#[derive(Clone)]
+pub struct LogRecord {
+    pub millis: u64,
+    pub entry: LogEntry,
+}
+
+/// Everything in a log file.
+pub struct Log {
+    pub settings: LogSettings,
+    pub records: Vec<LogRecord>,
+}
+
+/// Appends frames and sensor packets to a log file as they arrive.
+///
+/// The log is `VBLOG2`, then the `LogSettings` in effect when recording started:
+/// one byte each for the color standard, range, rotation, mirroring and pyramid
+/// level, and the crop as four little-endian `i64`s. Records follow. Each record is a tag byte (`F` or
+/// `S`) and a little-endian `u64` timestamp. A sensor record then has a `u32`
+/// length and the packet's UTF-8 bytes. A frame record then has the frame in
+/// the format of `image_proc::write_image_data`.
+///
+/// Settings changed while recording are not logged, so replay converts every
+/// frame with the settings the recording started with.
+pub struct Recorder {
+    out: BufWriter<File>,
+    start: Instant,
+}
+
+impl Recorder {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut out = BufWriter::new(File::create(path)?);
+        out.write_all(MAGIC)?;
+        LogSettings::current().write(&mut out)?;
+        Ok(Self { out, start: Instant::now() })
+    }
+
+    pub fn frame(&mut self, img: &ImageData) -> io::Result<()> {
+        self.header(FRAME_TAG)?;
+        write_image_data(&mut self.out, img)
+    }
+
+    pub fn sensors(&mut self, packet: &str) -> io::Result<()> {
+        self.header(SENSORS_TAG)?;
+        write_bytes(&mut self.out, packet.as_bytes())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    fn header(&mut self, tag: u8) -> io::Result<()> {
+        self.out.write_all(&[tag])?;
+        self.out.write_all(&(self.start.elapsed().as_millis() as u64).to_le_bytes())
+    }
+}
+
+/// Reads back the settings and every record of a log written by `Recorder`. A
+/// record cut short at the end, as when the app dies mid-write, is dropped.
+pub fn read_log<P: AsRef<Path>>(path: P) -> io::Result<Log> {
+    let mut input = BufReader::new(File::open(path)?);
+    let mut magic = [0; 6];
+    input.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(io::Error::new(ErrorKind::InvalidData, "not a recording"));
+    }
+    let settings = LogSettings::read(&mut input)?;
+    let mut records = vec![];
+    loop {
+        let mut tag = [0];
+        if input.read(&mut tag)? == 0 {
+            return Ok(Log { settings, records });
+        }
+        match read_record(&mut input, tag[0]) {
+            Ok(record) => records.push(record),
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(Log { settings, records }),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Forgets the position estimate and the previous `akaze_flow` frame, so that
+/// replaying a log always starts from the same state.
+pub fn reset_replay_state() {
+    reset_position_estimate();
+    reset_akaze_flow();
+}
+
+/// Feeds one record back through what the app runs live: sensor packets go to
+/// `process_sensor_data`, frames to `akaze_flow` and the three kNN classifiers.
+/// Returns a description of the results and, for frames, the flow view.
+pub fn replay(entry: LogEntry) -> (String, Option<ImageResponse>) {
+    match entry {
+        LogEntry::Sensors(packet) => (process_sensor_data(packet), None),
+        LogEntry::Frame(img) => {
+            let rgba = yuv_rgba(img.clone());
+            let image = DartImage { bytes: rgba.img.0, width: rgba.width, height: rgba.height };
+            let labels = format!(
+                "knn: {}; akaze_pos: {}; akaze_feature: {}",
+                classify_knn(image.bytes.clone()),
+                classify_knn_akaze_pos(image.clone()),
+                classify_knn_akaze_feature(image)
+            );
+            (labels, Some(akaze_flow(img)))
+        }
+    }
+}
+
+fn read_record<R: Read>(input: &mut R, tag: u8) -> io::Result<LogRecord> {
+    let mut millis = [0; 8];
+    input.read_exact(&mut millis)?;
+    let millis = u64::from_le_bytes(millis);
+    let entry = match tag {
+        FRAME_TAG => LogEntry::Frame(read_image_data(input)?),
+        SENSORS_TAG => {
+            let packet = String::from_utf8(read_bytes(input)?)
+                .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+            LogEntry::Sensors(packet)
+        }
+        _ => return Err(io::Error::new(ErrorKind::InvalidData, format!("unknown record tag {tag}"))),
+    };
+    Ok(LogRecord { millis, entry })
+}
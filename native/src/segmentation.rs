@@ -0,0 +1,278 @@
+use crate::image_proc::U8ColorTriple;
+use image::{GrayImage, Luma, Rgba, RgbaImage};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/// The floor sample is the middle third of the frame's width, across its bottom eighth.
+const FLOOR_SAMPLE_WIDTH_DIVISOR: u32 = 3;
+const FLOOR_SAMPLE_HEIGHT_DIVISOR: u32 = 8;
+
+/// Colors covering less than this share of the floor sample are treated as noise.
+const MIN_FLOOR_SHARE: f64 = 0.1;
+
+/// How many colors a frame is reduced to when there is no trained color model.
+pub const FRAME_PALETTE_SIZE: usize = 8;
+
+/// Frames are clustered from at most this many evenly spaced pixels.
+const PALETTE_SAMPLES: usize = 4096;
+const PALETTE_ITERATIONS: usize = 10;
+
+/// Palette colors closer than this, in RGB space, are taken to be noise.
+const MIN_SEED_DISTANCE: u32 = 48;
+
+/// A few representative colors for cluster-coloring a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    colors: Vec<U8ColorTriple>,
+}
+
+impl Palette {
+    pub fn new(mut colors: Vec<U8ColorTriple>) -> Self {
+        colors.sort();
+        colors.dedup();
+        Self { colors }
+    }
+
+    /// Up to `k` colors found by k-means on the frame itself, so that frames
+    /// can be segmented before any color model is trained.
+    ///
+    /// Seeds are picked farthest first, starting from the sample nearest the
+    /// frame's mean color, and only while the farthest sample is at least
+    /// `MIN_SEED_DISTANCE` from every seed; a noisy but uniform region thus
+    /// stays one color, and the same frame always gets the same palette.
+    pub fn of_frame(rgba: &RgbaImage, k: usize) -> Self {
+        let step = (rgba.pixels().len() / PALETTE_SAMPLES).max(1);
+        let samples: Vec<U8ColorTriple> = rgba.pixels().step_by(step).map(|p| (p[0], p[1], p[2])).collect();
+        if samples.is_empty() || k == 0 {
+            return Self::new(vec![]);
+        }
+        let mut seeds = vec![samples[nearest_in(&samples, mean_color(samples.iter().copied()))]];
+        while seeds.len() < k {
+            let distances = samples.iter().map(|c| distance(nearest_color(&seeds, *c), *c));
+            let (farthest, d) = distances.enumerate().fold((0, 0), |best, (i, d)| if d > best.1 { (i, d) } else { best });
+            if d < MIN_SEED_DISTANCE * MIN_SEED_DISTANCE {
+                break;
+            }
+            seeds.push(samples[farthest]);
+        }
+        let mut palette = Self::new(seeds);
+        for _ in 0..PALETTE_ITERATIONS {
+            let mut clusters = vec![vec![]; palette.colors.len()];
+            for color in samples.iter() {
+                clusters[palette.nearest_index(*color)].push(*color);
+            }
+            let means = palette
+                .colors
+                .iter()
+                .zip(clusters)
+                .map(|(color, cluster)| if cluster.is_empty() { *color } else { mean_color(cluster.into_iter()) })
+                .collect();
+            let next = Self::new(means);
+            if next == palette {
+                break;
+            }
+            palette = next;
+        }
+        palette
+    }
+
+    pub fn colors(&self) -> &[U8ColorTriple] {
+        &self.colors
+    }
+
+    /// The palette color closest to `color`, or `color` itself if the palette is empty.
+    pub fn nearest(&self, color: U8ColorTriple) -> U8ColorTriple {
+        self.colors.get(self.nearest_index(color)).copied().unwrap_or(color)
+    }
+
+    /// Replaces every pixel with its nearest palette color.
+    pub fn recolor(&self, rgba: &RgbaImage) -> RgbaImage {
+        let mut result = rgba.clone();
+        for pixel in result.pixels_mut() {
+            let (r, g, b) = self.nearest((pixel[0], pixel[1], pixel[2]));
+            *pixel = Rgba([r, g, b, u8::MAX]);
+        }
+        result
+    }
+
+    fn nearest_index(&self, color: U8ColorTriple) -> usize {
+        nearest_in(&self.colors, color)
+    }
+}
+
+/// Squared distance between two colors in RGB space.
+fn distance(a: U8ColorTriple, b: U8ColorTriple) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Index of the color in `colors` closest to `color`; 0 if there are none.
+fn nearest_in(colors: &[U8ColorTriple], color: U8ColorTriple) -> usize {
+    (0..colors.len()).min_by_key(|i| distance(colors[*i], color)).unwrap_or(0)
+}
+
+fn nearest_color(colors: &[U8ColorTriple], color: U8ColorTriple) -> U8ColorTriple {
+    colors[nearest_in(colors, color)]
+}
+
+fn mean_color<I: Iterator<Item = U8ColorTriple>>(colors: I) -> U8ColorTriple {
+    let (mut r, mut g, mut b, mut n) = (0u64, 0u64, 0u64, 0u64);
+    for color in colors {
+        (r, g, b, n) = (r + color.0 as u64, g + color.1 as u64, b + color.2 as u64, n + 1);
+    }
+    let mean = |sum: u64| ((sum + n / 2) / n.max(1)) as u8;
+    (mean(r), mean(g), mean(b))
+}
+
+/// The colors of a cluster-colored frame that make up the floor sample
+/// in its bottom-center region.
+pub fn floor_colors(clustered: &RgbaImage) -> HashSet<U8ColorTriple> {
+    let (width, height) = clustered.dimensions();
+    let sample_width = (width / FLOOR_SAMPLE_WIDTH_DIVISOR).max(1).min(width);
+    let sample_height = (height / FLOOR_SAMPLE_HEIGHT_DIVISOR).max(1).min(height);
+    let left = (width - sample_width) / 2;
+
+    let mut counts: HashMap<U8ColorTriple, u32> = HashMap::new();
+    for y in height - sample_height..height {
+        for x in left..left + sample_width {
+            let p = clustered.get_pixel(x, y);
+            *counts.entry((p[0], p[1], p[2])).or_insert(0) += 1;
+        }
+    }
+    let min_count = MIN_FLOOR_SHARE * (sample_width * sample_height) as f64;
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count as f64 >= min_count)
+        .map(|(color, _)| color)
+        .collect()
+}
+
+/// Free-space mask of a cluster-colored frame: 255 where a pixel has one of the
+/// floor colors, 0 elsewhere.
+pub fn free_space_mask(clustered: &RgbaImage) -> GrayImage {
+    let floor = floor_colors(clustered);
+    GrayImage::from_fn(clustered.width(), clustered.height(), |x, y| {
+        let p = clustered.get_pixel(x, y);
+        if floor.contains(&(p[0], p[1], p[2])) {
+            Luma([u8::MAX])
+        } else {
+            Luma([0])
+        }
+    })
+}
+
+/// For each column of `mask`, how many pixels of free space lie above the bottom
+/// row before the first obstacle pixel.
+pub fn column_free_space(mask: &GrayImage) -> Vec<u32> {
+    let (width, height) = mask.dimensions();
+    (0..width)
+        .map(|x| {
+            (0..height)
+                .rev()
+                .take_while(|y| mask.get_pixel(x, *y)[0] > 0)
+                .count() as u32
+        })
+        .collect()
+}
+
+/// A 4-connected region of a single color.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Blob {
+    pub color: U8ColorTriple,
+    pub area: u32,
+    pub centroid: (f64, f64),
+    /// Inclusive bounding box corners, `(x, y)`.
+    pub top_left: (u32, u32),
+    pub bottom_right: (u32, u32),
+}
+
+impl Blob {
+    /// The blob as found in an image downsampled by `factor`, mapped back to
+    /// full resolution: every pixel stands for a `factor` x `factor` block, and
+    /// the centroid moves to the center of its block like `image_proc::to_full_scale`.
+    pub fn to_full_scale(&self, factor: u32) -> Blob {
+        let factor = factor.max(1);
+        let centroid = |c: f64| c * factor as f64 + (factor - 1) as f64 / 2.0;
+        Blob {
+            color: self.color,
+            area: self.area * factor * factor,
+            centroid: (centroid(self.centroid.0), centroid(self.centroid.1)),
+            top_left: (self.top_left.0 * factor, self.top_left.1 * factor),
+            bottom_right: ((self.bottom_right.0 + 1) * factor - 1, (self.bottom_right.1 + 1) * factor - 1),
+        }
+    }
+}
+
+/// Connected-component labeling of a cluster-colored frame. Returns every blob
+/// of at least `min_area` pixels, largest first.
+pub fn color_blobs(clustered: &RgbaImage, min_area: u32) -> Vec<Blob> {
+    let (width, height) = clustered.dimensions();
+    connected_components(width, height, min_area, |x, y| {
+        let p = clustered.get_pixel(x, y);
+        (p[0], p[1], p[2])
+    })
+}
+
+/// Blobs of nonzero pixels in `mask`, largest first. Each blob's color is its
+/// mask value in all three channels.
+pub fn mask_blobs(mask: &GrayImage, min_area: u32) -> Vec<Blob> {
+    let (width, height) = mask.dimensions();
+    let mut blobs = connected_components(width, height, min_area, |x, y| {
+        let v = mask.get_pixel(x, y)[0];
+        (v, v, v)
+    });
+    blobs.retain(|blob| blob.color.0 > 0);
+    blobs
+}
+
+fn connected_components<F: Fn(u32, u32) -> U8ColorTriple>(
+    width: u32,
+    height: u32,
+    min_area: u32,
+    color_at: F,
+) -> Vec<Blob> {
+    let mut visited = vec![false; (width * height) as usize];
+    let mut blobs = vec![];
+    let mut pending = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            if visited[(y * width + x) as usize] {
+                continue;
+            }
+            let color = color_at(x, y);
+            visited[(y * width + x) as usize] = true;
+            pending.push((x, y));
+            let mut area = 0;
+            let (mut sum_x, mut sum_y) = (0u64, 0u64);
+            let (mut top_left, mut bottom_right) = ((x, y), (x, y));
+            while let Some((px, py)) = pending.pop() {
+                area += 1;
+                sum_x += px as u64;
+                sum_y += py as u64;
+                top_left = (top_left.0.min(px), top_left.1.min(py));
+                bottom_right = (bottom_right.0.max(px), bottom_right.1.max(py));
+                let neighbors = [
+                    (px.wrapping_sub(1), py),
+                    (px + 1, py),
+                    (px, py.wrapping_sub(1)),
+                    (px, py + 1),
+                ];
+                for (nx, ny) in neighbors {
+                    if nx < width && ny < height {
+                        let i = (ny * width + nx) as usize;
+                        if !visited[i] && color_at(nx, ny) == color {
+                            visited[i] = true;
+                            pending.push((nx, ny));
+                        }
+                    }
+                }
+            }
+            if area >= min_area {
+                let centroid = (sum_x as f64 / area as f64, sum_y as f64 / area as f64);
+                blobs.push(Blob { color, area, centroid, top_left, bottom_right });
+            }
+        }
+    }
+    blobs.sort_by_key(|blob| Reverse(blob.area));
+    blobs
+}
@@ -0,0 +1,271 @@
+use crate::api::{
+    next_robot_command, parse_sensor_data, process_sensor_data, robot_command_wire, MotorData, RobotCommand,
+    RobotSensorPosition, SensorData, BOT,
+};
+use crate::control::Controller;
+use flutter_rust_bridge::support::lazy_static;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::FRAC_PI_2;
+
+/// A straight wall segment; coordinates are in sonar distance units.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Wall {
+    pub start: (f64, f64),
+    pub end: (f64, f64),
+}
+
+impl Wall {
+    pub fn new(start: (f64, f64), end: (f64, f64)) -> Self {
+        Self { start, end }
+    }
+
+    /// The four walls of a `width` x `height` room with a corner at the origin.
+    pub fn room(width: f64, height: f64) -> Vec<Wall> {
+        let corners = [(0.0, 0.0), (width, 0.0), (width, height), (0.0, height)];
+        (0..corners.len())
+            .map(|i| Wall::new(corners[i], corners[(i + 1) % corners.len()]))
+            .collect()
+    }
+
+    /// Distance along the ray from `origin` at angle `heading` to this wall, if they meet.
+    fn ray_distance(&self, origin: (f64, f64), heading: f64) -> Option<f64> {
+        let (dx, dy) = (heading.cos(), heading.sin());
+        let (ex, ey) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        let denominator = dx * ey - dy * ex;
+        if denominator.abs() < 1e-12 {
+            return None;
+        }
+        let (ox, oy) = (self.start.0 - origin.0, self.start.1 - origin.1);
+        let along_ray = (ox * ey - oy * ex) / denominator;
+        let along_wall = (ox * dy - oy * dx) / denominator;
+        (along_ray >= 0.0 && (0.0..=1.0).contains(&along_wall)).then_some(along_ray)
+    }
+
+    fn distance_to(&self, point: (f64, f64)) -> f64 {
+        let (ex, ey) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        let (px, py) = (point.0 - self.start.0, point.1 - self.start.1);
+        let length_squared = ex * ex + ey * ey;
+        let t = if length_squared == 0.0 { 0.0 } else { ((px * ex + py * ey) / length_squared).clamp(0.0, 1.0) };
+        ((px - t * ex).powi(2) + (py - t * ey).powi(2)).sqrt()
+    }
+}
+
+/// Encoder ticks per packet interval for each unit of wheel speed.
+const TICKS_PER_SPEED: f64 = 0.2;
+
+/// `BOT`'s distance per encoder tick and distance between the wheels, measured
+/// by feeding encoder counts to the same position estimate `process_sensor_data`
+/// uses, so the simulated robot moves exactly as the estimate expects.
+fn bot_geometry() -> (f64, f64) {
+    let position_after = |counts: &[(i64, i64)]| {
+        let mut pos = RobotSensorPosition::new(BOT);
+        for (left_count, right_count) in counts.iter().copied() {
+            pos.motor_update(MotorData { left_count, right_count, ..MotorData::default() });
+        }
+        pos.get_pos().position()
+    };
+    let ticks = 1000;
+    let (x, y) = position_after(&[(0, 0), (ticks, ticks)]);
+    let distance_per_tick = x.hypot(y) / ticks as f64;
+    // A small spin in place, then a straight run: the run's direction is the
+    // angle turned, whatever units the estimate keeps its heading in.
+    let spin = 10;
+    let (x, y) = position_after(&[(0, 0), (-spin, spin), (ticks - spin, ticks + spin)]);
+    let axle_width = 2.0 * spin as f64 * distance_per_tick / y.atan2(x);
+    (distance_per_tick, axle_width)
+}
+
+lazy_static! {
+    static ref BOT_GEOMETRY: (f64, f64) = bot_geometry();
+}
+
+/// Where the simulated robot is. Heading is in radians, counterclockwise from +x.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SimPose {
+    pub x: f64,
+    pub y: f64,
+    pub heading: f64,
+}
+
+/// Sensor imperfections. Sonar readings are off by up to `sonar` distance units;
+/// each step's encoder ticks are off by up to `encoder` as a fraction of the
+/// true wheel travel. Both are uniformly distributed.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SimNoise {
+    pub sonar: f64,
+    pub encoder: f64,
+}
+
+/// Differential-drive robot with three sonars (front, left, right) and wheel
+/// encoders among straight walls. Each `step` is one sensor packet interval.
+/// Without noise, or with a fixed seed, every run is the same.
+///
+/// The geometry fields start out as the `BOT` geometry that `process_sensor_data`
+/// estimates with.
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    pub walls: Vec<Wall>,
+    pub pose: SimPose,
+    pub left_speed: i64,
+    pub right_speed: i64,
+    /// Speed of both wheels after `Start`.
+    pub cruise_speed: i64,
+    /// Distance between the wheels.
+    pub axle_width: f64,
+    /// Distance a wheel travels per step for each unit of speed.
+    pub distance_per_speed: f64,
+    /// Wheel travel per encoder tick.
+    pub distance_per_tick: f64,
+    /// Reading reported when a sonar hears no echo.
+    pub sonar_range: f64,
+    pub noise: SimNoise,
+    left_ticks: f64,
+    right_ticks: f64,
+    rng: StdRng,
+}
+
+impl Simulation {
+    pub fn new(walls: Vec<Wall>, pose: SimPose) -> Self {
+        let (distance_per_tick, axle_width) = *BOT_GEOMETRY;
+        Self {
+            walls,
+            pose,
+            left_speed: 0,
+            right_speed: 0,
+            cruise_speed: 100,
+            axle_width,
+            distance_per_speed: TICKS_PER_SPEED * distance_per_tick,
+            distance_per_tick,
+            sonar_range: 200.0,
+            noise: SimNoise::default(),
+            left_ticks: 0.0,
+            right_ticks: 0.0,
+            rng: StdRng::seed_from_u64(0),
+        }
+    }
+
+    pub fn with_noise(mut self, noise: SimNoise, seed: u64) -> Self {
+        self.noise = noise;
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Sets the wheel speeds as the robot would; `Start` drives straight ahead at
+    /// `cruise_speed`.
+    pub fn apply(&mut self, command: RobotCommand) {
+        let (left, right) = match command {
+            RobotCommand::NoOp => return,
+            RobotCommand::Start => (self.cruise_speed, self.cruise_speed),
+            RobotCommand::Stop => (0, 0),
+            RobotCommand::Drive { speed } => (speed, speed),
+            RobotCommand::Turn { speed } => (speed, -speed),
+            RobotCommand::SetSpeeds { left, right } => (left, right),
+        };
+        self.left_speed = left;
+        self.right_speed = right;
+    }
+
+    pub fn step(&mut self) {
+        let left = self.left_speed as f64 * self.distance_per_speed;
+        let right = self.right_speed as f64 * self.distance_per_speed;
+        self.left_ticks += self.measured(left) / self.distance_per_tick;
+        self.right_ticks += self.measured(right) / self.distance_per_tick;
+        let forward = (left + right) / 2.0;
+        let rotation = (right - left) / self.axle_width;
+        let mid_heading = self.pose.heading + rotation / 2.0;
+        self.pose.x += forward * mid_heading.cos();
+        self.pose.y += forward * mid_heading.sin();
+        self.pose.heading += rotation;
+    }
+
+    /// Distance to the nearest wall straight ahead of the robot when turned by `offset`.
+    pub fn sonar(&self, offset: f64) -> f64 {
+        self.walls
+            .iter()
+            .filter_map(|wall| wall.ray_distance((self.pose.x, self.pose.y), self.pose.heading + offset))
+            .fold(self.sonar_range, f64::min)
+    }
+
+    /// Encoder counts since the simulation started.
+    pub fn encoder_counts(&self) -> (i64, i64) {
+        (self.left_ticks.round() as i64, self.right_ticks.round() as i64)
+    }
+
+    fn measured(&mut self, travel: f64) -> f64 {
+        if self.noise.encoder > 0.0 {
+            travel * (1.0 + self.rng.gen_range(-self.noise.encoder..=self.noise.encoder))
+        } else {
+            travel
+        }
+    }
+
+    fn sonar_reading(&mut self, offset: f64) -> i64 {
+        let noise = if self.noise.sonar > 0.0 {
+            self.rng.gen_range(-self.noise.sonar..=self.noise.sonar)
+        } else {
+            0.0
+        };
+        (self.sonar(offset) + noise).clamp(0.0, self.sonar_range).round() as i64
+    }
+
+    pub fn sensors(&mut self) -> SensorData {
+        let (left_count, right_count) = self.encoder_counts();
+        SensorData {
+            sonar_front: self.sonar_reading(0.0),
+            sonar_left: self.sonar_reading(FRAC_PI_2),
+            sonar_right: self.sonar_reading(-FRAC_PI_2),
+            left_count,
+            right_count,
+            left_speed: self.left_speed,
+            right_speed: self.right_speed,
+        }
+    }
+
+    /// The packet the robot would send: the format `parse_sensor_data` reads.
+    pub fn sensor_string(&mut self) -> String {
+        let s = self.sensors();
+        format!(
+            "SF:{};SL:{};SR:{};LC:{};RC:{};LS:{};RS:{}",
+            s.sonar_front, s.sonar_left, s.sonar_right, s.left_count, s.right_count, s.left_speed, s.right_speed
+        )
+    }
+
+    pub fn nearest_wall_distance(&self) -> f64 {
+        self.walls
+            .iter()
+            .map(|wall| wall.distance_to((self.pose.x, self.pose.y)))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Drives the robot through the native API for `steps` packets, as the phone
+    /// would: each sensor string goes to `process_sensor_data` and the active
+    /// controller, whose command crosses the wire format before it is applied.
+    /// Returns the true pose each packet was sent from, with the position
+    /// estimate that packet produced.
+    pub fn run_native(&mut self, steps: usize) -> Vec<(SimPose, String)> {
+        (0..steps)
+            .map(|_| {
+                let pose = self.pose;
+                let packet = self.sensor_string();
+                let estimate = process_sensor_data(packet.clone());
+                let command = robot_command_wire(next_robot_command(parse_sensor_data(packet)));
+                self.apply(RobotCommand::from_wire(&command).unwrap_or(RobotCommand::NoOp));
+                self.step();
+                (pose, estimate)
+            })
+            .collect()
+    }
+
+    /// Runs `controller` for `steps` packets, returning the pose after each one.
+    pub fn run(&mut self, controller: &mut dyn Controller, steps: usize) -> Vec<SimPose> {
+        (0..steps)
+            .map(|_| {
+                let command = controller.next_command(&self.sensors());
+                self.apply(command);
+                self.step();
+                self.pose
+            })
+            .collect()
+    }
+}
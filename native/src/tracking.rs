@@ -0,0 +1,109 @@
+use crate::api::{ColorSpace, ColorTarget};
+use crate::image_proc::U8ColorTriple;
+use crate::segmentation::{mask_blobs, Blob};
+use image::{GrayImage, Luma, RgbaImage};
+
+/// Once the target fills this share of the frame, the robot has arrived and stops.
+const ARRIVAL_SHARE: f64 = 0.25;
+
+/// Decides whether a pixel matches a `ColorTarget`.
+#[derive(Copy, Clone, Debug)]
+pub struct ColorMatcher {
+    target: U8ColorTriple,
+    target_hsv: (f32, f32, f32),
+    tolerance: i64,
+    space: ColorSpace,
+    min_area: u32,
+}
+
+impl ColorMatcher {
+    pub fn new(target: &ColorTarget) -> Self {
+        let clamp = |c: i64| c.clamp(0, u8::MAX as i64) as u8;
+        let color = (clamp(target.red), clamp(target.green), clamp(target.blue));
+        Self {
+            target: color,
+            target_hsv: rgb_hsv(color),
+            tolerance: target.tolerance.max(0),
+            space: target.space,
+            min_area: target.min_area.clamp(1, u32::MAX as i64) as u32,
+        }
+    }
+
+    /// The smallest region, in full-resolution pixels, that counts as the target.
+    pub fn min_area(&self) -> u32 {
+        self.min_area
+    }
+
+    /// In `Rgb`, each channel may differ from the target by at most the tolerance.
+    /// In `Hsv`, the hue may differ by at most the tolerance in degrees, and the
+    /// saturation and value must each be at least half of the target's.
+    pub fn matches(&self, color: U8ColorTriple) -> bool {
+        match self.space {
+            ColorSpace::Rgb => {
+                let diff = |a: u8, b: u8| (a as i64 - b as i64).abs();
+                diff(color.0, self.target.0) <= self.tolerance
+                    && diff(color.1, self.target.1) <= self.tolerance
+                    && diff(color.2, self.target.2) <= self.tolerance
+            }
+            ColorSpace::Hsv => {
+                let (hue, saturation, value) = rgb_hsv(color);
+                let (target_hue, target_saturation, target_value) = self.target_hsv;
+                let hue_diff = (hue - target_hue).abs();
+                hue_diff.min(360.0 - hue_diff) <= self.tolerance as f32
+                    && saturation >= target_saturation / 2.0
+                    && value >= target_value / 2.0
+            }
+        }
+    }
+
+    /// 255 wherever a pixel of `rgba` matches, 0 elsewhere.
+    pub fn mask(&self, rgba: &RgbaImage) -> GrayImage {
+        GrayImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+            let p = rgba.get_pixel(x, y);
+            if self.matches((p[0], p[1], p[2])) {
+                Luma([u8::MAX])
+            } else {
+                Luma([0])
+            }
+        })
+    }
+
+    /// The largest region of matching pixels, if it has at least `min_area` pixels.
+    pub fn largest_region(&self, rgba: &RgbaImage, min_area: u32) -> Option<Blob> {
+        mask_blobs(&self.mask(rgba), min_area).first().copied()
+    }
+}
+
+/// Hue in degrees (`0.0..360.0`), saturation and value in `0.0..=1.0`.
+pub fn rgb_hsv(color: U8ColorTriple) -> (f32, f32, f32) {
+    let (r, g, b) = (color.0 as f32 / 255.0, color.1 as f32 / 255.0, color.2 as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+    (hue, saturation, max)
+}
+
+/// Left and right wheel speeds that steer toward `region` in a `width` x `height`
+/// frame: `cruise` straight ahead, with up to `turn` added to one wheel and taken
+/// from the other in proportion to how far off-center the region is. Stops when
+/// there is no region or the region is close enough to fill `ARRIVAL_SHARE` of the frame.
+pub fn steer_toward(region: Option<&Blob>, width: u32, height: u32, cruise: i64, turn: i64) -> (i64, i64) {
+    match region {
+        Some(blob) if width > 0 && (blob.area as f64) < ARRIVAL_SHARE * (width * height) as f64 => {
+            let offset = 2.0 * blob.centroid.0 / (width - 1).max(1) as f64 - 1.0;
+            let correction = (turn as f64 * offset).round() as i64;
+            (cruise + correction, cruise - correction)
+        }
+        _ => (0, 0),
+    }
+}
@@ -0,0 +1,106 @@
+use common::example;
+use native::api::{AugmentSettings, DartImage, LabeledImage};
+use native::augmentation::{augment, jittered, Jitter};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+/// A 4x3 photo whose red channel counts up across the rows.
+fn photo() -> DartImage {
+    let bytes = (0..12u8).flat_map(|i| [i * 20, 100, 200, u8::MAX]).collect();
+    DartImage { bytes, width: 4, height: 3 }
+}
+
+fn examples() -> Vec<LabeledImage> {
+    vec![
+        example("left", photo()),
+        example("right", jittered(&photo(), &flipped(), &mut rng())),
+    ]
+}
+
+fn flipped() -> Jitter {
+    Jitter { flip: true, ..Jitter::none() }
+}
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0)
+}
+
+fn reds(image: &DartImage) -> Vec<u8> {
+    image.bytes.chunks_exact(4).map(|p| p[0]).collect()
+}
+
+#[test]
+fn keeps_originals_and_labels() {
+    let settings = AugmentSettings { copies: 3, ..AugmentSettings::default() };
+    let augmented = augment(&examples(), &settings);
+    assert_eq!(augmented.len(), 8);
+    assert_eq!(augmented[0].image.bytes, photo().bytes);
+    let labels: Vec<&str> = augmented.iter().map(|e| e.label.as_str()).collect();
+    assert_eq!(labels, ["left", "right", "left", "left", "left", "right", "right", "right"]);
+    for example in augmented.iter() {
+        assert_eq!((example.image.width, example.image.height), (4, 3));
+        assert_eq!(example.image.bytes.len(), 48);
+        assert!(example.image.bytes.chunks_exact(4).all(|p| p[3] == u8::MAX));
+    }
+}
+
+#[test]
+fn seed_makes_it_repeatable() {
+    let bytes = |seed| -> Vec<Vec<u8>> {
+        let settings = AugmentSettings { seed, ..AugmentSettings::default() };
+        augment(&examples(), &settings).into_iter().map(|e| e.image.bytes).collect()
+    };
+    assert_eq!(bytes(1), bytes(1));
+    assert_ne!(bytes(1), bytes(2));
+}
+
+#[test]
+fn no_jitter_changes_nothing() {
+    assert_eq!(jittered(&photo(), &Jitter::none(), &mut rng()).bytes, photo().bytes);
+    let settings = AugmentSettings {
+        copies: 2,
+        flip: false,
+        max_rotation_degrees: 0.0,
+        max_shift: 0.0,
+        max_brightness: 0.0,
+        max_contrast: 0.0,
+        max_noise: 0.0,
+        seed: 5,
+    };
+    let originals = examples();
+    for example in augment(&originals, &settings).iter() {
+        let original = originals.iter().find(|o| o.label == example.label).unwrap();
+        assert_eq!(example.image.bytes, original.image.bytes);
+    }
+}
+
+#[test]
+fn flip_mirrors_rows() {
+    let image = jittered(&photo(), &flipped(), &mut rng());
+    assert_eq!(reds(&image), [60, 40, 20, 0, 140, 120, 100, 80, 220, 200, 180, 160]);
+}
+
+#[test]
+fn shift_repeats_the_edge() {
+    let image = jittered(&photo(), &Jitter { shift: (1.0, 0.0), ..Jitter::none() }, &mut rng());
+    assert_eq!(reds(&image), [0, 0, 20, 40, 80, 80, 100, 120, 160, 160, 180, 200]);
+}
+
+#[test]
+fn brightness_and_contrast_clamp() {
+    let brighter = jittered(&photo(), &Jitter { brightness: 60.0, ..Jitter::none() }, &mut rng());
+    assert_eq!(&brighter.bytes[..4], &[60, 160, 255, u8::MAX]);
+    let flatter = jittered(&photo(), &Jitter { contrast: -0.5, ..Jitter::none() }, &mut rng());
+    assert_eq!(&flatter.bytes[..4], &[64, 114, 164, u8::MAX]);
+}
+
+#[test]
+fn noise_stays_within_its_limit() {
+    let noisy = jittered(&photo(), &Jitter { noise: 5.0, ..Jitter::none() }, &mut rng());
+    for (a, b) in noisy.bytes.iter().zip(photo().bytes.iter()) {
+        assert!(a.abs_diff(*b) <= 5);
+    }
+    assert_ne!(noisy.bytes, photo().bytes);
+}
@@ -0,0 +1,32 @@
+//! Helpers shared by the integration tests; each test file uses only some of them.
+#![allow(dead_code)]
+
+use image::{Rgba, RgbaImage};
+use native::api::{DartImage, ImageData, LabeledImage};
+use native::image_proc;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+static GLOBAL_STATE: Mutex<()> = Mutex::new(());
+
+/// A path in the temp directory that no other test process uses.
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("vision_bot_{}_{name}", std::process::id()))
+}
+
+pub fn example(label: &str, image: DartImage) -> LabeledImage {
+    LabeledImage { label: label.to_owned(), image }
+}
+
+/// A `Bgra8888` frame whose pixel at `(x, y)` has the RGBA color `pixel(x, y)`.
+pub fn bgra_frame(width: u32, height: u32, pixel: impl Fn(u32, u32) -> [u8; 4]) -> ImageData {
+    image_proc::bgra_frame(&RgbaImage::from_fn(width, height, |x, y| Rgba(pixel(x, y))))
+}
+
+/// The API keeps its settings, models, controller and recorder in process-wide
+/// globals, while the tests of a file share one process and run in parallel. A
+/// test that changes a global holds this lock for as long as it relies on it,
+/// and puts the default back before letting go.
+pub fn global_state() -> MutexGuard<'static, ()> {
+    GLOBAL_STATE.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,65 @@
+use native::api::{next_robot_command, queue_robot_command, RobotCommand, SensorData};
+use native::control::{CommandQueue, Controller, Manual};
+
+mod common;
+
+const COMMANDS: [RobotCommand; 6] = [
+    RobotCommand::NoOp,
+    RobotCommand::Start,
+    RobotCommand::Stop,
+    RobotCommand::Drive { speed: -40 },
+    RobotCommand::Turn { speed: 25 },
+    RobotCommand::SetSpeeds { left: 80, right: -80 },
+];
+
+#[test]
+fn wire_format() {
+    let wire: Vec<String> = COMMANDS.iter().map(|c| c.to_wire()).collect();
+    assert_eq!(wire, ["None", "Start", "Stop", "Drive:-40", "Turn:25", "LS:80;RS:-80"]);
+}
+
+#[test]
+fn wire_round_trip() {
+    for command in COMMANDS {
+        assert_eq!(RobotCommand::from_wire(&command.to_wire()), Some(command));
+    }
+    for bad in ["", "Go", "start", "Stop ", "Drive:", "Turn:x", "LS:1", "LS:1;XS:2", "RS:1;LS:2"] {
+        assert_eq!(RobotCommand::from_wire(bad), None, "{bad}");
+    }
+}
+
+#[test]
+fn queue_is_first_in_first_out() {
+    let mut queue = CommandQueue::default();
+    for command in COMMANDS {
+        queue.push(command);
+    }
+    let drained: Vec<RobotCommand> = std::iter::from_fn(|| queue.pop()).collect();
+    assert_eq!(drained, COMMANDS);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn newer_speeds_replace_queued_ones() {
+    let mut queue = CommandQueue::default();
+    queue.push_speeds(10, 20);
+    queue.push_speeds(30, 40);
+    queue.push(RobotCommand::Stop);
+    queue.push_speeds(50, 60);
+    assert_eq!(queue.pop(), Some(RobotCommand::SetSpeeds { left: 30, right: 40 }));
+    assert_eq!(queue.pop(), Some(RobotCommand::Stop));
+    assert_eq!(queue.pop(), Some(RobotCommand::SetSpeeds { left: 50, right: 60 }));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn queued_commands_precede_the_controller() {
+    let _globals = common::global_state();
+    let sensors = SensorData::default();
+    assert_eq!(Manual.next_command(&sensors), RobotCommand::NoOp);
+    queue_robot_command(RobotCommand::Start);
+    queue_robot_command(RobotCommand::Drive { speed: 60 });
+    assert_eq!(next_robot_command(sensors), RobotCommand::Start);
+    assert_eq!(next_robot_command(sensors), RobotCommand::Drive { speed: 60 });
+    assert_eq!(next_robot_command(sensors), RobotCommand::NoOp);
+}
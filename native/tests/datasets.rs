@@ -0,0 +1,68 @@
+use image::{Rgba, RgbaImage};
+use native::datasets::{archive_images, export_project, import_project, read_manifest, write_manifest, ManifestEntry};
+use native::projects::{labeled_images, project_dir};
+use std::fs;
+use std::path::Path;
+
+mod common;
+
+fn save_photo(dir: &Path, project: &str, label: &str, name: &str, color: [u8; 3]) {
+    let label_dir = project_dir(dir, project).join(label);
+    fs::create_dir_all(&label_dir).unwrap();
+    let img = RgbaImage::from_pixel(5, 2, Rgba([color[0], color[1], color[2], u8::MAX]));
+    img.save(label_dir.join(name)).unwrap();
+}
+
+fn entry(label: &str, filename: &str) -> ManifestEntry {
+    ManifestEntry { label: label.to_owned(), filename: filename.to_owned(), width: 5, height: 2, modified: 0 }
+}
+
+#[test]
+fn export_then_import_copies_the_project() {
+    let dir = common::temp_path("round_trip");
+    fs::create_dir_all(&dir).unwrap();
+    save_photo(&dir, "proj1", "left", "ph_1.png", [200, 0, 0]);
+    save_photo(&dir, "proj1", "left", "ph_2.png", [190, 10, 0]);
+    save_photo(&dir, "proj1", "right", "ph_3.png", [0, 0, 200]);
+    let archive = dir.join("proj1.zip");
+
+    let exported = export_project(&dir, "proj1", &archive).unwrap();
+    let imported = import_project(&dir, "copy", &archive).unwrap();
+    let original = labeled_images(project_dir(&dir, "proj1")).unwrap();
+    let copy = labeled_images(project_dir(&dir, "copy")).unwrap();
+    let from_archive = archive_images(&archive).unwrap();
+    let photo = fs::read(project_dir(&dir, "copy").join("left/ph_2.png")).unwrap();
+    let original_photo = fs::read(project_dir(&dir, "proj1").join("left/ph_2.png")).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(exported, imported);
+    let names: Vec<(&str, &str)> = exported.iter().map(|e| (e.label.as_str(), e.filename.as_str())).collect();
+    assert_eq!(names, [("left", "ph_1.png"), ("left", "ph_2.png"), ("right", "ph_3.png")]);
+    assert!(exported.iter().all(|e| (e.width, e.height) == (5, 2) && e.modified > 0));
+    assert_eq!(photo, original_photo);
+    for examples in [copy, from_archive] {
+        assert_eq!(examples.len(), original.len());
+        for (a, b) in examples.iter().zip(original.iter()) {
+            assert_eq!(a.label, b.label);
+            assert_eq!(a.image.bytes, b.image.bytes);
+        }
+    }
+}
+
+#[test]
+fn manifest_round_trips_awkward_names() {
+    let entries = vec![entry("left, slowly", "ph_1.png"), entry("say \"hi\"", "ph 2.png")];
+    let csv = write_manifest(&entries);
+    assert!(csv.starts_with("label,filename,width,height,modified\n"));
+    assert_eq!(read_manifest(&csv).unwrap(), entries);
+}
+
+#[test]
+fn manifest_rejects_paths_outside_the_project() {
+    for row in ["../evil,ph.png,5,2,0", "left,../../ph.png,5,2,0", "left,sub/ph.png,5,2,0", ",ph.png,5,2,0"] {
+        let csv = format!("label,filename,width,height,modified\n{row}\n");
+        assert!(read_manifest(&csv).is_err(), "{row}");
+    }
+    assert!(read_manifest("left,ph.png,5,2,0\n").is_err());
+    assert!(read_manifest("label,filename,width,height,modified\nleft,ph.png,5\n").is_err());
+}
@@ -0,0 +1,64 @@
+use common::example;
+use native::api::{find_duplicates, DartImage};
+use native::duplicates::{self, pixel_distance};
+
+mod common;
+
+fn gray(level: u8, width: i64) -> DartImage {
+    DartImage { bytes: vec![level; width as usize * 2 * 4], width, height: 2 }
+}
+
+#[test]
+fn pixel_distance_is_per_byte() {
+    assert_eq!(pixel_distance(&gray(10, 3), &gray(10, 3)), Some(0.0));
+    assert_eq!(pixel_distance(&gray(10, 3), &gray(14, 3)), Some(4.0));
+    let mut one_off = gray(10, 3);
+    one_off.bytes[0] = 34;
+    assert_eq!(pixel_distance(&gray(10, 3), &one_off), Some(24f64.sqrt()));
+    assert_eq!(pixel_distance(&gray(10, 3), &gray(10, 4)), None);
+}
+
+#[test]
+fn chains_of_near_duplicates_form_one_group() {
+    let examples = vec![
+        example("left", gray(100, 3)),
+        example("left", gray(200, 3)),
+        example("left", gray(103, 3)),
+        example("left", gray(106, 3)),
+        example("left", gray(201, 3)),
+        example("left", gray(150, 3)),
+    ];
+    let found = duplicates::find_duplicates(&examples, 3.0, pixel_distance);
+    assert_eq!(found.groups, vec![vec![0, 2, 3], vec![1, 4]]);
+    assert!(found.conflicts.is_empty());
+}
+
+#[test]
+fn different_labels_conflict_instead_of_grouping() {
+    let examples = vec![
+        example("left", gray(100, 3)),
+        example("right", gray(101, 3)),
+        example("left", gray(100, 4)),
+    ];
+    let found = duplicates::find_duplicates(&examples, 3.0, pixel_distance);
+    assert!(found.groups.is_empty());
+    assert_eq!(found.conflicts, vec![(0, 1, 1.0)]);
+}
+
+#[test]
+fn report_names_and_labels_its_findings() {
+    let examples = vec![
+        example("left", gray(100, 3)),
+        example("forward", gray(50, 3)),
+        example("forward", gray(51, 3)),
+        example("right", gray(100, 3)),
+    ];
+    let report = find_duplicates(examples, 2.0);
+    assert_eq!(report.names, ["left #0", "forward #1", "forward #2", "right #3"]);
+    assert_eq!(report.groups.len(), 1);
+    assert_eq!(report.groups[0].label, "forward");
+    assert_eq!(report.groups[0].members, [1, 2]);
+    assert_eq!(report.conflicts.len(), 1);
+    assert_eq!((report.conflicts[0].first, report.conflicts[0].second), (0, 3));
+    assert_eq!(report.conflicts[0].distance, 0.0);
+}
@@ -0,0 +1,110 @@
+use image::{GrayImage, Luma};
+use native::edges::{canny, gaussian_blur_5, sobel_magnitude, Gradients};
+
+const WIDTH: u32 = 12;
+const HEIGHT: u32 = 12;
+
+/// Dark left of column 5, `level(y)` from there on.
+fn step(level: impl Fn(u32) -> u8) -> GrayImage {
+    GrayImage::from_fn(WIDTH, HEIGHT, |x, y| Luma([if x < 5 { 0 } else { level(y) }]))
+}
+
+fn edge_columns(edges: &GrayImage, y: u32) -> Vec<u32> {
+    (0..edges.width()).filter(|x| edges.get_pixel(*x, y)[0] == u8::MAX).collect()
+}
+
+fn edge_count(edges: &GrayImage) -> usize {
+    edges.pixels().filter(|p| p[0] == u8::MAX).count()
+}
+
+#[test]
+fn sobel_responds_on_both_sides_of_a_step() {
+    let gradients = Gradients::sobel(&step(|_| 200));
+    for y in 0..HEIGHT as usize {
+        assert_eq!(gradients.magnitude(3, y), 0.0);
+        assert_eq!(gradients.magnitude(4, y), 800.0);
+        assert_eq!(gradients.magnitude(5, y), 800.0);
+        assert_eq!(gradients.magnitude(6, y), 0.0);
+        assert_eq!(gradients.direction(4, y), 0.0);
+    }
+    let magnitude = sobel_magnitude(&step(|_| 200));
+    assert_eq!(magnitude.get_pixel(4, 0)[0], 141);
+    assert_eq!(magnitude.get_pixel(0, 0)[0], 0);
+}
+
+#[test]
+fn sobel_measures_the_slope_of_a_ramp() {
+    let ramp = GrayImage::from_fn(WIDTH, HEIGHT, |_, y| Luma([y as u8 * 10]));
+    let gradients = Gradients::sobel(&ramp);
+    for y in 1..HEIGHT as usize - 1 {
+        for x in 0..WIDTH as usize {
+            assert_eq!(gradients.magnitude(x, y), 80.0);
+            assert_eq!(gradients.direction(x, y), std::f32::consts::FRAC_PI_2);
+        }
+    }
+    // Replicated borders halve the difference across the first and last rows.
+    assert_eq!(gradients.magnitude(0, 0), 40.0);
+}
+
+#[test]
+fn canny_edges_are_one_pixel_wide() {
+    let edges = canny(&step(|_| 200), 100.0, 300.0);
+    for y in 1..HEIGHT - 1 {
+        assert_eq!(edge_columns(&edges, y), [4], "row {y}");
+    }
+    // Non-maximum suppression skips the border.
+    assert!(edge_columns(&edges, 0).is_empty());
+    assert!(edge_columns(&edges, HEIGHT - 1).is_empty());
+}
+
+#[test]
+fn canny_thins_a_ramp_to_one_pixel_per_row() {
+    // A gradient of 160 everywhere but the replicated borders.
+    let ramp = GrayImage::from_fn(WIDTH, HEIGHT, |x, _| Luma([x as u8 * 20]));
+    let edges = canny(&ramp, 10.0, 30.0);
+    for y in 0..HEIGHT {
+        assert!(edge_columns(&edges, y).len() <= 1, "row {y}");
+    }
+    assert_eq!(edge_count(&canny(&ramp, 200.0, 300.0)), 0);
+}
+
+#[test]
+fn weak_edges_need_a_strong_neighbor() {
+    // Alone, a weak step never reaches the high threshold.
+    assert_eq!(edge_count(&canny(&step(|_| 60), 100.0, 300.0)), 0);
+    assert_eq!(edge_columns(&canny(&step(|_| 60), 100.0, 140.0), 6), [4]);
+
+    // The same weak step continuing a strong one is followed from it... Both
+    // sides change by the same amount, so the gradient along the step stays
+    // horizontal where the contrast drops.
+    let fading = GrayImage::from_fn(WIDTH, HEIGHT, |x, y| {
+        Luma([match (x < 5, y < 6) {
+            (true, true) => 0,
+            (true, false) => 70,
+            (false, true) => 200,
+            (false, false) => 130,
+        }])
+    });
+    let edges = canny(&fading, 100.0, 300.0);
+    for y in 7..HEIGHT - 1 {
+        assert_eq!(edge_columns(&edges, y), [4], "row {y}");
+    }
+    // ...unless it is below the low threshold.
+    let edges = canny(&fading, 200.0, 300.0);
+    for y in 7..HEIGHT - 1 {
+        assert!(edge_columns(&edges, y).is_empty(), "row {y}");
+    }
+    assert_eq!(edge_columns(&edges, 2), [4]);
+}
+
+#[test]
+fn tiny_images_have_no_edges() {
+    for (width, height) in [(0, 0), (1, 1), (2, 1), (1, 3)] {
+        let img = GrayImage::from_pixel(width, height, Luma([90]));
+        assert_eq!(gaussian_blur_5(&img), img);
+        assert_eq!(sobel_magnitude(&img).dimensions(), (width, height));
+        let edges = canny(&img, 1.0, 2.0);
+        assert_eq!(edges.dimensions(), (width, height));
+        assert_eq!(edge_count(&edges), 0);
+    }
+}
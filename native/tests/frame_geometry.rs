@@ -0,0 +1,169 @@
+use image::imageops::{crop_imm, flip_horizontal, rotate180, rotate270, rotate90};
+use image::{Rgba, RgbaImage};
+use native::api::{FrameTransform, ImageData, PixelFormat, Rotation};
+use native::image_proc::{frame_size, set_frame_transform, FrameGeometry};
+
+mod common;
+
+const ROTATIONS: [Rotation; 4] = [Rotation::Rotate0, Rotation::Rotate90, Rotation::Rotate180, Rotation::Rotate270];
+const WIDTH: u32 = 5;
+const HEIGHT: u32 = 3;
+
+/// Every pixel a different color, so any misplaced pixel shows.
+fn sensor_pixel(x: u32, y: u32) -> [u8; 4] {
+    [x as u8 * 40, y as u8 * 80, (x + y * WIDTH) as u8, u8::MAX]
+}
+
+fn sensor_image() -> RgbaImage {
+    RgbaImage::from_fn(WIDTH, HEIGHT, |x, y| Rgba(sensor_pixel(x, y)))
+}
+
+/// The sensor image as a `Bgra8888` frame, with two bytes of row padding.
+fn sensor_frame() -> ImageData {
+    let mut frame = common::bgra_frame(WIDTH, HEIGHT, sensor_pixel);
+    frame.ys = frame.ys.chunks(WIDTH as usize * 4).flat_map(|row| row.iter().copied().chain([0, 0])).collect();
+    frame.y_row_stride += 2;
+    frame
+}
+
+fn transform(rotation: Rotation, mirror: bool, crop: (i64, i64, i64, i64)) -> FrameTransform {
+    let (crop_x, crop_y, crop_width, crop_height) = crop;
+    FrameTransform { rotation, mirror, crop_x, crop_y, crop_width, crop_height }
+}
+
+/// What the transform should produce, built with `image`'s own operations.
+fn expected(img: &RgbaImage, transform: FrameTransform) -> RgbaImage {
+    let rotated = match transform.rotation {
+        Rotation::Rotate0 => img.clone(),
+        Rotation::Rotate90 => rotate90(img),
+        Rotation::Rotate180 => rotate180(img),
+        Rotation::Rotate270 => rotate270(img),
+    };
+    let oriented = if transform.mirror { flip_horizontal(&rotated) } else { rotated };
+    if transform.crop_width > 0 && transform.crop_height > 0 {
+        let (x, y) = (transform.crop_x.max(0) as u32, transform.crop_y.max(0) as u32);
+        let x = x.min(oriented.width());
+        let y = y.min(oriented.height());
+        let width = (transform.crop_width as u32).min(oriented.width() - x);
+        let height = (transform.crop_height as u32).min(oriented.height() - y);
+        crop_imm(&oriented, x, y, width, height).to_image()
+    } else {
+        oriented
+    }
+}
+
+fn converted(img: &ImageData, transform: FrameTransform) -> RgbaImage {
+    let geometry = FrameGeometry::new(img, transform);
+    let mut bytes = vec![0; geometry.width() * geometry.height() * 4];
+    geometry.rgba_into(img, &mut bytes);
+    RgbaImage::from_raw(geometry.width() as u32, geometry.height() as u32, bytes).unwrap()
+}
+
+#[test]
+fn every_orientation_matches_image_operations() {
+    let sensor = sensor_image();
+    let frame = sensor_frame();
+    for rotation in ROTATIONS {
+        for mirror in [false, true] {
+            for crop in [(0, 0, 0, 0), (1, 1, 2, 2), (0, 2, 3, 1)] {
+                let transform = transform(rotation, mirror, crop);
+                assert_eq!(converted(&frame, transform), expected(&sensor, transform), "{transform:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn source_rotates_clockwise_then_mirrors() {
+    let frame = sensor_frame();
+    let corner = |rotation, mirror| FrameGeometry::new(&frame, transform(rotation, mirror, (0, 0, 0, 0))).source(0, 0);
+    assert_eq!(corner(Rotation::Rotate0, false), (0, 0));
+    assert_eq!(corner(Rotation::Rotate90, false), (0, HEIGHT as usize - 1));
+    assert_eq!(corner(Rotation::Rotate180, false), (WIDTH as usize - 1, HEIGHT as usize - 1));
+    assert_eq!(corner(Rotation::Rotate270, false), (WIDTH as usize - 1, 0));
+    assert_eq!(corner(Rotation::Rotate0, true), (WIDTH as usize - 1, 0));
+    assert_eq!(corner(Rotation::Rotate90, true), (0, 0));
+
+    let cropped = FrameGeometry::new(&frame, transform(Rotation::Rotate90, false, (1, 2, 2, 2)));
+    assert_eq!(cropped.source(0, 0), (2, 1));
+}
+
+#[test]
+fn quarter_turns_swap_the_dimensions() {
+    let frame = sensor_frame();
+    for rotation in ROTATIONS {
+        let geometry = FrameGeometry::new(&frame, transform(rotation, false, (0, 0, 0, 0)));
+        let size = (geometry.width(), geometry.height());
+        match rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => assert_eq!(size, (5, 3)),
+            Rotation::Rotate90 | Rotation::Rotate270 => assert_eq!(size, (3, 5)),
+        }
+    }
+}
+
+#[test]
+fn crops_are_clipped_to_the_frame() {
+    let frame = sensor_frame();
+    let size = |crop| {
+        let geometry = FrameGeometry::new(&frame, transform(Rotation::Rotate0, false, crop));
+        (geometry.width(), geometry.height())
+    };
+    assert_eq!(size((3, 1, 10, 10)), (2, 2));
+    assert_eq!(size((-2, -1, 2, 2)), (2, 2));
+    assert_eq!(size((7, 0, 2, 2)), (0, 2));
+    assert_eq!(size((0, 9, 2, 2)), (2, 0));
+    // A crop without an area leaves the frame whole.
+    assert_eq!(size((1, 1, 0, 2)), (5, 3));
+    assert_eq!(size((1, 1, 2, -1)), (5, 3));
+
+    let outside = transform(Rotation::Rotate90, true, (9, 9, 2, 2));
+    assert!(converted(&frame, outside).is_empty());
+}
+
+#[test]
+fn identity_needs_no_rotation_mirror_or_crop() {
+    let frame = sensor_frame();
+    let is_identity = |rotation, mirror, crop| FrameGeometry::new(&frame, transform(rotation, mirror, crop)).is_identity();
+    assert!(is_identity(Rotation::Rotate0, false, (0, 0, 0, 0)));
+    assert!(is_identity(Rotation::Rotate0, false, (0, 0, 5, 3)));
+    assert!(is_identity(Rotation::Rotate0, false, (0, 0, 50, 30)));
+    assert!(!is_identity(Rotation::Rotate0, false, (1, 0, 4, 3)));
+    assert!(!is_identity(Rotation::Rotate0, true, (0, 0, 0, 0)));
+    assert!(!is_identity(Rotation::Rotate180, false, (0, 0, 0, 0)));
+}
+
+#[test]
+fn odd_sized_yuv_frames_rotate_within_their_planes() {
+    // Gray chroma, so every pixel's color is its luma.
+    let sensor = sensor_image();
+    let ys: Vec<u8> = sensor.pixels().map(|p| p[2]).collect();
+    let chroma_len = (WIDTH.div_ceil(2) * HEIGHT.div_ceil(2)) as usize;
+    let frame = ImageData {
+        ys,
+        us: vec![128; chroma_len],
+        vs: vec![128; chroma_len],
+        width: WIDTH as i64,
+        height: HEIGHT as i64,
+        y_row_stride: WIDTH as i64,
+        uv_row_stride: WIDTH.div_ceil(2) as i64,
+        uv_pixel_stride: 1,
+        format: PixelFormat::I420,
+    };
+    for rotation in ROTATIONS {
+        let transform = transform(rotation, true, (0, 0, 0, 0));
+        let blue: Vec<u8> = converted(&frame, transform).pixels().map(|p| p[0]).collect();
+        let expected: Vec<u8> = expected(&sensor, transform).pixels().map(|p| p[2]).collect();
+        assert_eq!(blue, expected, "{transform:?}");
+    }
+}
+
+#[test]
+fn frame_size_follows_the_current_transform() {
+    let _globals = common::global_state();
+    let frame = sensor_frame();
+    assert_eq!(frame_size(&frame), (5, 3));
+    set_frame_transform(transform(Rotation::Rotate270, false, (1, 0, 2, 10)));
+    assert_eq!(frame_size(&frame), (2, 5));
+    set_frame_transform(FrameTransform::default());
+    assert_eq!(frame_size(&frame), (5, 3));
+}
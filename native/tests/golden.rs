@@ -0,0 +1,309 @@
+// Regression tests of the image pipelines against stored outputs.
+//
+// Fixtures in tests/fixtures/golden are 240x180 synthetic scenes built by
+// `scene` below (colored rectangles, a checkerboard, a disc and a scatter of
+// small squares on a gradient) saved with `save_image_data`: the same scene as
+// padded I420, NV21 and BGRA8888, plus the I420 scene shifted 3 pixels right
+// and 2 down. Expected outputs sit next to them as `<fixture>.<check>.png` or
+// `.txt`.
+//
+// After an intended change of output, regenerate the fixtures and expected
+// files with
+// UPDATE_GOLDEN=1 cargo test --test golden
+// and review the diff before committing it.
+//
+// Clustering with a trained color model is out of scope: k-means training is
+// randomly seeded, so only the per-frame palette used before training is pinned.
+
+use cv::feature::akaze::Akaze;
+use cv::image::image::DynamicImage;
+use image::{Rgba, RgbaImage};
+use native::api::{akaze_view, color_clusterer, color_count, kmeans_ready, ImageData, PixelFormat};
+use native::image_proc::{
+    bgra_frame, convert, inner_yuv_rgba, load_image_data, luma_image, save_image_data, KeyPointMovements,
+};
+use native::segmentation::{Palette, FRAME_PALETTE_SIZE};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FRAMES: [&str; 3] = ["scene_i420", "scene_nv21", "scene_bgra"];
+const WIDTH: u32 = 240;
+const HEIGHT: u32 = 180;
+/// Bytes of padding after each row of the I420 fixtures' Y plane.
+const Y_PADDING: usize = 8;
+
+/// Largest difference allowed in any channel of a converted pixel.
+const CHANNEL_TOLERANCE: u8 = 1;
+/// Fraction by which distinct color and keypoint counts may drift.
+const COUNT_TOLERANCE: f64 = 0.02;
+/// Pixels by which the mean keypoint movement may drift.
+const FLOW_TOLERANCE: f64 = 0.5;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden").join(name)
+}
+
+/// The stored fixture, or while updating, the frame it is regenerated from.
+fn fixture(name: &str) -> ImageData {
+    if updating() {
+        build_fixture(name)
+    } else {
+        load_image_data(fixture_path(&format!("{name}.vbim"))).unwrap()
+    }
+}
+
+fn build_fixture(name: &str) -> ImageData {
+    match name {
+        "scene_i420" => i420(&scene(0, 0)),
+        "scene_nv21" => nv21(&scene(0, 0)),
+        "scene_bgra" => bgra_frame(&scene(0, 0)),
+        "scene_shifted_i420" => i420(&scene(3, 2)),
+        _ => panic!("no fixture {name}"),
+    }
+}
+
+/// A disc's center, radius and color.
+type Dot = (i64, i64, i64, (u8, u8, u8));
+
+/// Gradient background under flat shapes and dots, which give AKAZE blobs and
+/// corners to find at several scales. `(dx, dy)` shifts everything.
+fn scene(dx: i64, dy: i64) -> RgbaImage {
+    // Dots of different sizes and colors, each a distinct blob for AKAZE.
+    const DOTS: [Dot; 10] = [
+        (110, 50, 4, (250, 250, 250)),
+        (105, 82, 6, (10, 10, 10)),
+        (192, 60, 5, (200, 40, 200)),
+        (196, 90, 3, (0, 0, 0)),
+        (44, 96, 4, (255, 160, 0)),
+        (126, 148, 5, (240, 240, 240)),
+        (100, 100, 3, (120, 0, 0)),
+        (192, 132, 4, (0, 90, 90)),
+        (80, 152, 6, (230, 220, 255)),
+        (150, 36, 3, (40, 40, 120)),
+    ];
+    RgbaImage::from_fn(WIDTH, HEIGHT, |x, y| {
+        let (x, y) = (x as i64 - dx, y as i64 - dy);
+        let inside = |x0, y0, x1, y1| (x0..x1).contains(&x) && (y0..y1).contains(&y);
+        let (r, g, b) = if (x - 115).pow(2) + (y - 120).pow(2) <= 100 {
+            (20, 30, 200)
+        } else if inside(140, 100, 180, 140) {
+            let gray = if ((x - 140) / 6 + (y - 100) / 6) % 2 == 0 { 230 } else { 20 };
+            (gray, gray, gray)
+        } else if inside(50, 45, 90, 80) {
+            (220, 40, 30)
+        } else if inside(130, 50, 170, 80) {
+            (30, 200, 60)
+        } else if inside(55, 105, 95, 140) {
+            (240, 230, 40)
+        } else if let Some((_, _, _, color)) =
+            DOTS.iter().find(|(cx, cy, radius, _)| (x - cx).pow(2) + (y - cy).pow(2) <= radius * radius)
+        {
+            *color
+        } else {
+            let (x, y) = (x as f64, y as f64);
+            let channel = |v: f64| v.clamp(0.0, 255.0) as u8;
+            (channel(60.0 + x * 0.35), channel(80.0 + y * 0.45), channel(140.0 - x * 0.2 + y * 0.15))
+        };
+        Rgba([r, g, b, u8::MAX])
+    })
+}
+
+/// BT.601 full range YUV of an RGB color.
+fn yuv((r, g, b): (f64, f64, f64)) -> [u8; 3] {
+    let (kr, kb) = (0.299, 0.114);
+    let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+    let u = (b - y) / (2.0 * (1.0 - kb)) + 128.0;
+    let v = (r - y) / (2.0 * (1.0 - kr)) + 128.0;
+    [y, u, v].map(|c| c.round().clamp(0.0, 255.0) as u8)
+}
+
+/// The Y plane, and U and V averaged over each 2x2 block.
+fn yuv_planes(img: &RgbaImage) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let channel = |p: &Rgba<u8>, c: usize| p[c] as f64;
+    let ys = img.pixels().map(|p| yuv((channel(p, 0), channel(p, 1), channel(p, 2)))[0]).collect();
+    let (mut us, mut vs) = (vec![], vec![]);
+    for cy in 0..img.height() / 2 {
+        for cx in 0..img.width() / 2 {
+            let block = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(i, j)| *img.get_pixel(2 * cx + i, 2 * cy + j));
+            let mean = |c| block.iter().map(|p| channel(p, c)).sum::<f64>() / 4.0;
+            let [_, u, v] = yuv((mean(0), mean(1), mean(2)));
+            us.push(u);
+            vs.push(v);
+        }
+    }
+    (ys, us, vs)
+}
+
+fn i420(img: &RgbaImage) -> ImageData {
+    let (ys, us, vs) = yuv_planes(img);
+    let width = img.width() as usize;
+    ImageData {
+        ys: ys.chunks(width).flat_map(|row| row.iter().copied().chain([0; Y_PADDING])).collect(),
+        us,
+        vs,
+        width: width as i64,
+        height: img.height() as i64,
+        y_row_stride: (width + Y_PADDING) as i64,
+        uv_row_stride: width as i64 / 2,
+        uv_pixel_stride: 1,
+        format: PixelFormat::I420,
+    }
+}
+
+fn nv21(img: &RgbaImage) -> ImageData {
+    let (ys, us, vs) = yuv_planes(img);
+    ImageData {
+        ys,
+        us: vs.iter().zip(us.iter()).flat_map(|(v, u)| [*v, *u]).collect(),
+        vs: vec![],
+        width: img.width() as i64,
+        height: img.height() as i64,
+        y_row_stride: img.width() as i64,
+        uv_row_stride: img.width() as i64,
+        uv_pixel_stride: 2,
+        format: PixelFormat::Nv21,
+    }
+}
+
+fn updating() -> bool {
+    std::env::var_os("UPDATE_GOLDEN").is_some()
+}
+
+fn missing(path: &Path) -> ! {
+    panic!("no expected output {}; run with UPDATE_GOLDEN=1 to create it", path.display())
+}
+
+fn check_image(name: &str, check: &str, actual: &RgbaImage) {
+    if updating() {
+        actual.save(fixture_path(&format!("{name}.{check}.png"))).unwrap();
+    } else {
+        compare_image(name, check, actual);
+    }
+}
+
+/// Like `check_image`, for outputs that must match one another's expected image
+/// and so never update it. While updating, that image may be mid-rewrite, so the
+/// comparison waits for the next run.
+fn compare_image(name: &str, check: &str, actual: &RgbaImage) {
+    if updating() {
+        return;
+    }
+    let path = fixture_path(&format!("{name}.{check}.png"));
+    let expected = image::open(&path).unwrap_or_else(|_| missing(&path)).to_rgba8();
+    assert_eq!(expected.dimensions(), actual.dimensions(), "{name}.{check}");
+    for ((x, y, e), a) in expected.enumerate_pixels().zip(actual.pixels()) {
+        let close = e.0.iter().zip(a.0.iter()).all(|(e, a)| e.abs_diff(*a) <= CHANNEL_TOLERANCE);
+        assert!(close, "{name}.{check} at ({x}, {y}): expected {:?}, got {:?}", e.0, a.0);
+    }
+}
+
+/// Compares whitespace-separated numbers, each within `tolerance(expected)`.
+fn check_values(name: &str, check: &str, actual: &[f64], tolerance: impl Fn(f64) -> f64) {
+    let path = fixture_path(&format!("{name}.{check}.txt"));
+    if updating() {
+        let values: Vec<String> = actual.iter().map(|v| v.to_string()).collect();
+        fs::write(&path, values.join(" ") + "\n").unwrap();
+        return;
+    }
+    let expected: Vec<f64> = fs::read_to_string(&path)
+        .unwrap_or_else(|_| missing(&path))
+        .split_whitespace()
+        .map(|v| v.parse().unwrap())
+        .collect();
+    assert_eq!(expected.len(), actual.len(), "{name}.{check}");
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert!((e - a).abs() <= tolerance(*e), "{name}.{check}: expected {expected:?}, got {actual:?}");
+    }
+}
+
+fn relative(expected: f64) -> f64 {
+    expected * COUNT_TOLERANCE
+}
+
+fn rgba(name: &str, img: &ImageData) -> RgbaImage {
+    RgbaImage::from_raw(img.width as u32, img.height as u32, inner_yuv_rgba(img)).expect(name)
+}
+
+#[test]
+fn fixtures_are_the_scenes() {
+    for name in FRAMES.into_iter().chain(["scene_shifted_i420"]) {
+        let path = fixture_path(&format!("{name}.vbim"));
+        if updating() {
+            save_image_data(&path, &build_fixture(name)).unwrap();
+        } else {
+            assert!(load_image_data(&path).unwrap() == build_fixture(name), "{name} differs from its scene");
+        }
+    }
+}
+
+#[test]
+fn inner_yuv_rgba_matches() {
+    for name in FRAMES {
+        check_image(name, "rgba", &rgba(name, &fixture(name)));
+    }
+}
+
+#[test]
+fn convert_matches() {
+    for name in FRAMES {
+        compare_image(name, "rgba", &convert(&fixture(name)));
+    }
+}
+
+#[test]
+fn color_count_matches() {
+    for name in FRAMES {
+        check_values(name, "color_count", &[color_count(fixture(name)) as f64], relative);
+    }
+}
+
+// No palette is trained in the tests, so this pins down the fallback.
+#[test]
+fn color_clusterer_without_palette_matches() {
+    assert!(!kmeans_ready());
+    for name in FRAMES {
+        let response = color_clusterer(fixture(name));
+        let actual = RgbaImage::from_raw(response.width as u32, response.height as u32, response.img.0).unwrap();
+        compare_image(name, "rgba", &actual);
+    }
+}
+
+#[test]
+fn frame_palette_matches() {
+    for name in FRAMES {
+        let rgba = convert(&fixture(name));
+        check_image(name, "frame_palette", &Palette::of_frame(&rgba, FRAME_PALETTE_SIZE).recolor(&rgba));
+    }
+}
+
+#[test]
+fn akaze_keypoint_counts_match() {
+    for name in FRAMES {
+        let msg = akaze_view(fixture(name)).msg;
+        let points: f64 = msg.split_whitespace().nth(1).and_then(|n| n.parse().ok()).expect(&msg);
+        check_values(name, "akaze_points", &[points], relative);
+    }
+}
+
+#[test]
+fn keypoint_movement_mean_matches() {
+    let extract = |name| Akaze::dense().extract(&DynamicImage::ImageLuma8(luma_image(&fixture(name))));
+    let (last_keypoints, last_features) = extract("scene_i420");
+    let (keypoints, features) = extract("scene_shifted_i420");
+    let feature_moves = KeyPointMovements::feature_match(&last_keypoints, &last_features, &keypoints, &features);
+    let keypoint_moves = KeyPointMovements::keypoint_match(&last_keypoints, &last_features, &keypoints, &features);
+    for (check, moves) in [("feature_flow", feature_moves), ("keypoint_flow", keypoint_moves)] {
+        let ((x1, y1), (x2, y2)) = moves.mean();
+        let actual = [x1, y1, x2, y2].map(|v| v as f64);
+        // Whatever the stored values, matching descriptors finds that the scene
+        // moved 3 right and 2 down. Matching by position pairs up some of the
+        // keypoints found in only one frame, so it only gets the direction.
+        let (dx, dy) = (actual[2] - actual[0], actual[3] - actual[1]);
+        if check == "feature_flow" {
+            assert!((dx - 3.0).abs() <= FLOW_TOLERANCE && (dy - 2.0).abs() <= FLOW_TOLERANCE, "{check}: {dx}, {dy}");
+        } else {
+            assert!(dx > 0.0 && dy > 0.0, "{check}: {dx}, {dy}");
+        }
+        check_values("scene_shifted_i420", check, &actual, |_| FLOW_TOLERANCE);
+    }
+}
@@ -0,0 +1,105 @@
+use native::api::{ImageData, PixelFormat};
+use native::image_proc::{inner_yuv_rgba, load_image_data, read_image_data, save_image_data, write_image_data};
+use std::io::ErrorKind;
+
+mod common;
+
+const WIDTH: i64 = 6;
+const HEIGHT: i64 = 4;
+
+fn bytes(len: i64, seed: u8) -> Vec<u8> {
+    (0..len).map(|i| (i as u8).wrapping_mul(37).wrapping_add(seed)).collect()
+}
+
+/// One frame of every format, with padded rows where the format allows them.
+fn frames() -> Vec<ImageData> {
+    let y_row_stride = WIDTH + 2;
+    let planar = |format, uv_row_stride, uv_pixel_stride, uv_len| ImageData {
+        ys: bytes(y_row_stride * HEIGHT, 1),
+        us: bytes(uv_len, 2),
+        vs: bytes(uv_len, 3),
+        width: WIDTH,
+        height: HEIGHT,
+        y_row_stride,
+        uv_row_stride,
+        uv_pixel_stride,
+        format,
+    };
+    vec![
+        planar(PixelFormat::Yuv420, WIDTH, 2, WIDTH * HEIGHT / 2),
+        planar(PixelFormat::I420, WIDTH / 2, 1, WIDTH * HEIGHT / 4),
+        ImageData { us: bytes(WIDTH * HEIGHT / 2, 2), vs: vec![], ..planar(PixelFormat::Nv21, WIDTH, 2, 0) },
+        // Chroma rows follow the luma rows in `ys`.
+        ImageData { ys: bytes(y_row_stride * HEIGHT * 3 / 2, 1), ..planar(PixelFormat::Nv12, y_row_stride, 2, 0) },
+        ImageData {
+            ys: bytes(WIDTH * 4 * HEIGHT, 4),
+            us: vec![],
+            vs: vec![],
+            width: WIDTH,
+            height: HEIGHT,
+            y_row_stride: WIDTH * 4,
+            uv_row_stride: 0,
+            uv_pixel_stride: 0,
+            format: PixelFormat::Bgra8888,
+        },
+    ]
+}
+
+fn round_trip(img: &ImageData) -> ImageData {
+    let mut encoded = vec![];
+    write_image_data(&mut encoded, img).unwrap();
+    read_image_data(&mut encoded.as_slice()).unwrap()
+}
+
+#[test]
+fn every_format_round_trips() {
+    for img in frames() {
+        assert_eq!(round_trip(&img), img);
+    }
+}
+
+#[test]
+fn round_tripped_frames_convert_the_same() {
+    for img in frames() {
+        assert_eq!(inner_yuv_rgba(&round_trip(&img)), inner_yuv_rgba(&img), "{:?}", img.format);
+    }
+}
+
+#[test]
+fn header_layout() {
+    let img = &frames()[1];
+    let mut encoded = vec![];
+    write_image_data(&mut encoded, img).unwrap();
+    assert_eq!(&encoded[..6], b"VBIM\x01\x03");
+    assert_eq!(&encoded[6..10], &(WIDTH as u32).to_le_bytes());
+    assert_eq!(&encoded[10..14], &(HEIGHT as u32).to_le_bytes());
+    assert_eq!(&encoded[26..30], &(img.ys.len() as u32).to_le_bytes());
+    assert_eq!(encoded.len(), 26 + 12 + img.ys.len() + img.us.len() + img.vs.len());
+}
+
+#[test]
+fn files_round_trip() {
+    let path = common::temp_path("round_trip.vbim");
+    let img = &frames()[0];
+    save_image_data(&path, img).unwrap();
+    let loaded = load_image_data(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, *img);
+}
+
+#[test]
+fn rejects_bad_input() {
+    let mut encoded = vec![];
+    write_image_data(&mut encoded, &frames()[0]).unwrap();
+
+    let truncated = read_image_data(&mut &encoded[..encoded.len() - 1]);
+    assert_eq!(truncated.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let mut wrong_magic = encoded.clone();
+    wrong_magic[0] = b'X';
+    assert_eq!(read_image_data(&mut wrong_magic.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut wrong_format = encoded;
+    wrong_format[5] = 9;
+    assert_eq!(read_image_data(&mut wrong_format.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+}
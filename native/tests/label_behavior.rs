@@ -0,0 +1,49 @@
+use native::api::RobotCommand;
+use native::behavior::LabelBehavior;
+
+const FORWARD: RobotCommand = RobotCommand::Drive { speed: 100 };
+const LEFT: RobotCommand = RobotCommand::Turn { speed: -50 };
+const STOP: RobotCommand = RobotCommand::Stop;
+
+fn observe_all(behavior: &mut LabelBehavior, labels: &[&str]) -> Vec<RobotCommand> {
+    labels.iter().map(|label| behavior.observe(label)).collect()
+}
+
+#[test]
+fn stops_until_a_label_settles() {
+    let mut behavior = LabelBehavior::default();
+    assert_eq!(behavior.command(), STOP);
+    assert_eq!(observe_all(&mut behavior, &["forward", "forward"]), [STOP, FORWARD]);
+    assert_eq!(behavior.current_label(), Some("forward"));
+}
+
+#[test]
+fn ignores_isolated_misclassifications() {
+    let mut behavior = LabelBehavior::default();
+    observe_all(&mut behavior, &["forward"; 5]);
+    let commands = observe_all(&mut behavior, &["left", "forward", "right", "forward", "stop", "forward"]);
+    assert!(commands.iter().all(|c| *c == FORWARD), "{commands:?}");
+    assert_eq!(behavior.current_label(), Some("forward"));
+}
+
+#[test]
+fn switches_once_a_new_label_holds_the_majority() {
+    let mut behavior = LabelBehavior::default();
+    observe_all(&mut behavior, &["forward"; 5]);
+    let commands = observe_all(&mut behavior, &["left"; 5]);
+    // The window needs three lefts to outvote forward, then the hold needs one more.
+    assert_eq!(commands, [FORWARD, FORWARD, FORWARD, LEFT, LEFT]);
+    assert_eq!(behavior.current_label(), Some("left"));
+    let commands = observe_all(&mut behavior, &["stop"; 5]);
+    assert_eq!(commands, [LEFT, LEFT, LEFT, STOP, STOP]);
+}
+
+#[test]
+fn unknown_labels_mean_stop() {
+    let mut behavior = LabelBehavior::new(1, 1);
+    behavior.set_action("go", FORWARD);
+    assert_eq!(behavior.observe("go"), FORWARD);
+    assert_eq!(behavior.observe("Need more examples; 1 < 3"), STOP);
+    behavior.clear_actions();
+    assert_eq!(behavior.observe("go"), STOP);
+}
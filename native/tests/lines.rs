@@ -0,0 +1,107 @@
+use image::{GrayImage, Luma};
+use native::lines::{hough_lines, HoughLine};
+use std::f32::consts::PI;
+
+const SIZE: u32 = 32;
+
+fn edges(points: impl IntoIterator<Item = (u32, u32)>) -> GrayImage {
+    let mut img = GrayImage::new(SIZE, SIZE);
+    for (x, y) in points {
+        img.put_pixel(x, y, Luma([u8::MAX]));
+    }
+    img
+}
+
+fn assert_near(actual: (f32, f32), expected: (f32, f32)) {
+    let distance = ((actual.0 - expected.0).powi(2) + (actual.1 - expected.1).powi(2)).sqrt();
+    assert!(distance < 0.5, "{actual:?} is not {expected:?}");
+}
+
+fn assert_endpoints(line: &HoughLine, a: (f32, f32), b: (f32, f32)) {
+    let (first, second) = if (line.start.0 - a.0).abs() + (line.start.1 - a.1).abs() < 1.0 { (a, b) } else { (b, a) };
+    assert_near(line.start, first);
+    assert_near(line.end, second);
+}
+
+fn assert_rho(line: &HoughLine, rho: f32) {
+    assert!((line.rho - rho).abs() < 1e-3, "rho {} is not {rho}", line.rho);
+}
+
+fn degrees(theta: f32) -> f32 {
+    (theta * 180.0 / PI).round()
+}
+
+#[test]
+fn horizontal_segment() {
+    let lines = hough_lines(&edges((4..=20).map(|x| (x, 7))), 10, 1);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(degrees(lines[0].theta), 90.0);
+    assert_rho(&lines[0], 7.0);
+    assert_eq!(lines[0].votes, 17);
+    assert_endpoints(&lines[0], (4.0, 7.0), (20.0, 7.0));
+}
+
+#[test]
+fn vertical_segment() {
+    let lines = hough_lines(&edges((2..=25).map(|y| (12, y))), 10, 1);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(degrees(lines[0].theta), 0.0);
+    assert_rho(&lines[0], 12.0);
+    assert_eq!(lines[0].votes, 24);
+    assert_endpoints(&lines[0], (12.0, 2.0), (12.0, 25.0));
+}
+
+#[test]
+fn diagonal_segments() {
+    let lines = hough_lines(&edges((0..30).map(|i| (i, i))), 10, 1);
+    assert_eq!(degrees(lines[0].theta), 135.0);
+    assert_rho(&lines[0], 0.0);
+    assert_eq!(lines[0].votes, 30);
+    assert_endpoints(&lines[0], (0.0, 0.0), (29.0, 29.0));
+
+    let lines = hough_lines(&edges((0..30).map(|i| (i, 30 - i))), 10, 1);
+    assert_eq!(degrees(lines[0].theta), 45.0);
+    assert_rho(&lines[0], 30.0 / 2.0_f32.sqrt());
+    assert_eq!(lines[0].votes, 30);
+    assert_endpoints(&lines[0], (0.0, 30.0), (29.0, 1.0));
+}
+
+#[test]
+fn near_vertical_lines_peak_once_across_the_theta_wrap() {
+    // Steps one pixel left halfway down, a lean of under 3 degrees, so its
+    // votes straddle theta 0 and PI, where rho changes sign.
+    let points: Vec<(u32, u32)> = (0..SIZE).map(|y| (16 - y / 16, y)).collect();
+    let lines = hough_lines(&edges(points), 12, 10);
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    assert_eq!(lines[0].votes, SIZE);
+    let theta = degrees(lines[0].theta);
+    assert!(theta <= 3.0 || theta >= 177.0, "{theta}");
+    let (cos, sin) = (lines[0].theta.cos(), lines[0].theta.sin());
+    for (x, y) in [(16.0, 7.5), (15.0, 23.5)] {
+        assert!((x * cos + y * sin - lines[0].rho).abs() <= 0.5, "{lines:?}");
+    }
+}
+
+#[test]
+fn strongest_lines_come_first() {
+    let long = (2..=29).map(|x| (x, 5));
+    let short = (10..=21).map(|y| (3, y));
+    let img = edges(long.chain(short));
+    let lines = hough_lines(&img, 10, 5);
+    assert_eq!(lines.len(), 2);
+    assert_eq!((degrees(lines[0].theta), lines[0].votes), (90.0, 28));
+    assert_eq!((degrees(lines[1].theta), lines[1].votes), (0.0, 12));
+
+    let lines = hough_lines(&img, 10, 1);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].votes, 28);
+
+    let lines = hough_lines(&img, 20, 5);
+    assert_eq!(lines.len(), 1);
+}
+
+#[test]
+fn no_edges_no_lines() {
+    assert!(hough_lines(&GrayImage::new(SIZE, SIZE), 1, 10).is_empty());
+    assert!(hough_lines(&GrayImage::new(0, 0), 1, 10).is_empty());
+}
@@ -0,0 +1,126 @@
+use common::example;
+use native::api::{
+    classify_knn_hash, find_duplicates, find_hash_duplicates, hash_distance, perceptual_hash, train_knn_hash,
+    DartImage, HashKind, ImageData, PixelFormat,
+};
+use native::phash::{hash_dart_image, hash_image_data, PerceptualHash};
+use std::f64::consts::PI;
+
+mod common;
+
+const KINDS: [HashKind; 3] = [HashKind::Average, HashKind::Difference, HashKind::Dct];
+
+/// Gray levels of a smooth scene, the same at any resolution, with energy in
+/// every low frequency the DCT hash looks at.
+fn levels(width: i64, height: i64, inverted: bool) -> Vec<u8> {
+    let mut levels = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            let (u, t) = ((x as f64 + 0.5) / width as f64, (y as f64 + 0.5) / height as f64);
+            let mut level = 128.0;
+            for fy in 0..8 {
+                for fx in 0..8 {
+                    let phase = ((fx * 7 + fy * 13) % 11) as f64;
+                    let amplitude = 24.0 / (1 + fx + fy) as f64;
+                    level += amplitude * (PI * (fx as f64 * u + fy as f64 * t) + phase).cos();
+                }
+            }
+            levels.push(if inverted { 255.0 - level } else { level }.clamp(0.0, 255.0).round() as u8);
+        }
+    }
+    levels
+}
+
+fn scene(width: i64, height: i64, inverted: bool) -> DartImage {
+    let bytes = levels(width, height, inverted).into_iter().flat_map(|l| [l, l, l, u8::MAX]).collect();
+    DartImage { bytes, width, height }
+}
+
+#[test]
+fn distance_counts_differing_bits() {
+    assert_eq!(hash_distance(0b1011, 0b0001), 2);
+    assert_eq!(hash_distance(u64::MAX, 0), 64);
+    assert_eq!(PerceptualHash(7).distance(&PerceptualHash(7)), 0);
+}
+
+#[test]
+fn resizing_barely_changes_the_hash() {
+    for kind in KINDS {
+        let small = hash_dart_image(&scene(32, 24, false), kind);
+        let large = hash_dart_image(&scene(96, 72, false), kind);
+        assert!(small.distance(&large) <= 8, "{kind:?}: {small:?} vs {large:?}");
+        assert_eq!(hash_dart_image(&scene(32, 24, false), kind), small);
+    }
+}
+
+#[test]
+fn inverting_flips_most_bits() {
+    for kind in KINDS {
+        let scene = perceptual_hash(scene(64, 48, false), kind);
+        let inverted = perceptual_hash(self::scene(64, 48, true), kind);
+        assert!(hash_distance(scene, inverted) >= 48, "{kind:?}: {scene:x} vs {inverted:x}");
+    }
+}
+
+#[test]
+fn frames_hash_like_their_pixels() {
+    let (width, height) = (40, 30);
+    let frame = ImageData {
+        ys: levels(width, height, false),
+        us: vec![128; (width * height / 4) as usize],
+        vs: vec![128; (width * height / 4) as usize],
+        width,
+        height,
+        y_row_stride: width,
+        uv_row_stride: width / 2,
+        uv_pixel_stride: 1,
+        format: PixelFormat::I420,
+    };
+    for kind in KINDS {
+        assert_eq!(hash_image_data(&frame, kind), hash_dart_image(&scene(width, height, false), kind));
+    }
+}
+
+#[test]
+fn empty_images_hash_to_zero() {
+    let empty = DartImage { bytes: vec![], width: 0, height: 0 };
+    for kind in KINDS {
+        assert_eq!(hash_dart_image(&empty, kind), PerceptualHash(0));
+    }
+}
+
+#[test]
+fn classifies_by_hash() {
+    let examples = vec![
+        example("forward", scene(32, 24, false)),
+        example("forward", scene(48, 36, false)),
+        example("back", scene(32, 24, true)),
+        example("back", scene(48, 36, true)),
+    ];
+    assert_eq!(classify_knn_hash(scene(40, 30, false)), "Need more examples; 0 < 3");
+    assert_eq!(train_knn_hash(1, examples, HashKind::Dct), "Training finished; 4 examples");
+    assert_eq!(classify_knn_hash(scene(80, 60, false)), "forward");
+    assert_eq!(classify_knn_hash(scene(80, 60, true)), "back");
+}
+
+#[test]
+fn hash_duplicates_span_sizes() {
+    let examples = vec![
+        example("left", scene(32, 24, false)),
+        example("right", scene(32, 24, true)),
+        example("left", scene(96, 72, false)),
+        example("right", scene(64, 48, false)),
+    ];
+    let report = find_hash_duplicates(examples.clone(), HashKind::Difference, 8);
+    assert_eq!(report.groups.len(), 1);
+    assert_eq!(report.groups[0].label, "left");
+    assert_eq!(report.groups[0].members, [0, 2]);
+    let pairs: Vec<(i64, i64)> = report.conflicts.iter().map(|c| (c.first, c.second)).collect();
+    assert_eq!(pairs, [(0, 3), (2, 3)]);
+    assert!(report.conflicts.iter().all(|c| c.distance <= 8.0));
+
+    // Raw pixels cannot compare photos of different sizes.
+    let report = find_duplicates(examples, 2.0);
+    assert!(report.groups.is_empty());
+    assert!(report.conflicts.is_empty());
+}
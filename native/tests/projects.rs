@@ -0,0 +1,54 @@
+use image::{Rgba, RgbaImage};
+use native::api::{classify_knn, load_project, KnnModel};
+use native::projects::{labeled_images, project_dir};
+use std::fs;
+use std::path::Path;
+
+mod common;
+
+/// Saves `photos` solid-color 4x3 PNGs for each label, plus a stray file.
+fn make_project(dir: &Path, labels: &[(&str, [u8; 3])], photos: usize) {
+    for (label, color) in labels.iter() {
+        let label_dir = project_dir(dir, "proj1").join(label);
+        fs::create_dir_all(&label_dir).unwrap();
+        for i in 0..photos {
+            let shade = color.map(|c| c.saturating_add(i as u8));
+            let img = RgbaImage::from_pixel(4, 3, Rgba([shade[0], shade[1], shade[2], u8::MAX]));
+            img.save(label_dir.join(format!("ph_{i}.png"))).unwrap();
+        }
+        fs::write(label_dir.join("notes.txt"), "not a photo").unwrap();
+    }
+}
+
+#[test]
+fn reads_every_label_in_name_order() {
+    let dir = common::temp_path("order");
+    make_project(&dir, &[("right", [0, 0, 200]), ("left", [200, 0, 0])], 2);
+    let examples = labeled_images(project_dir(&dir, "proj1"));
+    fs::remove_dir_all(&dir).unwrap();
+
+    let examples = examples.unwrap();
+    let labels: Vec<&str> = examples.iter().map(|e| e.label.as_str()).collect();
+    assert_eq!(labels, ["left", "left", "right", "right"]);
+    let first = &examples[0].image;
+    assert_eq!((first.width, first.height), (4, 3));
+    assert_eq!(&first.bytes[..4], &[200, 0, 0, u8::MAX]);
+    assert_eq!(&examples[3].image.bytes[..4], &[1, 1, 201, u8::MAX]);
+}
+
+#[test]
+fn missing_project_is_an_error() {
+    assert!(labeled_images(project_dir(common::temp_path("missing"), "proj1")).is_err());
+}
+
+#[test]
+fn load_project_trains_the_classifier() {
+    let dir = common::temp_path("train");
+    make_project(&dir, &[("left", [200, 0, 0]), ("right", [0, 0, 200])], 3);
+    let result = load_project(dir.to_string_lossy().into_owned(), "proj1".to_owned(), 3, KnnModel::Pixels);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(result.unwrap(), "Training finished; 6 examples");
+    let blue = RgbaImage::from_pixel(4, 3, Rgba([10, 0, 190, u8::MAX]));
+    assert_eq!(classify_knn(blue.into_raw()), "right");
+}
@@ -0,0 +1,89 @@
+use image::{GrayImage, Luma, Rgba, RgbaImage};
+use native::api::{akaze_view, set_pyramid_level, ImageData};
+use native::image_proc::{downsample, to_full_scale, upsample};
+
+mod common;
+
+fn gray(width: u32, height: u32, levels: &[u8]) -> GrayImage {
+    GrayImage::from_raw(width, height, levels.to_vec()).unwrap()
+}
+
+#[test]
+fn downsampling_averages_blocks_rounding_half_up() {
+    let img = gray(4, 2, &[1, 2, 0, 0, 3, 4, 1, 1]);
+    assert_eq!(downsample(&img, 2).into_raw(), [3, 1]);
+}
+
+#[test]
+fn downsampling_drops_partial_blocks() {
+    let img = gray(5, 3, &[10, 10, 10, 10, 99, 10, 10, 10, 10, 99, 99, 99, 99, 99, 99]);
+    let small = downsample(&img, 2);
+    assert_eq!(small.dimensions(), (2, 1));
+    assert_eq!(small.into_raw(), [10, 10]);
+    assert_eq!(downsample(&img, 6).dimensions(), (0, 0));
+}
+
+#[test]
+fn downsampling_by_one_or_zero_copies() {
+    let img = gray(3, 1, &[7, 8, 9]);
+    assert_eq!(downsample(&img, 1), img);
+    assert_eq!(downsample(&img, 0), img);
+}
+
+#[test]
+fn downsampling_averages_each_channel() {
+    let img = RgbaImage::from_fn(2, 2, |x, y| Rgba([x as u8 * 100, y as u8 * 50, 7, 255]));
+    assert_eq!(*downsample(&img, 2).get_pixel(0, 0), Rgba([50, 25, 7, 255]));
+}
+
+#[test]
+fn upsampling_repeats_pixels_out_to_the_full_size() {
+    let small = gray(2, 1, &[1, 2]);
+    let big = upsample(&small, 2, 5, 3);
+    assert_eq!(big.dimensions(), (5, 3));
+    for y in 0..3 {
+        let row: Vec<u8> = (0..5).map(|x| big.get_pixel(x, y)[0]).collect();
+        assert_eq!(row, [1, 1, 2, 2, 2]);
+    }
+    assert_eq!(upsample(&GrayImage::new(0, 0), 2, 3, 2), GrayImage::from_pixel(3, 2, Luma([0])));
+}
+
+#[test]
+fn round_trip_keeps_uniform_blocks() {
+    let img = GrayImage::from_fn(6, 4, |x, y| Luma([if x < 2 && y < 2 { 200 } else { 20 }]));
+    assert_eq!(upsample(&downsample(&img, 2), 2, 6, 4), img);
+}
+
+#[test]
+fn keypoints_map_to_the_center_of_their_block() {
+    assert_eq!(to_full_scale((0.0, 0.0), 1), (0.0, 0.0));
+    assert_eq!(to_full_scale((3.0, 1.5), 1), (3.0, 1.5));
+    assert_eq!(to_full_scale((0.0, 0.0), 2), (0.5, 0.5));
+    assert_eq!(to_full_scale((2.0, 1.0), 2), (4.5, 2.5));
+    assert_eq!(to_full_scale((1.0, 3.0), 4), (5.5, 13.5));
+    assert_eq!(to_full_scale((2.0, 1.0), 0), (2.0, 1.0));
+}
+
+/// A `width` x `height` BGRA checkerboard of 8-pixel squares.
+fn checkerboard(width: u32, height: u32) -> ImageData {
+    common::bgra_frame(width, height, |x, y| {
+        let v = if (x / 8 + y / 8) % 2 == 0 { 230 } else { 20 };
+        [v, v, v, u8::MAX]
+    })
+}
+
+fn akaze_points(img: ImageData) -> usize {
+    let msg = akaze_view(img).msg;
+    msg.split_whitespace().nth(1).and_then(|n| n.parse().ok()).expect(&msg)
+}
+
+#[test]
+fn akaze_finds_nothing_in_frames_too_small_to_search() {
+    let _globals = common::global_state();
+    assert_eq!(akaze_points(checkerboard(32, 24)), 0);
+    assert!(akaze_points(checkerboard(128, 96)) > 0);
+    set_pyramid_level(2);
+    let points = akaze_points(checkerboard(128, 96));
+    set_pyramid_level(0);
+    assert_eq!(points, 0);
+}
@@ -0,0 +1,149 @@
+use native::api::{
+    process_sensor_data, replay_recording, set_color_conversion, set_frame_transform, set_pyramid_level,
+    start_recording, stop_recording, yuv_rgba, ColorRange, ColorStandard, FrameTransform, ImageData, PixelFormat,
+    Rotation,
+};
+use native::recording::{read_log, LogEntry, LogSettings, Recorder};
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+
+fn log_path(name: &str) -> PathBuf {
+    common::temp_path(&format!("{name}.vblog"))
+}
+
+/// Big enough that AKAZE still searches it when rotated, cropped and halved.
+fn frame(seed: u8) -> ImageData {
+    let (width, height) = (128, 96);
+    ImageData {
+        ys: (0..width * height).map(|i| (i as u8).wrapping_mul(seed)).collect(),
+        us: (0..width * height / 4).map(|i| (i as u8).wrapping_add(seed)).collect(),
+        vs: (0..width * height / 4).map(|i| 255 - i as u8).collect(),
+        width,
+        height,
+        y_row_stride: width,
+        uv_row_stride: width / 2,
+        uv_pixel_stride: 1,
+        format: PixelFormat::Yuv420,
+    }
+}
+
+#[test]
+fn records_read_back_in_order() {
+    let path = log_path("order");
+    let mut recorder = Recorder::create(&path).unwrap();
+    recorder.frame(&frame(3)).unwrap();
+    recorder.sensors("SF:10;SL:20;SR:30;LC:1;RC:2;LS:0;RS:0").unwrap();
+    recorder.frame(&frame(5)).unwrap();
+    recorder.flush().unwrap();
+
+    let records = read_log(&path).unwrap().records;
+    fs::remove_file(&path).unwrap();
+    assert_eq!(records.len(), 3);
+    assert!(records.windows(2).all(|w| w[0].millis <= w[1].millis));
+    match (&records[0].entry, &records[1].entry, &records[2].entry) {
+        (LogEntry::Frame(first), LogEntry::Sensors(packet), LogEntry::Frame(second)) => {
+            assert_eq!(*first, frame(3));
+            assert_eq!(packet, "SF:10;SL:20;SR:30;LC:1;RC:2;LS:0;RS:0");
+            assert_eq!(*second, frame(5));
+        }
+        _ => panic!("records out of order"),
+    }
+}
+
+#[test]
+fn truncated_last_record_is_dropped() {
+    let path = log_path("truncated");
+    let mut recorder = Recorder::create(&path).unwrap();
+    recorder.sensors("SF:10;SL:20;SR:30;LC:1;RC:2;LS:0;RS:0").unwrap();
+    recorder.frame(&frame(3)).unwrap();
+    recorder.flush().unwrap();
+    let bytes = fs::read(&path).unwrap();
+    fs::write(&path, &bytes[..bytes.len() - 10]).unwrap();
+
+    let records = read_log(&path).unwrap().records;
+    fs::remove_file(&path).unwrap();
+    assert_eq!(records.len(), 1);
+    assert!(matches!(records[0].entry, LogEntry::Sensors(_)));
+}
+
+#[test]
+fn oversized_lengths_are_cut_short() {
+    let path = log_path("oversized");
+    Recorder::create(&path).unwrap().flush().unwrap();
+    let mut bytes = fs::read(&path).unwrap();
+    bytes.push(b'S');
+    bytes.extend_from_slice(&7u64.to_le_bytes());
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+    bytes.extend_from_slice(b"SF:10");
+    fs::write(&path, bytes).unwrap();
+
+    let records = read_log(&path).unwrap().records;
+    fs::remove_file(&path).unwrap();
+    assert!(records.is_empty());
+}
+
+#[test]
+fn rejects_other_files() {
+    let path = log_path("other");
+    fs::write(&path, b"\x89PNG\r\n\x1a\n").unwrap();
+    let result = read_log(&path);
+    fs::remove_file(&path).unwrap();
+    assert!(result.is_err());
+}
+
+#[test]
+fn replay_is_repeatable_under_the_recorded_settings() {
+    let _globals = common::global_state();
+    let defaults = LogSettings::current();
+    let recorded = LogSettings {
+        standard: ColorStandard::Bt709,
+        range: ColorRange::Limited,
+        transform: FrameTransform {
+            rotation: Rotation::Rotate90,
+            mirror: true,
+            crop_x: 2,
+            crop_y: 4,
+            crop_width: 90,
+            crop_height: 120,
+        },
+        pyramid_level: 1,
+    };
+    set_color_conversion(recorded.standard, recorded.range);
+    set_frame_transform(recorded.transform);
+    set_pyramid_level(recorded.pyramid_level);
+    assert_eq!(LogSettings::current(), recorded);
+
+    let path = log_path("replay");
+    start_recording(path.to_string_lossy().into_owned()).unwrap();
+    for i in 0..4 {
+        yuv_rgba(frame(i + 1));
+        process_sensor_data(format!("SF:50;SL:50;SR:50;LC:{};RC:{};LS:100;RS:100", 10 * i, 10 * i));
+    }
+    stop_recording().unwrap();
+    defaults.apply();
+
+    assert_eq!(read_log(&path).unwrap().settings, recorded);
+    let path = path.to_string_lossy().into_owned();
+    let first = replay_recording(path.clone()).unwrap();
+    assert_eq!(LogSettings::current(), defaults);
+    let second = replay_recording(path.clone()).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(first.len(), 8);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn settings_come_back_after_a_panicking_replay() {
+    let _globals = common::global_state();
+    let defaults = LogSettings::current();
+    let recorded = LogSettings { pyramid_level: 2, ..defaults };
+    let replay = std::panic::catch_unwind(|| {
+        let _restore = recorded.apply_until_dropped();
+        assert_eq!(LogSettings::current(), recorded);
+        panic!("replay failed");
+    });
+    assert!(replay.is_err());
+    assert_eq!(LogSettings::current(), defaults);
+}
@@ -0,0 +1,202 @@
+use image::{GrayImage, Luma, Rgba, RgbaImage};
+use native::api::{detect_blobs, floor_free_space, set_min_blob_area, stop_blob_tracking, track_blob_color};
+use native::image_proc::bgra_frame;
+use native::segmentation::{
+    color_blobs, column_free_space, floor_colors, free_space_mask, mask_blobs, Blob, Palette, FRAME_PALETTE_SIZE,
+};
+
+mod common;
+
+const WIDTH: u32 = 24;
+const HEIGHT: u32 = 16;
+const FLOOR: (u8, u8, u8) = (90, 90, 90);
+const WALL: (u8, u8, u8) = (20, 40, 160);
+const BOX: (u8, u8, u8) = (200, 30, 30);
+
+/// A wall above row 10, floor below it, and a box standing on the floor in
+/// columns 4 to 7, down to row 12.
+fn scene_color(x: u32, y: u32) -> (u8, u8, u8) {
+    if (4..8).contains(&x) && (6..13).contains(&y) {
+        BOX
+    } else if y < 10 {
+        WALL
+    } else {
+        FLOOR
+    }
+}
+
+fn rgba((r, g, b): (u8, u8, u8)) -> Rgba<u8> {
+    Rgba([r, g, b, u8::MAX])
+}
+
+fn scene() -> RgbaImage {
+    RgbaImage::from_fn(WIDTH, HEIGHT, |x, y| rgba(scene_color(x, y)))
+}
+
+/// The scene as a camera would see it, every channel off by up to 10.
+fn noisy_scene() -> RgbaImage {
+    RgbaImage::from_fn(WIDTH, HEIGHT, |x, y| {
+        let noise = |salt: u32| ((x * 7919 + y * 104729 + salt * 31) % 21) as i32 - 10;
+        let (r, g, b) = scene_color(x, y);
+        let channel = |c: u8, salt| (c as i32 + noise(salt)).clamp(0, 255) as u8;
+        rgba((channel(r, 1), channel(g, 2), channel(b, 3)))
+    })
+}
+
+fn expected_heights() -> Vec<u32> {
+    (0..WIDTH).map(|x| if (4..8).contains(&x) { 3 } else { 6 }).collect()
+}
+
+#[test]
+fn floor_is_the_bottom_center_color() {
+    assert_eq!(floor_colors(&scene()).into_iter().collect::<Vec<_>>(), [FLOOR]);
+}
+
+#[test]
+fn rare_colors_in_the_floor_sample_are_noise() {
+    // The sample is columns 8 to 15 of the bottom two rows: 16 pixels.
+    let mut img = scene();
+    img.put_pixel(9, 15, rgba(BOX));
+    assert_eq!(floor_colors(&img).into_iter().collect::<Vec<_>>(), [FLOOR]);
+
+    let patterned = (60, 60, 60);
+    for x in 12..16 {
+        img.put_pixel(x, 14, rgba(patterned));
+    }
+    let mut floor: Vec<_> = floor_colors(&img).into_iter().collect();
+    floor.sort();
+    assert_eq!(floor, [patterned, FLOOR]);
+}
+
+#[test]
+fn free_space_is_every_floor_colored_pixel() {
+    let mask = free_space_mask(&scene());
+    for (x, y, p) in mask.enumerate_pixels() {
+        let expected = if scene_color(x, y) == FLOOR { u8::MAX } else { 0 };
+        assert_eq!(p[0], expected, "({x}, {y})");
+    }
+    assert_eq!(column_free_space(&mask), expected_heights());
+}
+
+#[test]
+fn column_free_space_stops_at_the_first_obstacle() {
+    let mut img = scene();
+    // Floor-colored patch on the wall, cut off from the floor below.
+    img.put_pixel(20, 2, rgba(FLOOR));
+    img.put_pixel(21, 15, rgba(WALL));
+    let heights = column_free_space(&free_space_mask(&img));
+    assert_eq!(heights[20], 6);
+    assert_eq!(heights[21], 0);
+}
+
+#[test]
+fn frame_palettes_recover_the_scene() {
+    let noisy = noisy_scene();
+    let palette = Palette::of_frame(&noisy, FRAME_PALETTE_SIZE);
+    assert!(palette.colors().len() <= FRAME_PALETTE_SIZE);
+    assert_eq!(Palette::of_frame(&noisy, FRAME_PALETTE_SIZE), palette);
+    let near = |a: (u8, u8, u8), b: (u8, u8, u8)| a.0.abs_diff(b.0) <= 10 && a.1.abs_diff(b.1) <= 10 && a.2.abs_diff(b.2) <= 10;
+    for color in palette.colors() {
+        assert!([FLOOR, WALL, BOX].iter().any(|c| near(*c, *color)), "{color:?}");
+    }
+    for (x, y, p) in palette.recolor(&noisy).enumerate_pixels() {
+        assert!(near((p[0], p[1], p[2]), scene_color(x, y)), "({x}, {y})");
+    }
+    assert_eq!(column_free_space(&free_space_mask(&palette.recolor(&noisy))), expected_heights());
+}
+
+#[test]
+fn palettes_pick_the_nearest_color() {
+    let palette = Palette::new(vec![WALL, FLOOR, BOX, FLOOR]);
+    assert_eq!(palette.colors(), [WALL, FLOOR, BOX]);
+    assert_eq!(palette.nearest((250, 0, 0)), BOX);
+    assert_eq!(palette.nearest((100, 95, 80)), FLOOR);
+    assert_eq!(Palette::new(vec![]).nearest((1, 2, 3)), (1, 2, 3));
+    assert!(Palette::of_frame(&RgbaImage::new(0, 0), 4).colors().is_empty());
+    assert_eq!(Palette::of_frame(&scene(), 2).colors().len(), 2);
+}
+
+#[test]
+fn untrained_floor_uses_the_frame_palette() {
+    let _globals = common::global_state();
+    let free_space = floor_free_space(bgra_frame(&noisy_scene()));
+    assert_eq!((free_space.width, free_space.height), (WIDTH as i64, HEIGHT as i64));
+    let heights: Vec<u32> = free_space.column_heights.iter().map(|h| *h as u32).collect();
+    assert_eq!(heights, expected_heights());
+}
+
+/// A black 6x5 image with `color` at `points`.
+fn dots(color: (u8, u8, u8), points: &[(u32, u32)]) -> RgbaImage {
+    let mut img = RgbaImage::from_pixel(6, 5, rgba((0, 0, 0)));
+    for (x, y) in points {
+        img.put_pixel(*x, *y, rgba(color));
+    }
+    img
+}
+
+#[test]
+fn blobs_are_four_connected() {
+    let blobs = color_blobs(&dots(BOX, &[(1, 1), (2, 2)]), 1);
+    let red: Vec<&Blob> = blobs.iter().filter(|blob| blob.color == BOX).collect();
+    assert_eq!(red.len(), 2);
+    assert!(red.iter().all(|blob| blob.area == 1));
+    // The background still joins up around them.
+    assert_eq!(blobs[0].color, (0, 0, 0));
+    assert_eq!(blobs[0].area, 28);
+}
+
+#[test]
+fn blobs_have_a_centroid_and_inclusive_bounds() {
+    let l_shape = [(2, 1), (2, 2), (2, 3), (3, 3), (4, 3)];
+    let blobs = color_blobs(&dots(BOX, &l_shape), 1);
+    let blob = blobs.iter().find(|blob| blob.color == BOX).unwrap();
+    assert_eq!(blob.area, 5);
+    assert!((blob.centroid.0 - 2.6).abs() < 1e-9 && (blob.centroid.1 - 2.4).abs() < 1e-9, "{:?}", blob.centroid);
+    assert_eq!(blob.top_left, (2, 1));
+    assert_eq!(blob.bottom_right, (4, 3));
+}
+
+#[test]
+fn blobs_come_largest_first_above_the_minimum_area() {
+    let mut img = dots(BOX, &[(0, 0), (1, 0), (0, 1), (5, 4)]);
+    img.put_pixel(5, 0, rgba(WALL));
+    img.put_pixel(5, 1, rgba(WALL));
+    let areas: Vec<u32> = color_blobs(&img, 1).iter().map(|blob| blob.area).collect();
+    assert_eq!(areas, [24, 3, 2, 1]);
+    let areas: Vec<u32> = color_blobs(&img, 2).iter().map(|blob| blob.area).collect();
+    assert_eq!(areas, [24, 3, 2]);
+    assert!(color_blobs(&img, 25).is_empty());
+    assert!(color_blobs(&RgbaImage::new(0, 0), 1).is_empty());
+}
+
+#[test]
+fn mask_blobs_skip_the_background() {
+    let mut mask = GrayImage::new(6, 5);
+    for (x, y) in [(0, 0), (1, 0), (4, 4)] {
+        mask.put_pixel(x, y, Luma([u8::MAX]));
+    }
+    let blobs = mask_blobs(&mask, 1);
+    assert_eq!(blobs.len(), 2);
+    assert_eq!((blobs[0].area, blobs[0].color), (2, (255, 255, 255)));
+    assert_eq!((blobs[1].top_left, blobs[1].bottom_right), ((4, 4), (4, 4)));
+}
+
+#[test]
+fn untrained_blobs_use_the_frame_palette() {
+    let _globals = common::global_state();
+    let frame = bgra_frame(&noisy_scene());
+    // The box is smaller than the default minimum area.
+    let areas: Vec<i64> = detect_blobs(frame.clone()).iter().map(|blob| blob.area).collect();
+    assert_eq!(areas, [224, 132]);
+
+    set_min_blob_area(10);
+    track_blob_color(250, 0, 0);
+    let blobs = detect_blobs(frame);
+    stop_blob_tracking();
+    set_min_blob_area(50);
+    assert_eq!(blobs.len(), 1);
+    let blob = &blobs[0];
+    assert_eq!(blob.area, 28);
+    assert_eq!((blob.left, blob.top, blob.right, blob.bottom), (4, 6, 7, 12));
+    assert_eq!((blob.centroid_x, blob.centroid_y), (5.5, 9.0));
+}
@@ -0,0 +1,119 @@
+use native::api::{
+    parse_sensor_data, reset_position_estimate, set_controller, ControllerKind, MotorData, RobotCommand,
+    RobotSensorPosition, SonarSettings, BOT,
+};
+use native::control::SonarController;
+use native::simulation::{SimNoise, SimPose, Simulation, Wall};
+use std::f64::consts::TAU;
+
+mod common;
+
+fn start() -> SimPose {
+    SimPose { x: 40.0, y: 40.0, heading: 0.3 }
+}
+
+#[test]
+fn sensor_string_round_trips_through_the_parser() {
+    let mut sim = Simulation::new(Wall::room(200.0, 150.0), start());
+    sim.apply(RobotCommand::Start);
+    for _ in 0..50 {
+        sim.step();
+    }
+    let expected = sim.sensors();
+    assert_eq!(parse_sensor_data(sim.sensor_string()), expected);
+    assert!(expected.left_count > 0);
+    assert_eq!(expected.left_count, expected.right_count);
+}
+
+#[test]
+fn encoders_count_wheel_travel() {
+    let mut sim = Simulation::new(Wall::room(200.0, 150.0), start());
+    (sim.left_speed, sim.right_speed) = (50, -50);
+    for _ in 0..10 {
+        sim.step();
+    }
+    let travel = 10.0 * 50.0 * sim.distance_per_speed / sim.distance_per_tick;
+    assert_eq!(sim.encoder_counts(), (travel.round() as i64, -travel.round() as i64));
+}
+
+#[test]
+fn noise_stays_in_bounds_and_repeats_with_the_seed() {
+    let noise = SimNoise { sonar: 3.0, encoder: 0.1 };
+    let run = |seed| {
+        let mut sim = Simulation::new(Wall::room(200.0, 150.0), start()).with_noise(noise, seed);
+        sim.run(&mut SonarController::avoiding(SonarSettings::default()), 500);
+        (sim.pose, sim.sensors())
+    };
+    assert_eq!(run(7), run(7));
+
+    let mut noisy = Simulation::new(Wall::room(200.0, 150.0), start()).with_noise(noise, 7);
+    let clean = Simulation::new(Wall::room(200.0, 150.0), start()).sensors();
+    for _ in 0..100 {
+        let reading = noisy.sensors();
+        assert!((reading.sonar_front - clean.sonar_front).abs() <= 3, "{reading:?}");
+        assert!((reading.sonar_left - clean.sonar_left).abs() <= 3, "{reading:?}");
+    }
+}
+
+/// The `(x y heading)` at the start of a `process_sensor_data` estimate.
+fn estimated_pose(estimate: &str) -> (f64, f64, f64) {
+    let mut parts = estimate.trim_start_matches('(').split([' ', ')']).map(|p| p.parse::<f64>().unwrap());
+    (parts.next().unwrap(), parts.next().unwrap(), parts.next().unwrap())
+}
+
+/// How many units of estimated heading make a radian, from a spin of known angle.
+fn heading_units_per_radian(sim: &Simulation) -> f64 {
+    let spin = 100;
+    let mut pos = RobotSensorPosition::new(BOT);
+    pos.motor_update(MotorData::default());
+    pos.motor_update(MotorData { left_count: -spin, right_count: spin, ..MotorData::default() });
+    let radians = 2.0 * spin as f64 * sim.distance_per_tick / sim.axle_width;
+    pos.get_pos().heading().to_string().parse::<f64>().unwrap() / radians
+}
+
+/// The estimate starts at its own origin facing along its x axis, so each pose
+/// is compared relative to where the run started, turned into that frame.
+/// Headings are compared in radians, modulo whole turns.
+fn assert_estimates_track(sim: &Simulation, steps: &[(SimPose, String)], tolerance: f64, heading_tolerance: f64) {
+    let start = steps[0].0;
+    let units = heading_units_per_radian(sim);
+    let (sin, cos) = start.heading.sin_cos();
+    for (pose, estimate) in steps.iter() {
+        let (x, y, heading) = estimated_pose(estimate);
+        let (dx, dy) = (pose.x - start.x, pose.y - start.y);
+        let (expected_x, expected_y) = (dx * cos + dy * sin, dy * cos - dx * sin);
+        assert!((x - expected_x).abs() < tolerance, "x: {pose:?} vs {estimate}");
+        assert!((y - expected_y).abs() < tolerance, "y: {pose:?} vs {estimate}");
+        let turned = (heading / units - (pose.heading - start.heading)).rem_euclid(TAU);
+        assert!(turned.min(TAU - turned) < heading_tolerance, "heading: {pose:?} vs {estimate}");
+    }
+}
+
+fn path_length(steps: &[(SimPose, String)]) -> f64 {
+    steps.windows(2).map(|w| (w[1].0.x - w[0].0.x).hypot(w[1].0.y - w[0].0.y)).sum()
+}
+
+#[test]
+fn native_api_drives_the_simulated_robot() {
+    let _globals = common::global_state();
+    reset_position_estimate();
+    set_controller(ControllerKind::AvoidObstacles);
+    let mut sim = Simulation::new(Wall::room(200.0, 150.0), start());
+    let steps = sim.run_native(500);
+    for (pose, _) in steps.iter() {
+        let at = Simulation::new(Wall::room(200.0, 150.0), *pose);
+        assert!(at.nearest_wall_distance() > 5.0, "{pose:?}");
+    }
+    assert!(path_length(&steps) > 300.0, "{:?}", sim.pose);
+    assert_estimates_track(&sim, &steps, 0.5, 0.01);
+
+    // Curving left across an open floor exercises the axle width as well.
+    set_controller(ControllerKind::Manual);
+    reset_position_estimate();
+    let mut sim = Simulation::new(vec![], start());
+    (sim.left_speed, sim.right_speed) = (60, 100);
+    let steps = sim.run_native(300);
+    assert!(sim.pose.heading - start().heading > std::f64::consts::FRAC_PI_2, "{:?}", sim.pose);
+    assert_estimates_track(&sim, &steps, 1.0, 0.01);
+    reset_position_estimate();
+}
@@ -0,0 +1,118 @@
+use native::api::{
+    next_robot_command, set_controller, set_sonar_settings, ControllerKind, RobotCommand, SensorData, SonarSettings,
+};
+use native::control::{Controller, SonarController, WallSide};
+use native::simulation::{SimPose, Simulation, Wall};
+
+mod common;
+
+fn sensors(front: i64, left: i64, right: i64) -> SensorData {
+    SensorData {
+        sonar_front: front,
+        sonar_left: left,
+        sonar_right: right,
+        ..SensorData::default()
+    }
+}
+
+fn room() -> Vec<Wall> {
+    Wall::room(200.0, 150.0)
+}
+
+fn assert_clear_of_walls(poses: &[SimPose]) {
+    for pose in poses.iter() {
+        let at = Simulation::new(room(), *pose);
+        assert!(at.nearest_wall_distance() > 5.0, "{pose:?}");
+    }
+}
+
+fn path_length(poses: &[SimPose]) -> f64 {
+    poses
+        .windows(2)
+        .map(|w| ((w[1].x - w[0].x).powi(2) + (w[1].y - w[0].y).powi(2)).sqrt())
+        .sum()
+}
+
+#[test]
+fn avoider_turns_toward_the_open_side() {
+    let settings = SonarSettings::default();
+    let command = |s: SensorData| SonarController::avoiding(settings).next_command(&s);
+    assert_eq!(command(sensors(100, 100, 100)), RobotCommand::Drive { speed: settings.cruise_speed });
+    assert_eq!(command(sensors(10, 30, 80)), RobotCommand::Turn { speed: settings.turn_speed });
+    assert_eq!(command(sensors(10, 80, 30)), RobotCommand::Turn { speed: -settings.turn_speed });
+}
+
+#[test]
+fn avoider_keeps_turning_the_same_way_until_clear() {
+    let settings = SonarSettings::default();
+    let mut controller = SonarController::avoiding(settings);
+    let right = RobotCommand::Turn { speed: settings.turn_speed };
+    assert_eq!(controller.next_command(&sensors(10, 30, 80)), right);
+    assert_eq!(controller.next_command(&sensors(10, 80, 30)), right);
+    assert_eq!(controller.next_command(&sensors(100, 80, 80)), RobotCommand::Drive { speed: settings.cruise_speed });
+    assert_eq!(controller.next_command(&sensors(10, 80, 30)), RobotCommand::Turn { speed: -settings.turn_speed });
+}
+
+#[test]
+fn follower_turns_away_from_its_wall() {
+    let settings = SonarSettings::default();
+    let mut left = SonarController::following(settings, WallSide::Left);
+    let mut right = SonarController::following(settings, WallSide::Right);
+    assert_eq!(left.next_command(&sensors(10, 100, 15)), RobotCommand::Turn { speed: settings.turn_speed });
+    assert_eq!(right.next_command(&sensors(10, 15, 100)), RobotCommand::Turn { speed: -settings.turn_speed });
+}
+
+#[test]
+fn avoider_roams_a_room_without_hitting_anything() {
+    let mut sim = Simulation::new(room(), SimPose { x: 40.0, y: 40.0, heading: 0.3 });
+    let mut controller = SonarController::avoiding(SonarSettings::default());
+    let poses = sim.run(&mut controller, 5000);
+    assert_clear_of_walls(&poses);
+    assert!(path_length(&poses) > 5000.0);
+}
+
+#[test]
+fn follower_settles_at_the_wall_distance() {
+    let walls = vec![Wall::new((-100.0, 0.0), (5000.0, 0.0))];
+    let mut sim = Simulation::new(walls, SimPose { x: 0.0, y: 30.0, heading: 0.0 });
+    let settings = SonarSettings::default();
+    let mut controller = SonarController::following(settings, WallSide::Right);
+    let poses = sim.run(&mut controller, 800);
+    for pose in poses[200..].iter() {
+        assert!((pose.y - 15.0).abs() < 1.0, "{pose:?}");
+    }
+    // Nearly as far as driving straight at the cruise speed the whole time.
+    let straight = 800.0 * settings.cruise_speed as f64 * sim.distance_per_speed;
+    let end = poses.last().unwrap();
+    assert!(end.x > 0.9 * straight, "{end:?} vs {straight}");
+}
+
+#[test]
+fn follower_circles_a_room() {
+    let mut sim = Simulation::new(room(), SimPose { x: 100.0, y: 15.0, heading: 0.0 });
+    let mut controller = SonarController::following(SonarSettings::default(), WallSide::Right);
+    let poses = sim.run(&mut controller, 5000);
+    assert_clear_of_walls(&poses);
+    // Following the right wall goes counterclockwise around the room.
+    assert!(poses.last().unwrap().heading > 6.0 * std::f64::consts::PI);
+}
+
+#[test]
+fn simulation_is_deterministic() {
+    let run = || {
+        let mut sim = Simulation::new(room(), SimPose { x: 40.0, y: 40.0, heading: 0.3 });
+        sim.run(&mut SonarController::avoiding(SonarSettings::default()), 1000)
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn new_settings_reach_the_active_controller() {
+    let _globals = common::global_state();
+    set_controller(ControllerKind::FollowRightWall);
+    let settings = SonarSettings { turn_speed: 30, ..SonarSettings::default() };
+    set_sonar_settings(settings);
+    assert_eq!(next_robot_command(sensors(10, 15, 100)), RobotCommand::Turn { speed: -30 });
+    set_sonar_settings(SonarSettings::default());
+    set_controller(ControllerKind::Manual);
+}
@@ -0,0 +1,149 @@
+use native::api::{
+    next_robot_command, set_color_target, set_min_blob_area, set_pyramid_level, set_tracking_speeds,
+    track_color_target, ColorSpace, ColorTarget, ImageData, RobotCommand, SensorData,
+};
+use native::segmentation::Blob;
+use native::tracking::{rgb_hsv, steer_toward, ColorMatcher};
+
+mod common;
+
+fn target((red, green, blue): (i64, i64, i64), tolerance: i64, space: ColorSpace) -> ColorTarget {
+    ColorTarget { red, green, blue, tolerance, space, min_area: 1 }
+}
+
+fn assert_hsv(color: (u8, u8, u8), expected: (f32, f32, f32)) {
+    let (h, s, v) = rgb_hsv(color);
+    assert!(
+        (h - expected.0).abs() < 0.1 && (s - expected.1).abs() < 1e-3 && (v - expected.2).abs() < 1e-3,
+        "{color:?} is {:?}",
+        (h, s, v)
+    );
+}
+
+#[test]
+fn hsv_of_primaries_and_grays() {
+    assert_hsv((255, 0, 0), (0.0, 1.0, 1.0));
+    assert_hsv((0, 255, 0), (120.0, 1.0, 1.0));
+    assert_hsv((0, 0, 255), (240.0, 1.0, 1.0));
+    assert_hsv((255, 0, 128), (329.9, 1.0, 1.0));
+    assert_hsv((0, 0, 0), (0.0, 0.0, 0.0));
+    assert_hsv((51, 51, 51), (0.0, 0.0, 0.2));
+}
+
+#[test]
+fn rgb_tolerance_is_per_channel() {
+    let matcher = ColorMatcher::new(&target((100, 150, 200), 10, ColorSpace::Rgb));
+    assert!(matcher.matches((100, 150, 200)));
+    assert!(matcher.matches((110, 140, 190)));
+    assert!(!matcher.matches((111, 150, 200)));
+    assert!(!matcher.matches((100, 150, 189)));
+}
+
+#[test]
+fn hue_differences_wrap_around_zero() {
+    // Hue 355.3 against 4.7: 9.4 degrees apart across 0/360.
+    let matcher = ColorMatcher::new(&target((255, 0, 20), 10, ColorSpace::Hsv));
+    assert!(matcher.matches((255, 20, 0)));
+    assert!(!ColorMatcher::new(&target((255, 0, 20), 5, ColorSpace::Hsv)).matches((255, 20, 0)));
+    assert!(!matcher.matches((0, 255, 0)));
+    // Too dark or too washed out to tell the hue.
+    assert!(!matcher.matches((100, 0, 0)));
+    assert!(!matcher.matches((255, 200, 200)));
+}
+
+#[test]
+fn min_area_is_clamped_to_one_pixel() {
+    let mut color = target((1, 2, 3), 0, ColorSpace::Rgb);
+    color.min_area = -5;
+    assert_eq!(ColorMatcher::new(&color).min_area(), 1);
+    color.min_area = 40;
+    assert_eq!(ColorMatcher::new(&color).min_area(), 40);
+}
+
+fn blob_at(x: f64, area: u32) -> Blob {
+    Blob { color: (255, 255, 255), area, centroid: (x, 5.0), top_left: (0, 0), bottom_right: (0, 0) }
+}
+
+#[test]
+fn steering_turns_toward_the_region() {
+    // 101 columns, so the center is column 50.
+    let steer = |blob: Blob| steer_toward(Some(&blob), 101, 10, 100, 40);
+    assert_eq!(steer(blob_at(50.0, 1)), (100, 100));
+    // To the right, the left wheel speeds up.
+    assert_eq!(steer(blob_at(75.0, 1)), (120, 80));
+    assert_eq!(steer(blob_at(100.0, 1)), (140, 60));
+    assert_eq!(steer(blob_at(0.0, 1)), (60, 140));
+}
+
+#[test]
+fn steering_stops_on_arrival_or_without_a_region() {
+    // A quarter of the 1010-pixel frame is 252.5 pixels.
+    assert_eq!(steer_toward(Some(&blob_at(50.0, 252)), 101, 10, 100, 40), (100, 100));
+    assert_eq!(steer_toward(Some(&blob_at(50.0, 253)), 101, 10, 100, 40), (0, 0));
+    assert_eq!(steer_toward(None, 101, 10, 100, 40), (0, 0));
+    assert_eq!(steer_toward(Some(&blob_at(0.0, 1)), 0, 0, 100, 40), (0, 0));
+}
+
+#[test]
+fn downsampled_blobs_cover_whole_blocks() {
+    let blob = Blob { color: (1, 2, 3), area: 4, centroid: (2.5, 1.5), top_left: (2, 1), bottom_right: (3, 2) };
+    let full = blob.to_full_scale(2);
+    assert_eq!(full.area, 16);
+    assert_eq!(full.centroid, (5.5, 3.5));
+    assert_eq!((full.top_left, full.bottom_right), ((4, 2), (7, 5)));
+    assert_eq!(blob.to_full_scale(1), blob);
+}
+
+/// Black 16x12 BGRA frame with a red 4x4 square at columns 4 to 7, rows 2 to 5.
+fn red_square_frame() -> ImageData {
+    common::bgra_frame(16, 12, |x, y| {
+        if (4..8).contains(&x) && (2..6).contains(&y) {
+            [230, 10, 10, u8::MAX]
+        } else {
+            [0, 0, 0, u8::MAX]
+        }
+    })
+}
+
+#[test]
+fn tracking_uses_its_own_min_area_at_every_level() {
+    let _globals = common::global_state();
+    set_tracking_speeds(100, 40);
+    // The blob tracking minimum doesn't apply to the color target.
+    set_min_blob_area(1000);
+    let mut red = target((255, 0, 0), 40, ColorSpace::Rgb);
+    red.min_area = 16;
+    set_color_target(red);
+    for level in [0, 1] {
+        set_pyramid_level(level);
+        let command = track_color_target(red_square_frame());
+        assert!(command.found, "level {level}");
+        assert_eq!(command.area, 16);
+        assert_eq!((command.centroid_x, command.centroid_y), (5.5, 3.5));
+        assert!(command.left_speed < command.right_speed, "level {level}");
+    }
+
+    red.min_area = 17;
+    set_color_target(red);
+    for level in [0, 1] {
+        set_pyramid_level(level);
+        let command = track_color_target(red_square_frame());
+        assert!(!command.found, "level {level}");
+        assert_eq!((command.left_speed, command.right_speed), (0, 0));
+    }
+    set_pyramid_level(0);
+    set_min_blob_area(50);
+    // Only the latest speeds were still waiting to go out.
+    assert_eq!(next_robot_command(SensorData::default()), RobotCommand::SetSpeeds { left: 0, right: 0 });
+}
+
+#[test]
+fn tracking_queues_its_wheel_speeds() {
+    let _globals = common::global_state();
+    set_tracking_speeds(100, 40);
+    set_color_target(target((255, 0, 0), 40, ColorSpace::Rgb));
+    let command = track_color_target(red_square_frame());
+    let expected = RobotCommand::SetSpeeds { left: command.left_speed, right: command.right_speed };
+    assert_eq!(next_robot_command(SensorData::default()), expected);
+    assert_eq!(next_robot_command(SensorData::default()), RobotCommand::NoOp);
+}
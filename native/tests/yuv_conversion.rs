@@ -0,0 +1,118 @@
+use native::api::{ColorRange, ColorStandard, ImageData, PixelFormat};
+use native::image_proc::{inner_yuv_rgba, YuvConversion};
+
+const STANDARDS: [ColorStandard; 2] = [ColorStandard::Bt601, ColorStandard::Bt709];
+const RANGES: [ColorRange; 2] = [ColorRange::Full, ColorRange::Limited];
+
+fn weights(standard: ColorStandard) -> (f64, f64) {
+    match standard {
+        ColorStandard::Bt601 => (0.299, 0.114),
+        ColorStandard::Bt709 => (0.2126, 0.0722),
+    }
+}
+
+/// Straightforward floating-point decode, straight from the standards.
+fn reference(standard: ColorStandard, range: ColorRange, y: u8, u: u8, v: u8) -> (u8, u8, u8) {
+    let (kr, kb) = weights(standard);
+    let (y, u, v) = match range {
+        ColorRange::Full => (y as f64, u as f64 - 128.0, v as f64 - 128.0),
+        ColorRange::Limited => (
+            (y as f64 - 16.0) * 255.0 / 219.0,
+            (u as f64 - 128.0) * 255.0 / 224.0,
+            (v as f64 - 128.0) * 255.0 / 224.0,
+        ),
+    };
+    let r = y + 2.0 * (1.0 - kr) * v;
+    let b = y + 2.0 * (1.0 - kb) * u;
+    let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+    let byte = |c: f64| c.round().clamp(0.0, 255.0) as u8;
+    (byte(r), byte(g), byte(b))
+}
+
+fn convert(standard: ColorStandard, range: ColorRange, yuv: (u8, u8, u8)) -> (u8, u8, u8) {
+    YuvConversion::new(standard, range).rgb(yuv.0 as i32, yuv.1 as i32, yuv.2 as i32)
+}
+
+#[test]
+fn grays_are_exact() {
+    for standard in STANDARDS {
+        assert_eq!(convert(standard, ColorRange::Full, (0, 128, 128)), (0, 0, 0));
+        assert_eq!(convert(standard, ColorRange::Full, (128, 128, 128)), (128, 128, 128));
+        assert_eq!(convert(standard, ColorRange::Full, (255, 128, 128)), (255, 255, 255));
+        assert_eq!(convert(standard, ColorRange::Limited, (16, 128, 128)), (0, 0, 0));
+        assert_eq!(convert(standard, ColorRange::Limited, (235, 128, 128)), (255, 255, 255));
+    }
+}
+
+#[test]
+fn primaries() {
+    let bt601 = ColorStandard::Bt601;
+    let bt709 = ColorStandard::Bt709;
+    let full = ColorRange::Full;
+    let limited = ColorRange::Limited;
+    assert_eq!(convert(bt601, full, (76, 85, 255)), (254, 0, 0));
+    assert_eq!(convert(bt601, full, (150, 44, 21)), (0, 255, 1));
+    assert_eq!(convert(bt601, full, (29, 255, 107)), (0, 0, 254));
+    assert_eq!(convert(bt601, limited, (81, 90, 240)), (254, 0, 0));
+    assert_eq!(convert(bt601, limited, (145, 54, 34)), (0, 255, 1));
+    assert_eq!(convert(bt601, limited, (41, 240, 110)), (0, 0, 255));
+    assert_eq!(convert(bt709, full, (54, 99, 255)), (254, 0, 0));
+    assert_eq!(convert(bt709, full, (182, 30, 12)), (0, 255, 0));
+    assert_eq!(convert(bt709, full, (18, 255, 116)), (0, 0, 254));
+    assert_eq!(convert(bt709, limited, (63, 102, 240)), (255, 1, 0));
+    assert_eq!(convert(bt709, limited, (173, 42, 26)), (0, 255, 1));
+    assert_eq!(convert(bt709, limited, (32, 240, 118)), (1, 0, 255));
+}
+
+#[test]
+fn matches_floating_point_reference() {
+    for standard in STANDARDS {
+        for range in RANGES {
+            let conversion = YuvConversion::new(standard, range);
+            for y in (0..=255u8).step_by(3) {
+                for u in (0..=255u8).step_by(5) {
+                    for v in (0..=255u8).step_by(5) {
+                        let actual = conversion.rgb(y as i32, u as i32, v as i32);
+                        let expected = reference(standard, range, y, u, v);
+                        for (a, e) in [(actual.0, expected.0), (actual.1, expected.1), (actual.2, expected.2)] {
+                            assert!(
+                                (a as i32 - e as i32).abs() <= 1,
+                                "{standard:?} {range:?} ({y}, {u}, {v}): {actual:?} vs {expected:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn semi_planar_frames_match_the_reference() {
+    let (width, height) = (64, 48);
+    // Interleaved chroma, as delivered by most Android cameras.
+    let uv_len = width * height / 2;
+    let img = ImageData {
+        ys: (0..width * height).map(|i| (i % 251) as u8).collect(),
+        us: (0..uv_len).map(|i| (i % 241) as u8).collect(),
+        vs: (0..uv_len).map(|i| (i % 239) as u8).collect(),
+        width: width as i64,
+        height: height as i64,
+        y_row_stride: width as i64,
+        uv_row_stride: width as i64,
+        uv_pixel_stride: 2,
+        format: PixelFormat::Yuv420,
+    };
+    let rgba = inner_yuv_rgba(&img);
+    for (i, pixel) in rgba.chunks(4).enumerate() {
+        let (x, y) = (i % width, i / width);
+        let uv = 2 * (x / 2) + width * (y / 2);
+        // Frames convert as BT.601 full range unless told otherwise.
+        let expected = reference(ColorStandard::Bt601, ColorRange::Full, img.ys[i], img.us[uv], img.vs[uv]);
+        let actual = (pixel[0], pixel[1], pixel[2]);
+        for (a, e) in [(actual.0, expected.0), (actual.1, expected.1), (actual.2, expected.2)] {
+            assert!((a as i32 - e as i32).abs() <= 1, "({x}, {y}): {actual:?} vs {expected:?}");
+        }
+        assert_eq!(pixel[3], u8::MAX);
+    }
+}